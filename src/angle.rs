@@ -15,17 +15,21 @@
 
 //! Angle units for type-safe, self-documenting code.
 
-use std::fmt;
-use std::f64;
-use std::ops::*;
+use core::fmt;
+use core::f64;
+use core::ops::*;
+use core::str::FromStr;
 
+#[cfg(feature = "rand")]
 use rand::{Rand, Rng};
+#[cfg(feature = "rand")]
 use rand::distributions::range::SampleRange;
 use num_traits::cast;
 
 use structure::*;
 
 use approx::ApproxEq;
+use format::{self, ParseError};
 use num::BaseFloat;
 
 /// An angle, in radians.
@@ -34,7 +38,6 @@ use num::BaseFloat;
 #[repr(C, packed)]
 #[derive(Copy, Clone, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Rad<S> { pub s: S }
 
 /// An angle, in degrees.
@@ -43,13 +46,33 @@ pub struct Rad<S> { pub s: S }
 #[repr(C, packed)]
 #[derive(Copy, Clone, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Deg<S> { pub s: S }
 
+/// An angle, in turns. A full turn is `1`.
+///
+/// This type is marked as `#[repr(C, packed)]`.
+#[repr(C, packed)]
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct Turns<S> { pub s: S }
+
+/// An angle, in gradians. A full turn is `400` gradians, so a right angle is
+/// `100` gradians.
+///
+/// This type is marked as `#[repr(C, packed)]`.
+#[repr(C, packed)]
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct Grad<S> { pub s: S }
+
 /// Create a new angle, in radians
 #[inline] pub fn rad<S: BaseFloat>(s: S) -> Rad<S> { Rad { s: s } }
 /// Create a new angle, in degrees
 #[inline] pub fn deg<S: BaseFloat>(s: S) -> Deg<S> { Deg { s: s } }
+/// Create a new angle, in turns
+#[inline] pub fn turns<S: BaseFloat>(s: S) -> Turns<S> { Turns { s: s } }
+/// Create a new angle, in gradians
+#[inline] pub fn grad<S: BaseFloat>(s: S) -> Grad<S> { Grad { s: s } }
 
 impl<S> From<Rad<S>> for Deg<S> where S: BaseFloat {
     #[inline]
@@ -65,8 +88,30 @@ impl<S> From<Deg<S>> for Rad<S> where S: BaseFloat {
     }
 }
 
+macro_rules! impl_angle_conversion {
+    ($From:ident, $To:ident, $factor:expr) => {
+        impl<S> From<$From<S>> for $To<S> where S: BaseFloat {
+            #[inline]
+            fn from(a: $From<S>) -> $To<S> {
+                $To::new(a.s * cast($factor).unwrap())
+            }
+        }
+    }
+}
+
+impl_angle_conversion!(Rad, Turns, 0.5 / f64::consts::PI);
+impl_angle_conversion!(Turns, Rad, f64::consts::PI * 2.0);
+impl_angle_conversion!(Rad, Grad, 200.0 / f64::consts::PI);
+impl_angle_conversion!(Grad, Rad, f64::consts::PI / 200.0);
+impl_angle_conversion!(Deg, Turns, 1.0 / 360.0);
+impl_angle_conversion!(Turns, Deg, 360.0);
+impl_angle_conversion!(Deg, Grad, 400.0 / 360.0);
+impl_angle_conversion!(Grad, Deg, 0.9);
+impl_angle_conversion!(Turns, Grad, 400.0);
+impl_angle_conversion!(Grad, Turns, 1.0 / 400.0);
+
 macro_rules! impl_angle {
-    ($Angle:ident, $fmt:expr, $full_turn:expr, $hi:expr) => {
+    ($Angle:ident, $suffix:expr, $full_turn:expr, $hi:expr) => {
         impl<S: BaseFloat> $Angle<S> {
             #[inline]
             pub fn new(value: S) -> $Angle<S> {
@@ -160,6 +205,7 @@ macro_rules! impl_angle {
             }
         }
 
+        #[cfg(feature = "rand")]
         impl<S: BaseFloat + SampleRange> Rand for $Angle<S> {
             #[inline]
             fn rand<R: Rng>(rng: &mut R) -> $Angle<S> {
@@ -169,11 +215,61 @@ macro_rules! impl_angle {
 
         impl<S: fmt::Debug> fmt::Debug for $Angle<S> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(f, $fmt, self.s)
+                write!(f, concat!("{:?}", $suffix), self.s)
+            }
+        }
+
+        /// Formatted as the number followed by its unit. The width applies
+        /// to both, such that `{:8.2}` pads `90.00°` to eight characters.
+        impl<S: fmt::Display> fmt::Display for $Angle<S> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                format::fmt_suffixed(f, &self.s, $suffix)
+            }
+        }
+
+        /// Parses a number, optionally followed by a unit of `rad`, `deg` or
+        /// `°`, `turns` or `grad`. Values in other units are converted, and
+        /// values without a unit are taken to be in this one.
+        impl<S: BaseFloat + FromStr> FromStr for $Angle<S> {
+            type Err = ParseError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<$Angle<S>, ParseError> {
+                parse_angle(s, $Angle::new)
             }
         }
     }
 }
 
-impl_angle!(Rad, "{:?} rad", f64::consts::PI * 2.0, f64::consts::PI);
-impl_angle!(Deg, "{:?}°", 360, 180);
+impl_angle!(Rad, " rad", f64::consts::PI * 2.0, f64::consts::PI);
+impl_angle!(Deg, "°", 360, 180);
+impl_angle!(Turns, " turns", 1, 0.5);
+impl_angle!(Grad, " grad", 400, 200);
+
+fn parse_angle<S, A, F>(s: &str, unitless: F) -> Result<A, ParseError> where
+    S: BaseFloat + FromStr,
+    A: From<Rad<S>> + From<Deg<S>> + From<Turns<S>> + From<Grad<S>>,
+    F: FnOnce(S) -> A,
+{
+    let s = s.trim();
+    let strip = |suffix: &str| if s.ends_with(suffix) { Some(&s[..s.len() - suffix.len()]) } else { None };
+
+    // `grad` needs to be checked before `rad`, which it ends with
+    if let Some(value) = strip("grad") { return format::parse_scalar(value).map(|s| Grad::new(s).into()); }
+    if let Some(value) = strip("rad") { return format::parse_scalar(value).map(|s| Rad::new(s).into()); }
+    if let Some(value) = strip("deg") { return format::parse_scalar(value).map(|s| Deg::new(s).into()); }
+    if let Some(value) = strip("°") { return format::parse_scalar(value).map(|s| Deg::new(s).into()); }
+    if let Some(value) = strip("turns") { return format::parse_scalar(value).map(|s| Turns::new(s).into()); }
+    format::parse_scalar(s).map(unitless)
+}
+
+impl_eders!(<S> Rad { s });
+impl_eders!(<S> Deg { s });
+impl_eders!(<S> Turns { s });
+impl_eders!(<S> Grad { s });
+
+impl_arbitrary!(<S> Rad { s });
+impl_arbitrary!(<S> Deg { s });
+impl_arbitrary!(<S> Turns { s });
+impl_arbitrary!(<S> Grad { s });
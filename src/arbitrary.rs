@@ -0,0 +1,236 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generators and shrinkers for property-based testing with [`quickcheck`].
+//!
+//! With the `quickcheck` feature enabled, the cgmath types implement
+//! `Arbitrary`. Scalars are generated within the size of the `Gen`, and values
+//! are shrunk one field at a time, towards whole numbers and zero. Types with
+//! an invariant only generate values that uphold it:
+//!
+//! | Type                                          | Values                                                 |
+//! |-----------------------------------------------|--------------------------------------------------------|
+//! | Vectors, points, matrices, angles, `Euler`    | Each component independently                           |
+//! | `Quaternion`, `Rotor3`, `Basis3`              | Uniformly distributed rotations, shrinking to identity |
+//! | `UnitComplex`, `Basis2`                       | Rotations by an arbitrary angle                        |
+//! | `DualQuaternion`                              | A rotation followed by a translation                   |
+//! | `PerspectiveFov`, `Perspective`, `Ortho`      | Projections that can be converted into a `Matrix4`     |
+//! | `FirstPerson`, `Orbit`, `Arcball`             | Cameras within their pitch and distance limits         |
+//! | `CatmullRom`                                  | Uniform, centripetal or chordal splines                |
+//! | `Track`                                       | Keyframes at strictly increasing times                 |
+//!
+//! Like its `Rand` implementation, `Quaternion` only generates unit
+//! quaternions, and a `Basis3` is always orthonormal. Constraints that only
+//! some values of a type satisfy are expressed by wrapping the type:
+//!
+//! - `Unit` generates unit vectors and unit quaternions.
+//! - `Invertible` generates matrices that are far enough from singular for
+//!   their inverse to be accurate.
+//! - `Orthonormal` generates rotation matrices.
+//!
+//! Shrinking a wrapped value only produces values that still satisfy the
+//! constraint.
+//!
+//! ```rust
+//! extern crate quickcheck;
+//! extern crate cgmath;
+//!
+//! use cgmath::prelude::*;
+//! use cgmath::{ApproxEq, Matrix4};
+//! use cgmath::arbitrary::Invertible;
+//!
+//! fn inverse_is_inverse(m: Invertible<Matrix4<f64>>) -> bool {
+//!     let Invertible(m) = m;
+//!     (m * m.invert().unwrap()).approx_eq_eps(&Matrix4::identity(), &1e-9)
+//! }
+//!
+//! # fn main() {
+//! quickcheck::quickcheck(inverse_is_inverse as fn(Invertible<Matrix4<f64>>) -> bool);
+//! # }
+//! ```
+//!
+//! [`quickcheck`]: https://crates.io/crates/quickcheck
+
+use core::ops::IndexMut;
+
+use num_traits::cast;
+use quickcheck::{Arbitrary, Gen};
+
+use structure::*;
+
+use angle::Rad;
+use matrix::{Matrix2, Matrix3, Matrix4};
+use num::BaseFloat;
+use quaternion::Quaternion;
+use unit_complex::UnitComplex;
+use vector::{Vector2, Vector3, Vector4};
+
+/// A vector or quaternion with a magnitude of one.
+///
+/// Vectors are uniformly distributed over the directions, and shrink towards
+/// the `x` axis. Quaternions are generated and shrunk in the same way as by
+/// the `Arbitrary` implementation of `Quaternion`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Unit<T>(pub T);
+
+/// A square matrix that is invertible, and whose columns are far enough from
+/// being linearly dependent for its inverse to be accurate.
+///
+/// The absolute value of the determinant of the matrix is at least a tenth of
+/// the product of the magnitudes of its columns, which is the largest it can
+/// be.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Invertible<T>(pub T);
+
+/// A rotation matrix, whose columns are orthogonal unit vectors, and whose
+/// determinant is one.
+///
+/// Rotations in the plane are by an arbitrary angle, and rotations in space
+/// are uniformly distributed. They shrink towards the identity.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Orthonormal<T>(pub T);
+
+/// A unit quaternion representing a rotation drawn uniformly from the space
+/// of all rotations, using the same method as `sample::unit_quaternion`.
+pub fn unit_quaternion<S: BaseFloat, G: Gen>(g: &mut G) -> Quaternion<S> {
+    let u: S = cast(g.gen::<f64>()).unwrap();
+    let (a, b) = ((S::one() - u).sqrt(), u.sqrt());
+    let (s1, c1) = Rad::sin_cos(Rad::full_turn() * cast(g.gen::<f64>()).unwrap());
+    let (s2, c2) = Rad::sin_cos(Rad::full_turn() * cast(g.gen::<f64>()).unwrap());
+
+    Quaternion::new(b * c2, a * s1, a * c1, b * s2)
+}
+
+/// Shrink a unit quaternion towards the identity.
+///
+/// This produces the identity, followed by the rotations obtained by setting
+/// one of the components of the quaternion to zero and normalizing it again.
+/// Each of these has fewer non-zero components than the original, so
+/// repeatedly shrinking a rotation always ends at the identity.
+pub fn shrink_rotation<S: BaseFloat + 'static>(q: Quaternion<S>) -> Box<Iterator<Item = Quaternion<S>>> {
+    shrink_unit(q, Quaternion::one(), 4)
+}
+
+fn shrink_unit<V>(v: V, target: V, len: usize) -> Box<Iterator<Item = V>> where
+    // FIXME: Ugly type signatures - blocked by rust-lang/rust#24092
+    V: InnerSpace + PartialEq + IndexMut<usize, Output = <V as VectorSpace>::Scalar> + 'static,
+    <V as VectorSpace>::Scalar: BaseFloat,
+{
+    let mut shrinks = Vec::new();
+    if v != target {
+        shrinks.push(target);
+    }
+    let non_zero = (0..len).filter(|&i| v[i] != V::Scalar::zero()).count();
+    if non_zero > 1 {
+        for i in (0..len).filter(|&i| v[i] != V::Scalar::zero()) {
+            let mut shrunk = v;
+            shrunk[i] = V::Scalar::zero();
+            shrinks.push(shrunk.normalize());
+        }
+    }
+    Box::new(shrinks.into_iter())
+}
+
+macro_rules! impl_unit_vector {
+    ($VectorN:ident, $n:expr) => {
+        impl<S: BaseFloat + Arbitrary> Arbitrary for Unit<$VectorN<S>> {
+            fn arbitrary<G: Gen>(g: &mut G) -> Unit<$VectorN<S>> {
+                // Sample the unit ball by rejection, so that the directions
+                // are uniformly distributed.
+                loop {
+                    let mut v: $VectorN<S> = $VectorN::zero();
+                    for i in 0..$n {
+                        v[i] = cast(g.gen_range(-1.0f64, 1.0)).unwrap();
+                    }
+                    let magnitude2 = v.magnitude2();
+                    if magnitude2 <= S::one() && magnitude2 > S::epsilon() {
+                        return Unit(v / magnitude2.sqrt());
+                    }
+                }
+            }
+
+            fn shrink(&self) -> Box<Iterator<Item = Unit<$VectorN<S>>>> {
+                Box::new(shrink_unit(self.0, $VectorN::unit_x(), $n).map(Unit))
+            }
+        }
+    }
+}
+
+impl_unit_vector!(Vector2, 2);
+impl_unit_vector!(Vector3, 3);
+impl_unit_vector!(Vector4, 4);
+
+impl<S: BaseFloat + Arbitrary> Arbitrary for Unit<Quaternion<S>> {
+    fn arbitrary<G: Gen>(g: &mut G) -> Unit<Quaternion<S>> {
+        Unit(unit_quaternion(g))
+    }
+
+    fn shrink(&self) -> Box<Iterator<Item = Unit<Quaternion<S>>>> {
+        Box::new(shrink_rotation(self.0).map(Unit))
+    }
+}
+
+macro_rules! impl_invertible {
+    ($MatrixN:ident { $($field:ident),+ }) => {
+        impl<S: BaseFloat + Arbitrary> Invertible<$MatrixN<S>> {
+            fn is_well_conditioned(m: &$MatrixN<S>) -> bool {
+                let det = m.determinant().abs();
+                let $MatrixN { $($field),+ } = *m;
+                let volume = S::one() $(* $field.magnitude())+;
+                det > S::zero() && det >= volume * cast(0.1f64).unwrap()
+            }
+        }
+
+        impl<S: BaseFloat + Arbitrary> Arbitrary for Invertible<$MatrixN<S>> {
+            fn arbitrary<G: Gen>(g: &mut G) -> Invertible<$MatrixN<S>> {
+                loop {
+                    let m = $MatrixN::arbitrary(g);
+                    if Self::is_well_conditioned(&m) {
+                        return Invertible(m);
+                    }
+                }
+            }
+
+            fn shrink(&self) -> Box<Iterator<Item = Invertible<$MatrixN<S>>>> {
+                Box::new(self.0.shrink().filter(Self::is_well_conditioned).map(Invertible))
+            }
+        }
+    }
+}
+
+impl_invertible!(Matrix2 { x, y });
+impl_invertible!(Matrix3 { x, y, z });
+impl_invertible!(Matrix4 { x, y, z, w });
+
+impl<S: BaseFloat + Arbitrary> Arbitrary for Orthonormal<Matrix2<S>> {
+    fn arbitrary<G: Gen>(g: &mut G) -> Orthonormal<Matrix2<S>> {
+        Orthonormal(Matrix2::from_angle(Rad::arbitrary(g)))
+    }
+
+    fn shrink(&self) -> Box<Iterator<Item = Orthonormal<Matrix2<S>>>> {
+        let angle = Rad::from(UnitComplex::from(self.0));
+        Box::new(angle.shrink().map(|angle| Orthonormal(Matrix2::from_angle(angle))))
+    }
+}
+
+impl<S: BaseFloat + Arbitrary> Arbitrary for Orthonormal<Matrix3<S>> {
+    fn arbitrary<G: Gen>(g: &mut G) -> Orthonormal<Matrix3<S>> {
+        Orthonormal(unit_quaternion(g).into())
+    }
+
+    fn shrink(&self) -> Box<Iterator<Item = Orthonormal<Matrix3<S>>>> {
+        Box::new(shrink_rotation(self.0.into()).map(|q| Orthonormal(q.into())))
+    }
+}
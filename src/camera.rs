@@ -0,0 +1,375 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Camera controllers, for turning user input into view transformations.
+//!
+//! The cameras follow the usual cgmath conventions: the world is right-handed
+//! with `y` pointing up, and a camera looks along its negative `z` axis. The
+//! controllers only store state and do arithmetic, so they can be driven by
+//! any windowing library by feeding them mouse and keyboard deltas.
+//!
+//! ```rust
+//! use cgmath::camera::{Camera, FirstPerson};
+//! use cgmath::{Matrix4, Point3, Rad};
+//!
+//! let mut camera = FirstPerson::new(Point3::new(0.0, 1.8, 5.0), Rad::new(0.0), Rad::new(0.0));
+//!
+//! // On mouse movement, and on key presses
+//! camera.rotate(Rad::new(-0.01), Rad::new(0.02));
+//! camera.move_by(0.1, 0.0, 0.0);
+//!
+//! let view: Matrix4<f32> = camera.view_matrix();
+//! ```
+
+use num_traits::cast;
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
+
+use structure::*;
+
+use angle::{Deg, Rad};
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::{Rotation, Rotation3};
+use transform::Decomposed;
+use vector::{Vector2, Vector3};
+
+/// A camera, positioned and oriented in the world.
+pub trait Camera<S: BaseFloat> {
+    /// The transformation from the camera's local space to the world. The
+    /// displacement is the position of the camera, and the rotation is its
+    /// orientation.
+    fn transform(&self) -> Decomposed<Vector3<S>, Quaternion<S>>;
+
+    /// The view matrix, transforming from the world to the camera's local
+    /// space. This is the inverse of `transform`.
+    #[inline]
+    fn view_matrix(&self) -> Matrix4<S> {
+        let transform = self.transform();
+        let rot = transform.rot.invert();
+        Matrix4::from(rot) * Matrix4::from_translation(-transform.disp)
+    }
+
+    /// The position of the camera in the world.
+    #[inline]
+    fn position(&self) -> Point3<S> {
+        Point3::from_vec(self.transform().disp)
+    }
+
+    /// The direction the camera is looking in.
+    #[inline]
+    fn forward(&self) -> Vector3<S> {
+        self.transform().rot.rotate_vector(-Vector3::unit_z())
+    }
+}
+
+/// The default limit on the pitch of cameras, slightly short of looking
+/// straight up or down, where the view would flip over.
+fn default_max_pitch<S: BaseFloat>() -> Rad<S> {
+    Deg::new(cast(89).unwrap()).into()
+}
+
+fn clamp<S: BaseFloat>(angle: Rad<S>, max: Rad<S>) -> Rad<S> {
+    Rad::new(angle.s.max(-max.s).min(max.s))
+}
+
+/// The orientation for the given yaw about the `y` axis, followed by pitch
+/// about the camera's local `x` axis.
+fn yaw_pitch<S: BaseFloat>(yaw: Rad<S>, pitch: Rad<S>) -> Quaternion<S> {
+    Quaternion::from_angle_y(yaw) * Quaternion::from_angle_x(pitch)
+}
+
+/// A first-person camera, which looks around by turning left and right (yaw)
+/// and up and down (pitch), without ever rolling.
+///
+/// With a yaw and pitch of zero, the camera looks along the negative `z`
+/// axis. Positive yaw turns to the left, and positive pitch looks up.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct FirstPerson<S> {
+    pub position: Point3<S>,
+    pub yaw: Rad<S>,
+    pub pitch: Rad<S>,
+    /// The largest angle the camera can look up or down by. Defaults to 89
+    /// degrees.
+    pub max_pitch: Rad<S>,
+}
+
+impl_eders!(<S> FirstPerson { position, yaw, pitch, max_pitch });
+
+impl<S: BaseFloat> FirstPerson<S> {
+    /// Construct a new camera. The pitch is clamped to the default limit.
+    pub fn new(position: Point3<S>, yaw: Rad<S>, pitch: Rad<S>) -> FirstPerson<S> {
+        let max_pitch = default_max_pitch();
+        FirstPerson {
+            position: position,
+            yaw: yaw,
+            pitch: clamp(pitch, max_pitch),
+            max_pitch: max_pitch,
+        }
+    }
+
+    /// Turn the camera, clamping the resulting pitch to `max_pitch`.
+    #[inline]
+    pub fn rotate(&mut self, yaw: Rad<S>, pitch: Rad<S>) {
+        self.yaw = self.yaw + yaw;
+        self.pitch = clamp(self.pitch + pitch, self.max_pitch);
+    }
+
+    /// The direction to the right of the camera, which is always horizontal.
+    #[inline]
+    pub fn right(&self) -> Vector3<S> {
+        let (s, c) = Rad::sin_cos(self.yaw);
+        Vector3::new(c, S::zero(), -s)
+    }
+
+    /// Move the camera forwards, to the right, and upwards. Forward movement
+    /// is horizontal, regardless of the pitch, and upward movement is along
+    /// the `y` axis.
+    pub fn move_by(&mut self, forward: S, right: S, up: S) {
+        let (s, c) = Rad::sin_cos(self.yaw);
+        let horizontal = Vector3::new(-s, S::zero(), -c);
+        self.position = self.position + horizontal * forward + self.right() * right + Vector3::unit_y() * up;
+    }
+}
+
+impl<S: BaseFloat> Camera<S> for FirstPerson<S> {
+    #[inline]
+    fn transform(&self) -> Decomposed<Vector3<S>, Quaternion<S>> {
+        Decomposed {
+            scale: S::one(),
+            rot: yaw_pitch(self.yaw, self.pitch),
+            disp: self.position.to_vec(),
+        }
+    }
+}
+
+/// A camera orbiting around a target point, such as in a model viewer.
+///
+/// The position of the camera is given by spherical coordinates around the
+/// target: the yaw is the angle around the `y` axis, and the pitch is the
+/// elevation above the horizontal plane. With a yaw and pitch of zero, the
+/// camera lies on the positive `z` axis from the target.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct Orbit<S> {
+    pub target: Point3<S>,
+    pub distance: S,
+    pub yaw: Rad<S>,
+    pub pitch: Rad<S>,
+    /// The largest angle the camera can be above or below the target by.
+    /// Defaults to 89 degrees.
+    pub max_pitch: Rad<S>,
+    /// The closest the camera can zoom in to the target. Defaults to zero.
+    pub min_distance: S,
+    /// The furthest the camera can zoom out from the target. Defaults to
+    /// infinity.
+    pub max_distance: S,
+}
+
+impl_eders!(<S> Orbit { target, distance, yaw, pitch, max_pitch, min_distance, max_distance });
+
+impl<S: BaseFloat> Orbit<S> {
+    /// Construct a new camera. The pitch is clamped to the default limit.
+    pub fn new(target: Point3<S>, distance: S, yaw: Rad<S>, pitch: Rad<S>) -> Orbit<S> {
+        let max_pitch = default_max_pitch();
+        Orbit {
+            target: target,
+            distance: distance,
+            yaw: yaw,
+            pitch: clamp(pitch, max_pitch),
+            max_pitch: max_pitch,
+            min_distance: S::zero(),
+            max_distance: S::infinity(),
+        }
+    }
+
+    /// Orbit around the target, clamping the resulting pitch to `max_pitch`.
+    #[inline]
+    pub fn rotate(&mut self, yaw: Rad<S>, pitch: Rad<S>) {
+        self.yaw = self.yaw + yaw;
+        self.pitch = clamp(self.pitch + pitch, self.max_pitch);
+    }
+
+    /// Multiply the distance from the target by `factor`, clamping it between
+    /// `min_distance` and `max_distance`. Factors less than one zoom in.
+    #[inline]
+    pub fn zoom(&mut self, factor: S) {
+        self.distance = (self.distance * factor).max(self.min_distance).min(self.max_distance);
+    }
+
+    /// Move the target, and the camera with it, to the right and up relative
+    /// to the view.
+    pub fn pan(&mut self, right: S, up: S) {
+        let rot = self.transform().rot;
+        self.target = self.target + rot.rotate_vector(Vector3::new(right, up, S::zero()));
+    }
+}
+
+impl<S: BaseFloat> Camera<S> for Orbit<S> {
+    fn transform(&self) -> Decomposed<Vector3<S>, Quaternion<S>> {
+        // Looking down at the target when above it
+        let rot = yaw_pitch(self.yaw, -self.pitch);
+        Decomposed {
+            scale: S::one(),
+            rot: rot,
+            disp: self.target.to_vec() + rot.rotate_vector(Vector3::unit_z() * self.distance),
+        }
+    }
+}
+
+/// An arcball, or virtual trackball, for rotating an object around a target
+/// by dragging it with the mouse.
+///
+/// Drags are given in normalized coordinates, with `(0, 0)` at the center of
+/// the arcball, `x` pointing right, `y` pointing up, and a radius of `1`.
+/// Points within the radius are projected onto the front of a sphere, and
+/// dragging between two points rotates the sphere to keep it under the
+/// cursor. Points outside of the radius rotate around the view axis.
+///
+/// The camera looks at the target from `distance` along the positive `z` axis,
+/// with the object rotated by `rotation`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct Arcball<S> {
+    pub target: Point3<S>,
+    pub distance: S,
+    /// The rotation of the object around the target, relative to the view.
+    pub rotation: Quaternion<S>,
+}
+
+impl_eders!(<S> Arcball { target, distance, rotation });
+
+impl<S: BaseFloat> Arcball<S> {
+    /// Construct a new arcball, with the object unrotated.
+    #[inline]
+    pub fn new(target: Point3<S>, distance: S) -> Arcball<S> {
+        Arcball {
+            target: target,
+            distance: distance,
+            rotation: Quaternion::one(),
+        }
+    }
+
+    /// Project a point in normalized coordinates onto the unit sphere.
+    pub fn project(point: Vector2<S>) -> Vector3<S> {
+        let r2 = point.magnitude2();
+        if r2 <= S::one() {
+            Vector3::new(point.x, point.y, (S::one() - r2).sqrt())
+        } else {
+            point.normalize().extend(S::zero())
+        }
+    }
+
+    /// The rotation that drags the sphere from under `from` to under `to`.
+    ///
+    /// Dragging between opposite points on the rim of the sphere is a half
+    /// turn around the view axis.
+    pub fn drag_rotation(from: Vector2<S>, to: Vector2<S>) -> Quaternion<S> {
+        let (a, b) = (Arcball::project(from), Arcball::project(to));
+        // Opposite points are only reached on the rim, where the view axis is
+        // perpendicular to both of them
+        if S::one() + a.dot(b) <= S::epsilon() {
+            return Quaternion::from_sv(S::zero(), Vector3::unit_z());
+        }
+        Quaternion::between_vectors(a, b)
+    }
+
+    /// Rotate the object by dragging it from `from` to `to`.
+    #[inline]
+    pub fn drag(&mut self, from: Vector2<S>, to: Vector2<S>) {
+        self.rotation = (Arcball::drag_rotation(from, to) * self.rotation).normalize();
+    }
+
+    /// Multiply the distance from the target by `factor`. Factors less than
+    /// one zoom in.
+    #[inline]
+    pub fn zoom(&mut self, factor: S) {
+        self.distance = self.distance * factor;
+    }
+}
+
+impl<S: BaseFloat> Camera<S> for Arcball<S> {
+    fn transform(&self) -> Decomposed<Vector3<S>, Quaternion<S>> {
+        // Rotating the object is equivalent to rotating the camera the
+        // opposite way around it
+        let rot = self.rotation.invert();
+        Decomposed {
+            scale: S::one(),
+            rot: rot,
+            disp: self.target.to_vec() + rot.rotate_vector(Vector3::unit_z() * self.distance),
+        }
+    }
+}
+
+/// A pitch uniformly distributed between the default limits.
+#[cfg(feature = "quickcheck")]
+fn arbitrary_pitch<S: BaseFloat, G: Gen>(g: &mut G) -> Rad<S> {
+    default_max_pitch() * cast(g.gen_range(-1.0f64, 1.0)).unwrap()
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for FirstPerson<S> {
+    /// Generate a camera with an arbitrary position and yaw, and a pitch
+    /// uniformly distributed within the default limit.
+    fn arbitrary<G: Gen>(g: &mut G) -> FirstPerson<S> {
+        FirstPerson::new(Point3::arbitrary(g), Rad::arbitrary(g), arbitrary_pitch(g))
+    }
+
+    /// Shrink each field in turn, only moving the pitch towards zero.
+    fn shrink(&self) -> Box<Iterator<Item = FirstPerson<S>>> {
+        let pitch = self.pitch.s.abs();
+        Box::new(shrink_fields!(self, { position, yaw, pitch })
+            .filter(move |camera| camera.pitch.s.abs() <= pitch))
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for Orbit<S> {
+    /// Generate a camera with an arbitrary target, yaw and non-negative
+    /// distance, and a pitch uniformly distributed within the default limit.
+    fn arbitrary<G: Gen>(g: &mut G) -> Orbit<S> {
+        Orbit::new(Point3::arbitrary(g), S::arbitrary(g).abs(), Rad::arbitrary(g), arbitrary_pitch(g))
+    }
+
+    /// Shrink each field in turn, only moving the pitch towards zero.
+    fn shrink(&self) -> Box<Iterator<Item = Orbit<S>>> {
+        let pitch = self.pitch.s.abs();
+        Box::new(shrink_fields!(self, { target, distance, yaw, pitch })
+            .filter(|camera| camera.distance >= S::zero())
+            .filter(move |camera| camera.pitch.s.abs() <= pitch))
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for Arcball<S> {
+    /// Generate an arcball with an arbitrary target and non-negative distance,
+    /// and a rotation that is uniformly distributed over the space of
+    /// rotations.
+    fn arbitrary<G: Gen>(g: &mut G) -> Arcball<S> {
+        Arcball {
+            target: Point3::arbitrary(g),
+            distance: S::arbitrary(g).abs(),
+            rotation: Quaternion::arbitrary(g),
+        }
+    }
+
+    fn shrink(&self) -> Box<Iterator<Item = Arcball<S>>> {
+        Box::new(shrink_fields!(self, { target, distance, rotation })
+            .filter(|camera| camera.distance >= S::zero()))
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A compact serialization format, matching the arrays used by glTF and most
+//! other tools.
+//!
+//! The `Serialize` and `Deserialize` implementations of the types themselves
+//! use a map from the names of their fields to their values, such as
+//! `{"x":1.0,"y":2.0,"z":3.0}`. The compact format instead uses:
+//!
+//! | Type                           | Compact format                        |
+//! |--------------------------------|---------------------------------------|
+//! | Vectors and points             | `[x, y, z]`                           |
+//! | Matrices                       | A flat array, in column-major order   |
+//! | `Quaternion`                   | `[x, y, z, w]`, with the scalar last  |
+//! | `Rad`, `Deg`, `Turns`, `Grad`  | A single number                       |
+//!
+//! The format can be selected for a value by wrapping it in `Compact`, or for
+//! a field of a derived struct by passing `serialize` and `deserialize` to the
+//! `serialize_with` and `deserialize_with` attributes.
+//!
+//! ```rust
+//! extern crate cgmath;
+//! extern crate serde_json;
+//!
+//! use cgmath::{Matrix2, Vector3};
+//! use cgmath::compact::Compact;
+//!
+//! # fn main() {
+//! let json = serde_json::to_string(&Compact(Vector3::new(1, 2, 3))).unwrap();
+//! assert_eq!(json, "[1,2,3]");
+//!
+//! let json = serde_json::to_string(&Compact(Matrix2::new(1.0, 2.0, 3.0, 4.0))).unwrap();
+//! assert_eq!(json, "[1.0,2.0,3.0,4.0]");
+//!
+//! let Compact(v): Compact<Vector3<i32>> = serde_json::from_str("[4,5,6]").unwrap();
+//! assert_eq!(v, Vector3::new(4, 5, 6));
+//! # }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use angle::{Deg, Grad, Rad, Turns};
+use matrix::{Matrix2, Matrix3, Matrix4};
+use num::BaseNum;
+use point::{Point1, Point2, Point3};
+use quaternion::Quaternion;
+use vector::{Vector1, Vector2, Vector3, Vector4};
+
+/// A type that can be serialized in the compact format.
+pub trait CompactFormat: Sized {
+    /// Serialize the value in the compact format.
+    fn serialize_compact<Ser>(&self, serializer: &mut Ser) -> Result<(), Ser::Error> where
+        Ser: Serializer;
+
+    /// Deserialize a value from the compact format.
+    fn deserialize_compact<De>(deserializer: &mut De) -> Result<Self, De::Error> where
+        De: Deserializer;
+}
+
+/// A wrapper selecting the compact format for the value it contains.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Compact<T>(pub T);
+
+impl<T: CompactFormat> Serialize for Compact<T> {
+    #[inline]
+    fn serialize<Ser>(&self, serializer: &mut Ser) -> Result<(), Ser::Error> where
+        Ser: Serializer,
+    {
+        self.0.serialize_compact(serializer)
+    }
+}
+
+impl<T: CompactFormat> Deserialize for Compact<T> {
+    #[inline]
+    fn deserialize<De>(deserializer: &mut De) -> Result<Compact<T>, De::Error> where
+        De: Deserializer,
+    {
+        T::deserialize_compact(deserializer).map(Compact)
+    }
+}
+
+/// Serialize a value in the compact format, for use with the `serialize_with`
+/// attribute.
+#[inline]
+pub fn serialize<T, Ser>(value: &T, serializer: &mut Ser) -> Result<(), Ser::Error> where
+    T: CompactFormat,
+    Ser: Serializer,
+{
+    value.serialize_compact(serializer)
+}
+
+/// Deserialize a value from the compact format, for use with the
+/// `deserialize_with` attribute.
+#[inline]
+pub fn deserialize<T, De>(deserializer: &mut De) -> Result<T, De::Error> where
+    T: CompactFormat,
+    De: Deserializer,
+{
+    T::deserialize_compact(deserializer)
+}
+
+macro_rules! impl_compact_array {
+    ($Type:ident, $n:expr) => {
+        impl<S: BaseNum + Serialize + Deserialize> CompactFormat for $Type<S> {
+            #[inline]
+            fn serialize_compact<Ser>(&self, serializer: &mut Ser) -> Result<(), Ser::Error> where
+                Ser: Serializer,
+            {
+                let array: &[S; $n] = self.as_ref();
+                array.serialize(serializer)
+            }
+
+            #[inline]
+            fn deserialize_compact<De>(deserializer: &mut De) -> Result<$Type<S>, De::Error> where
+                De: Deserializer,
+            {
+                <[S; $n]>::deserialize(deserializer).map($Type::from)
+            }
+        }
+    }
+}
+
+impl_compact_array!(Vector1, 1);
+impl_compact_array!(Vector2, 2);
+impl_compact_array!(Vector3, 3);
+impl_compact_array!(Vector4, 4);
+
+impl_compact_array!(Point1, 1);
+impl_compact_array!(Point2, 2);
+impl_compact_array!(Point3, 3);
+
+macro_rules! impl_compact_matrix {
+    ($MatrixN:ident, $n:expr) => {
+        impl<S: BaseNum + Serialize + Deserialize> CompactFormat for $MatrixN<S> {
+            fn serialize_compact<Ser>(&self, serializer: &mut Ser) -> Result<(), Ser::Error> where
+                Ser: Serializer,
+            {
+                let cols: &[[S; $n]; $n] = self.as_ref();
+                let mut flat = [S::zero(); $n * $n];
+                for (i, col) in cols.iter().enumerate() {
+                    flat[i * $n..(i + 1) * $n].copy_from_slice(col);
+                }
+                flat.serialize(serializer)
+            }
+
+            fn deserialize_compact<De>(deserializer: &mut De) -> Result<$MatrixN<S>, De::Error> where
+                De: Deserializer,
+            {
+                let flat = try!(<[S; $n * $n]>::deserialize(deserializer));
+                let mut cols = [[S::zero(); $n]; $n];
+                for (i, col) in cols.iter_mut().enumerate() {
+                    col.copy_from_slice(&flat[i * $n..(i + 1) * $n]);
+                }
+                Ok(cols.into())
+            }
+        }
+    }
+}
+
+impl_compact_matrix!(Matrix2, 2);
+impl_compact_matrix!(Matrix3, 3);
+impl_compact_matrix!(Matrix4, 4);
+
+/// The scalar part is last, following glTF, rather than first as in the
+/// array conversions of `Quaternion`.
+impl<S: BaseNum + Serialize + Deserialize> CompactFormat for Quaternion<S> {
+    #[inline]
+    fn serialize_compact<Ser>(&self, serializer: &mut Ser) -> Result<(), Ser::Error> where
+        Ser: Serializer,
+    {
+        [self.v.x, self.v.y, self.v.z, self.s].serialize(serializer)
+    }
+
+    #[inline]
+    fn deserialize_compact<De>(deserializer: &mut De) -> Result<Quaternion<S>, De::Error> where
+        De: Deserializer,
+    {
+        let v = try!(<[S; 4]>::deserialize(deserializer));
+        Ok(Quaternion { s: v[3], v: Vector3::new(v[0], v[1], v[2]) })
+    }
+}
+
+macro_rules! impl_compact_angle {
+    ($Angle:ident) => {
+        impl<S: Serialize + Deserialize> CompactFormat for $Angle<S> {
+            #[inline]
+            fn serialize_compact<Ser>(&self, serializer: &mut Ser) -> Result<(), Ser::Error> where
+                Ser: Serializer,
+            {
+                self.s.serialize(serializer)
+            }
+
+            #[inline]
+            fn deserialize_compact<De>(deserializer: &mut De) -> Result<$Angle<S>, De::Error> where
+                De: Deserializer,
+            {
+                S::deserialize(deserializer).map(|s| $Angle { s: s })
+            }
+        }
+    }
+}
+
+impl_compact_angle!(Rad);
+impl_compact_angle!(Deg);
+impl_compact_angle!(Turns);
+impl_compact_angle!(Grad);
@@ -66,18 +66,27 @@ pub fn array4<T, A: Into<[T; 4]>>(value: A) -> [T; 4] {
 }
 
 /// Force a conversion into a 2x2-element array.
+///
+/// Matrices are converted to a list of their columns, in column-major order.
+/// Use `to_rows_array` for row-major order.
 #[inline]
 pub fn array2x2<T, A: Into<[[T; 2]; 2]>>(value: A) -> [[T; 2]; 2] {
     value.into()
 }
 
 /// Force a conversion into a 3x3-element array.
+///
+/// Matrices are converted to a list of their columns, in column-major order.
+/// Use `to_rows_array` for row-major order.
 #[inline]
 pub fn array3x3<T, A: Into<[[T; 3]; 3]>>(value: A) -> [[T; 3]; 3] {
     value.into()
 }
 
 /// Force a conversion into a 4x4-element array.
+///
+/// Matrices are converted to a list of their columns, in column-major order.
+/// Use `to_rows_array` for row-major order.
 #[inline]
 pub fn array4x4<T, A: Into<[[T; 4]; 4]>>(value: A) -> [[T; 4]; 4] {
     value.into()
@@ -0,0 +1,224 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use structure::*;
+
+use matrix::{Matrix3, Matrix4};
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::{Basis3, Rotation3};
+use transform::Decomposed;
+use vector::Vector3;
+
+/// The orientation of the axes of a coordinate system.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub enum Handedness {
+    /// `x × y = z`, following the right-hand rule.
+    Right,
+    /// `x × y = -z`, following the left-hand rule.
+    Left,
+}
+
+impl_eders_enum!(Handedness { Right, Left });
+impl_arbitrary_enum!(Handedness { Right, Left });
+
+/// The axis of a coordinate system pointing upwards.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub enum UpAxis {
+    Y,
+    Z,
+}
+
+impl_eders_enum!(UpAxis { Y, Z });
+impl_arbitrary_enum!(UpAxis { Y, Z });
+
+/// A convention for the directions of the axes of a three dimensional
+/// coordinate system.
+///
+/// In every convention the x axis points to the right. The remaining axes are:
+///
+/// | Convention        | y        | z        | Used by              |
+/// |-------------------|----------|----------|----------------------|
+/// | Y-up right-handed | up       | backward | cgmath, OpenGL, glTF |
+/// | Y-up left-handed  | up       | forward  | Direct3D, Unity      |
+/// | Z-up right-handed | forward  | up       | Blender, 3ds Max     |
+/// | Z-up left-handed  | backward | up       |                      |
+///
+/// The functions in cgmath, such as `Matrix4::look_at`, `perspective` and the
+/// conversions from `Euler` angles, assume the Y-up right-handed convention.
+/// Values in other conventions can be re-expressed in it using
+/// `ChangeConvention`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct Convention {
+    pub handedness: Handedness,
+    pub up: UpAxis,
+}
+
+impl_eders!(<> Convention { handedness, up });
+impl_arbitrary!(<> Convention { handedness, up });
+
+impl Convention {
+    /// Construct a new convention.
+    #[inline]
+    pub fn new(handedness: Handedness, up: UpAxis) -> Convention {
+        Convention { handedness: handedness, up: up }
+    }
+
+    /// The Y-up right-handed convention assumed by cgmath.
+    #[inline]
+    pub fn y_up_right_handed() -> Convention {
+        Convention::new(Handedness::Right, UpAxis::Y)
+    }
+
+    /// The Y-up left-handed convention.
+    #[inline]
+    pub fn y_up_left_handed() -> Convention {
+        Convention::new(Handedness::Left, UpAxis::Y)
+    }
+
+    /// The Z-up right-handed convention.
+    #[inline]
+    pub fn z_up_right_handed() -> Convention {
+        Convention::new(Handedness::Right, UpAxis::Z)
+    }
+
+    /// The Z-up left-handed convention.
+    #[inline]
+    pub fn z_up_left_handed() -> Convention {
+        Convention::new(Handedness::Left, UpAxis::Z)
+    }
+
+    /// The axes of this convention, expressed in the Y-up right-handed
+    /// convention.
+    pub fn basis<S: BaseFloat>(self) -> Matrix3<S> {
+        let right = Vector3::unit_x();
+        let up = Vector3::unit_y();
+        let back = Vector3::unit_z();
+
+        match (self.up, self.handedness) {
+            (UpAxis::Y, Handedness::Right) => Matrix3::from_cols(right, up, back),
+            (UpAxis::Y, Handedness::Left) => Matrix3::from_cols(right, up, -back),
+            (UpAxis::Z, Handedness::Right) => Matrix3::from_cols(right, -back, up),
+            (UpAxis::Z, Handedness::Left) => Matrix3::from_cols(right, back, up),
+        }
+    }
+
+    /// The change of basis matrix, re-expressing vectors in this convention
+    /// in the `other` convention.
+    #[inline]
+    pub fn conversion_to<S: BaseFloat>(self, other: Convention) -> Matrix3<S> {
+        // The bases are orthonormal, so the transpose is the inverse
+        other.basis::<S>().transpose() * self.basis()
+    }
+}
+
+/// A value that can be re-expressed in a different coordinate system
+/// convention.
+///
+/// ```rust
+/// use cgmath::prelude::*;
+/// use cgmath::{Convention, Vector3};
+///
+/// let blender = Convention::z_up_right_handed();
+/// let unity = Convention::y_up_left_handed();
+///
+/// // Up is up, and forward is forward
+/// assert_eq!(Vector3::new(0.0, 0.0, 1.0).change_convention(blender, unity),
+///            Vector3::new(0.0, 1.0, 0.0));
+/// assert_eq!(Vector3::new(0.0, 1.0, 0.0).change_convention(blender, unity),
+///            Vector3::new(0.0, 0.0, 1.0));
+/// ```
+pub trait ChangeConvention: Sized {
+    /// Re-express the value, which is in the convention `from`, in the
+    /// convention `to`. The result describes the same physical quantity.
+    fn change_convention(self, from: Convention, to: Convention) -> Self;
+}
+
+impl<S: BaseFloat> ChangeConvention for Vector3<S> {
+    #[inline]
+    fn change_convention(self, from: Convention, to: Convention) -> Vector3<S> {
+        from.conversion_to::<S>(to) * self
+    }
+}
+
+impl<S: BaseFloat> ChangeConvention for Point3<S> {
+    #[inline]
+    fn change_convention(self, from: Convention, to: Convention) -> Point3<S> {
+        Point3::from_vec(self.to_vec().change_convention(from, to))
+    }
+}
+
+/// Re-expresses a linear transformation, so that it describes the same
+/// transformation of the re-expressed vectors.
+impl<S: BaseFloat> ChangeConvention for Matrix3<S> {
+    #[inline]
+    fn change_convention(self, from: Convention, to: Convention) -> Matrix3<S> {
+        let m = from.conversion_to::<S>(to);
+        m * self * m.transpose()
+    }
+}
+
+/// Re-expresses a homogeneous transformation, so that it describes the same
+/// transformation of the re-expressed points.
+///
+/// This should not be used for projection matrices, as their output is in
+/// clip space rather than in either convention.
+impl<S: BaseFloat> ChangeConvention for Matrix4<S> {
+    #[inline]
+    fn change_convention(self, from: Convention, to: Convention) -> Matrix4<S> {
+        let m = from.conversion_to::<S>(to);
+        Matrix4::from(m) * self * Matrix4::from(m.transpose())
+    }
+}
+
+/// The axis of rotation is re-expressed, and reversed if the handedness
+/// changes, so the quaternion describes the same physical rotation.
+impl<S: BaseFloat> ChangeConvention for Quaternion<S> {
+    #[inline]
+    fn change_convention(self, from: Convention, to: Convention) -> Quaternion<S> {
+        let v = self.v.change_convention(from, to);
+        if from.handedness == to.handedness {
+            Quaternion::from_sv(self.s, v)
+        } else {
+            Quaternion::from_sv(self.s, -v)
+        }
+    }
+}
+
+impl<S: BaseFloat> ChangeConvention for Basis3<S> {
+    #[inline]
+    fn change_convention(self, from: Convention, to: Convention) -> Basis3<S> {
+        Basis3::from(Quaternion::from(self).change_convention(from, to))
+    }
+}
+
+impl<S, R> ChangeConvention for Decomposed<Vector3<S>, R> where
+    S: BaseFloat,
+    R: Rotation3<S> + From<Quaternion<S>>,
+{
+    #[inline]
+    fn change_convention(self, from: Convention, to: Convention) -> Decomposed<Vector3<S>, R> {
+        let rot: Quaternion<S> = self.rot.into();
+        Decomposed {
+            scale: self.scale,
+            rot: R::from(rot.change_convention(from, to)),
+            disp: self.disp.change_convention(from, to),
+        }
+    }
+}
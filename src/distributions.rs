@@ -0,0 +1,160 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Probability distributions over vectors, points and rotations, for use with
+//! the `Sample` and `IndependentSample` traits of `rand`.
+//!
+//! The `Rand` implementations of the cgmath types each pick a single
+//! distribution, which is not always the one you want: `rng.gen()` fills a
+//! vector with components in `[0, 1)`, which is rarely useful as a direction.
+//! These distributions let the caller say which one they mean:
+//!
+//! | Distribution       | Types                               | Values                                          |
+//! |--------------------|-------------------------------------|-------------------------------------------------|
+//! | `UnitComponents`   | Vectors, points, quaternions        | Each component uniform in `[0, 1)`              |
+//! | `StandardNormal`   | Vectors, points, quaternions        | Each component drawn from `N(0, 1)`             |
+//! | `UnitSphere`       | `Vector2`, `Vector3`                | Uniform on the unit circle or sphere            |
+//! | `UniformRotation`  | 2D and 3D rotations                 | Uniform over the space of rotations             |
+//!
+//! `UniformRotation` supports `Basis2`, `Matrix2` and `UnitComplex` for
+//! rotations in the plane, and `Quaternion`, `Rotor3`, `Basis3`, `Matrix3` and
+//! `Euler<Rad<S>>` for rotations in space.
+//!
+//! ```rust
+//! extern crate rand;
+//! extern crate cgmath;
+//!
+//! use rand::distributions::IndependentSample;
+//! use cgmath::prelude::*;
+//! use cgmath::{ApproxEq, Quaternion, Vector3};
+//! use cgmath::distributions::{UniformRotation, UnitSphere};
+//!
+//! # fn main() {
+//! let mut rng = rand::thread_rng();
+//!
+//! let direction: Vector3<f64> = UnitSphere.ind_sample(&mut rng);
+//! assert!(direction.magnitude().approx_eq(&1.0));
+//!
+//! let rotation: Quaternion<f64> = UniformRotation.ind_sample(&mut rng);
+//! let rotated = rotation.rotate_vector(direction);
+//! # }
+//! ```
+
+use num_traits::cast;
+use rand::{Rand, Rng};
+use rand::distributions::{IndependentSample, Sample};
+use rand::distributions::normal;
+
+use angle::Rad;
+use euler::Euler;
+use matrix::{Matrix2, Matrix3};
+use num::BaseFloat;
+use point::{Point1, Point2, Point3};
+use quaternion::Quaternion;
+use rotation::{Basis2, Basis3, Rotation2};
+use rotor::Rotor3;
+use sample;
+use unit_complex::UnitComplex;
+use vector::{Vector1, Vector2, Vector3, Vector4};
+
+/// Each component drawn independently and uniformly from `[0, 1)`.
+///
+/// This is the distribution used by the `Rand` implementations of vectors and
+/// points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnitComponents;
+
+/// Each component drawn independently from the standard normal distribution,
+/// with a mean of zero and a standard deviation of one.
+///
+/// The distribution of the resulting vectors is spherically symmetric, so
+/// their directions are uniformly distributed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct StandardNormal;
+
+/// Unit vectors drawn uniformly from the unit circle, for `Vector2`, or the
+/// surface of the unit sphere, for `Vector3`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnitSphere;
+
+/// Rotations drawn uniformly from the space of all rotations.
+///
+/// In the plane this is a uniformly distributed angle. In space it is the Haar
+/// measure on rotations, which is not the same as choosing uniformly
+/// distributed Euler angles or a uniformly distributed axis and angle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UniformRotation;
+
+/// Draw a scalar from the standard normal distribution.
+#[inline]
+fn standard_normal<S: BaseFloat, R: Rng>(rng: &mut R) -> S {
+    let normal::StandardNormal(x) = rng.gen();
+    cast(x).unwrap()
+}
+
+// The distributions are stateless, so `Sample` just forwards to
+// `IndependentSample`.
+macro_rules! impl_sample {
+    ($Distribution:ident, $Type:ty, |$rng:ident| $body:expr) => {
+        impl<S: BaseFloat + Rand> Sample<$Type> for $Distribution {
+            #[inline]
+            fn sample<R: Rng>(&mut self, rng: &mut R) -> $Type {
+                self.ind_sample(rng)
+            }
+        }
+
+        impl<S: BaseFloat + Rand> IndependentSample<$Type> for $Distribution {
+            #[inline]
+            fn ind_sample<R: Rng>(&self, $rng: &mut R) -> $Type {
+                $body
+            }
+        }
+    }
+}
+
+macro_rules! impl_componentwise {
+    ($($Type:ident { $($field:ident),+ }),+) => {
+        $(
+            impl_sample!(UnitComponents, $Type<S>, |rng| $Type { $($field: rng.gen()),+ });
+            impl_sample!(StandardNormal, $Type<S>, |rng| $Type { $($field: standard_normal(rng)),+ });
+        )+
+    }
+}
+
+impl_componentwise!(Vector1 { x }, Vector2 { x, y }, Vector3 { x, y, z }, Vector4 { x, y, z, w });
+impl_componentwise!(Point1 { x }, Point2 { x, y }, Point3 { x, y, z });
+
+impl_sample!(UnitComponents, Quaternion<S>, |rng| {
+    Quaternion { s: rng.gen(), v: UnitComponents.ind_sample(rng) }
+});
+impl_sample!(StandardNormal, Quaternion<S>, |rng| {
+    Quaternion { s: standard_normal(rng), v: StandardNormal.ind_sample(rng) }
+});
+
+impl_sample!(UnitSphere, Vector2<S>, |rng| sample::on_unit_circle(rng));
+impl_sample!(UnitSphere, Vector3<S>, |rng| sample::on_unit_sphere(rng));
+
+impl_sample!(UniformRotation, UnitComplex<S>, |rng| {
+    let v: Vector2<S> = sample::on_unit_circle(rng);
+    UnitComplex::new(v.x, v.y)
+});
+impl_sample!(UniformRotation, Basis2<S>, |rng| Basis2::from_angle(sample::turn(rng)));
+impl_sample!(UniformRotation, Matrix2<S>, |rng| Matrix2::from_angle(sample::turn(rng)));
+
+impl_sample!(UniformRotation, Quaternion<S>, |rng| sample::unit_quaternion(rng));
+impl_sample!(UniformRotation, Rotor3<S>, |rng| sample::unit_quaternion(rng).into());
+impl_sample!(UniformRotation, Basis3<S>, |rng| sample::basis3(rng));
+impl_sample!(UniformRotation, Matrix3<S>, |rng| sample::unit_quaternion(rng).into());
+impl_sample!(UniformRotation, Euler<Rad<S>>, |rng| sample::unit_quaternion(rng).into());
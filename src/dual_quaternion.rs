@@ -0,0 +1,375 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::ops::*;
+use core::str::FromStr;
+
+use num_traits::cast;
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
+
+use structure::*;
+
+use angle::Rad;
+#[cfg(feature = "quickcheck")]
+use arbitrary;
+use approx::ApproxEq;
+use format::{self, ParseError};
+use matrix::{Matrix3, Matrix4};
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::Rotation;
+use transform::{Decomposed, Transform};
+use vector::{Vector3, Vector4};
+
+/// A [dual quaternion](https://en.wikipedia.org/wiki/Dual_quaternion),
+/// representing a rigid transformation made up of a rotation followed by a
+/// translation.
+///
+/// Unlike other representations of rigid transformations, dual quaternions
+/// can be blended together without introducing shearing or scaling, making
+/// them particularly useful for skinning meshes.
+///
+/// Most operations assume that the dual quaternion is normalized. That is,
+/// that the real part is a unit quaternion, and is orthogonal to the dual
+/// part.
+///
+/// This type is marked as `#[repr(C, packed)]`.
+#[repr(C, packed)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct DualQuaternion<S> {
+    /// The real part, describing the rotation.
+    pub real: Quaternion<S>,
+    /// The dual part, describing the translation in combination with the
+    /// rotation.
+    pub dual: Quaternion<S>,
+}
+
+impl_eders!(<S> DualQuaternion { real, dual });
+
+impl<S: BaseFloat> DualQuaternion<S> {
+    /// Construct a new dual quaternion from its real and dual parts.
+    #[inline]
+    pub fn new(real: Quaternion<S>, dual: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion { real: real, dual: dual }
+    }
+
+    /// Create a dual quaternion that rotates by a unit quaternion.
+    #[inline]
+    pub fn from_rotation(rotation: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion::new(rotation, Quaternion::from_sv(S::zero(), Vector3::zero()))
+    }
+
+    /// Create a dual quaternion that translates by a vector.
+    #[inline]
+    pub fn from_translation(translation: Vector3<S>) -> DualQuaternion<S> {
+        DualQuaternion::from_rotation_translation(Quaternion::one(), translation)
+    }
+
+    /// Create a dual quaternion that rotates by a unit quaternion, then
+    /// translates by a vector.
+    #[inline]
+    pub fn from_rotation_translation(rotation: Quaternion<S>, translation: Vector3<S>) -> DualQuaternion<S> {
+        let half: S = cast(0.5f64).unwrap();
+        let dual = Quaternion::from_sv(S::zero(), translation) * rotation * half;
+        DualQuaternion::new(rotation, dual)
+    }
+
+    /// Convert a `Decomposed` transform to a dual quaternion.
+    ///
+    /// Returns `None` if the transform has a scale other than `1`, as dual
+    /// quaternions can only represent rigid transformations.
+    pub fn from_decomposed(transform: &Decomposed<Vector3<S>, Quaternion<S>>) -> Option<DualQuaternion<S>> {
+        if transform.scale.approx_eq(&S::one()) {
+            Some(DualQuaternion::from_rotation_translation(transform.rot, transform.disp))
+        } else {
+            None
+        }
+    }
+
+    /// Convert a homogeneous transformation matrix to a dual quaternion.
+    ///
+    /// Returns `None` if the matrix does not describe a rigid transformation.
+    /// That is, if it contains scaling, shearing, reflection or a projection.
+    pub fn from_matrix4(m: &Matrix4<S>) -> Option<DualQuaternion<S>> {
+        if !m.row(3).approx_eq(&Vector4::unit_w()) {
+            return None;
+        }
+
+        let rotation = Matrix3::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate());
+        if !rotation.is_orthonormal() || rotation.determinant() < S::zero() {
+            return None;
+        }
+
+        Some(DualQuaternion::from_rotation_translation(rotation.into(), m.w.truncate()))
+    }
+
+    /// The rotation described by the dual quaternion.
+    #[inline]
+    pub fn rotation(&self) -> Quaternion<S> {
+        self.real
+    }
+
+    /// The translation described by the dual quaternion, applied after the
+    /// rotation.
+    #[inline]
+    pub fn translation(&self) -> Vector3<S> {
+        let two: S = cast(2).unwrap();
+        (self.dual * self.real.conjugate()).v * two
+    }
+
+    /// The quaternion conjugate of both parts of the dual quaternion. For a
+    /// normalized dual quaternion, this is the inverse transformation.
+    #[inline]
+    pub fn conjugate(self) -> DualQuaternion<S> {
+        DualQuaternion::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// Normalize the dual quaternion, so that its real part has a magnitude
+    /// of one and is orthogonal to the dual part.
+    pub fn normalize(self) -> DualQuaternion<S> {
+        let magnitude = self.real.magnitude();
+        let real = self.real / magnitude;
+        let dual = self.dual / magnitude;
+        DualQuaternion::new(real, dual - real * real.dot(dual))
+    }
+
+    /// Describe the transformation as a
+    /// [screw motion](https://en.wikipedia.org/wiki/Screw_theory): a rotation
+    /// by `angle` around a line, combined with a translation by `pitch` along
+    /// it.
+    ///
+    /// The line is given in Plücker coordinates, as a unit direction `axis`,
+    /// and a `moment` equal to the cross product of any point on the line
+    /// with the axis. The result is returned as `(axis, angle, pitch, moment)`.
+    ///
+    /// For a pure translation, the axis is the direction of the translation,
+    /// and the moment is zero.
+    pub fn to_screw(&self) -> (Vector3<S>, Rad<S>, S, Vector3<S>) {
+        let two: S = cast(2).unwrap();
+        let sin_half = self.real.v.magnitude();
+
+        if sin_half.approx_eq(&S::zero()) {
+            let translation = self.translation();
+            let pitch = translation.magnitude();
+            let axis = if pitch.approx_eq(&S::zero()) { Vector3::zero() } else { translation / pitch };
+            return (axis, Rad::zero(), pitch, Vector3::zero());
+        }
+
+        let axis = self.real.v / sin_half;
+        let angle = Rad::atan2(sin_half, self.real.s) * two;
+        let pitch = -two * self.dual.s / sin_half;
+        let moment = (self.dual.v - axis * (pitch * self.real.s / two)) / sin_half;
+        (axis, angle, pitch, moment)
+    }
+
+    /// Construct a dual quaternion from the parameters of a screw motion. See
+    /// `to_screw` for their meaning.
+    pub fn from_screw(axis: Vector3<S>, angle: Rad<S>, pitch: S, moment: Vector3<S>) -> DualQuaternion<S> {
+        let two: S = cast(2).unwrap();
+        let half_pitch = pitch / two;
+        let (s, c) = Rad::sin_cos(angle / two);
+
+        DualQuaternion::new(Quaternion::from_sv(c, axis * s),
+                            Quaternion::from_sv(-half_pitch * s, moment * s + axis * (half_pitch * c)))
+    }
+
+    /// Raise the dual quaternion to a power, scaling both the angle and the
+    /// translation of its screw motion.
+    pub fn powf(self, exponent: S) -> DualQuaternion<S> {
+        let (axis, angle, pitch, moment) = self.to_screw();
+        DualQuaternion::from_screw(axis, angle * exponent, pitch * exponent, moment)
+    }
+
+    /// Screw linear interpolation (ScLERP) with `other`, by `amount`.
+    ///
+    /// This is the generalization of `Quaternion::slerp` to rigid
+    /// transformations, moving at a constant speed along the screw motion
+    /// between the two transformations. Both dual quaternions should be
+    /// normalized.
+    pub fn sclerp(self, other: DualQuaternion<S>, amount: S) -> DualQuaternion<S> {
+        // Take the shortest path
+        let other = if self.real.dot(other.real) < S::zero() { -other } else { other };
+        self * (self.conjugate() * other).powf(amount)
+    }
+
+    /// Dual quaternion linear blending (DLB) of the given weighted
+    /// transformations.
+    ///
+    /// This is a cheap approximation of interpolating between transformations
+    /// that is commonly used for skinning, as it is both fast and free of the
+    /// artifacts of linearly blending matrices. Each dual quaternion should be
+    /// normalized, and the weights should generally add up to one.
+    ///
+    /// Returns `None` if there are no transformations, or they cancel out.
+    pub fn blend(weighted: &[(S, DualQuaternion<S>)]) -> Option<DualQuaternion<S>> {
+        let pivot = match weighted.first() {
+            Some(&(_, dq)) => dq.real,
+            None => return None,
+        };
+
+        let zero = Quaternion::from_sv(S::zero(), Vector3::zero());
+        let sum = weighted.iter().fold(DualQuaternion::new(zero, zero), |sum, &(weight, dq)| {
+            // Keep all of the rotations in the same hemisphere as the first
+            let weight = if dq.real.dot(pivot) < S::zero() { -weight } else { weight };
+            sum + dq * weight
+        });
+
+        if sum.real.magnitude2().approx_eq(&S::zero()) {
+            None
+        } else {
+            Some(sum.normalize())
+        }
+    }
+}
+
+impl<S: BaseFloat> Transform<Point3<S>> for DualQuaternion<S> {
+    #[inline]
+    fn one() -> DualQuaternion<S> {
+        DualQuaternion::from_rotation(Quaternion::one())
+    }
+
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        let rot = Quaternion::look_at(center - eye, up);
+        let disp = rot.rotate_vector(Point3::origin() - eye);
+        DualQuaternion::from_rotation_translation(rot, disp)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self.real.rotate_vector(vec)
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        Point3::from_vec(self.real.rotate_vector(point.to_vec()) + self.translation())
+    }
+
+    #[inline]
+    fn concat(&self, other: &DualQuaternion<S>) -> DualQuaternion<S> {
+        self * other
+    }
+
+    #[inline]
+    fn inverse_transform(&self) -> Option<DualQuaternion<S>> {
+        Some(self.conjugate())
+    }
+}
+
+impl<S: BaseFloat> From<DualQuaternion<S>> for Decomposed<Vector3<S>, Quaternion<S>> {
+    #[inline]
+    fn from(dq: DualQuaternion<S>) -> Decomposed<Vector3<S>, Quaternion<S>> {
+        Decomposed {
+            scale: S::one(),
+            rot: dq.rotation(),
+            disp: dq.translation(),
+        }
+    }
+}
+
+impl<S: BaseFloat> From<DualQuaternion<S>> for Matrix4<S> {
+    /// Convert the dual quaternion to a homogeneous transformation matrix.
+    fn from(dq: DualQuaternion<S>) -> Matrix4<S> {
+        Matrix4::from_translation(dq.translation()) * Matrix4::from(dq.rotation())
+    }
+}
+
+impl_operator!(<S: BaseFloat> Neg for DualQuaternion<S> {
+    fn neg(dq) -> DualQuaternion<S> {
+        DualQuaternion::new(-dq.real, -dq.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<S> for DualQuaternion<S> {
+    fn mul(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real * rhs, lhs.dual * rhs)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Add<DualQuaternion<S> > for DualQuaternion<S> {
+    fn add(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real + rhs.real, lhs.dual + rhs.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Sub<DualQuaternion<S> > for DualQuaternion<S> {
+    fn sub(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real - rhs.real, lhs.dual - rhs.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<DualQuaternion<S> > for DualQuaternion<S> {
+    fn mul(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real * rhs.real, lhs.real * rhs.dual + lhs.dual * rhs.real)
+    }
+});
+
+impl<S: BaseFloat> ApproxEq for DualQuaternion<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &DualQuaternion<S>, epsilon: &S) -> bool {
+        self.real.approx_eq_eps(&other.real, epsilon) &&
+        self.dual.approx_eq_eps(&other.dual, epsilon)
+    }
+}
+
+/// Formatted as a list of the real and dual parts, such as
+/// `[1 + 0i + 0j + 0k, 0 + 0.5i + 1j + 1.5k]`.
+impl<S: BaseFloat + fmt::Display> fmt::Display for DualQuaternion<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(f.write_str("["));
+        try!(fmt::Display::fmt(&self.real, f));
+        try!(f.write_str(", "));
+        try!(fmt::Display::fmt(&self.dual, f));
+        f.write_str("]")
+    }
+}
+
+impl<S: BaseFloat + FromStr> FromStr for DualQuaternion<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<DualQuaternion<S>, ParseError> {
+        let mut parts = try!(format::parse_list(s, 2));
+        Ok(DualQuaternion {
+            real: try!(format::parse_next(&mut parts, Quaternion::from_str)),
+            dual: try!(format::parse_next(&mut parts, Quaternion::from_str)),
+        })
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for DualQuaternion<S> {
+    /// Generate a normalized dual quaternion, from a rotation that is
+    /// uniformly distributed over the space of rotations, and an arbitrary
+    /// translation.
+    fn arbitrary<G: Gen>(g: &mut G) -> DualQuaternion<S> {
+        DualQuaternion::from_rotation_translation(arbitrary::unit_quaternion(g), Vector3::arbitrary(g))
+    }
+
+    /// Shrink the rotation towards the identity, and the translation towards
+    /// zero.
+    fn shrink(&self) -> Box<Iterator<Item = DualQuaternion<S>>> {
+        let (rotation, translation) = (self.rotation(), self.translation());
+        let rotations = arbitrary::shrink_rotation(rotation)
+            .map(move |rotation| DualQuaternion::from_rotation_translation(rotation, translation));
+        let translations = translation.shrink()
+            .map(move |translation| DualQuaternion::from_rotation_translation(rotation, translation));
+        Box::new(rotations.chain(translations))
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Easing curves, for shaping the progress of animations.
+//!
+//! Each function maps the linear progress `t` of an animation, from `0` to
+//! `1`, to an eased amount that also starts at `0` and ends at `1`. The `_in`
+//! variants start slowly, the `_out` variants end slowly, and the `_in_out`
+//! variants do both. The `elastic` and `back` curves overshoot the range
+//! `[0, 1]` part of the way through.
+//!
+//! These are most useful in combination with the `Interpolate` trait:
+//!
+//! ```rust
+//! use cgmath::prelude::*;
+//! use cgmath::{ease, Point2};
+//!
+//! let start = Point2::new(0.0, 0.0);
+//! let end = Point2::new(100.0, 50.0);
+//!
+//! let frames: Vec<Point2<f32>> = (0..61)
+//!     .map(|i| start.interpolate(end, ease::bounce_out(i as f32 / 60.0)))
+//!     .collect();
+//! ```
+//!
+//! The curves are those popularized by Robert Penner.
+
+use core::f64;
+
+use num_traits::cast;
+
+use num::BaseFloat;
+
+#[inline]
+fn c<S: BaseFloat>(value: f64) -> S {
+    cast(value).unwrap()
+}
+
+/// No easing.
+#[inline]
+pub fn linear<S: BaseFloat>(t: S) -> S {
+    t
+}
+
+/// Quadratic easing, starting slowly.
+#[inline]
+pub fn quad_in<S: BaseFloat>(t: S) -> S {
+    t * t
+}
+
+/// Quadratic easing, ending slowly.
+#[inline]
+pub fn quad_out<S: BaseFloat>(t: S) -> S {
+    let u = S::one() - t;
+    S::one() - u * u
+}
+
+/// Quadratic easing, starting and ending slowly.
+#[inline]
+pub fn quad_in_out<S: BaseFloat>(t: S) -> S {
+    if t < c(0.5) {
+        c::<S>(2.0) * t * t
+    } else {
+        let u = c::<S>(2.0) - c::<S>(2.0) * t;
+        S::one() - u * u / c(2.0)
+    }
+}
+
+/// Cubic easing, starting slowly.
+#[inline]
+pub fn cubic_in<S: BaseFloat>(t: S) -> S {
+    t * t * t
+}
+
+/// Cubic easing, ending slowly.
+#[inline]
+pub fn cubic_out<S: BaseFloat>(t: S) -> S {
+    let u = S::one() - t;
+    S::one() - u * u * u
+}
+
+/// Cubic easing, starting and ending slowly.
+#[inline]
+pub fn cubic_in_out<S: BaseFloat>(t: S) -> S {
+    if t < c(0.5) {
+        c::<S>(4.0) * t * t * t
+    } else {
+        let u = c::<S>(2.0) - c::<S>(2.0) * t;
+        S::one() - u * u * u / c(2.0)
+    }
+}
+
+/// Exponential easing, starting slowly.
+#[inline]
+pub fn expo_in<S: BaseFloat>(t: S) -> S {
+    if t <= S::zero() { S::zero() } else { (c::<S>(10.0) * t - c(10.0)).exp2() }
+}
+
+/// Exponential easing, ending slowly.
+#[inline]
+pub fn expo_out<S: BaseFloat>(t: S) -> S {
+    if t >= S::one() { S::one() } else { S::one() - (c::<S>(-10.0) * t).exp2() }
+}
+
+/// Exponential easing, starting and ending slowly.
+#[inline]
+pub fn expo_in_out<S: BaseFloat>(t: S) -> S {
+    if t <= S::zero() {
+        S::zero()
+    } else if t >= S::one() {
+        S::one()
+    } else if t < c(0.5) {
+        (c::<S>(20.0) * t - c(10.0)).exp2() / c(2.0)
+    } else {
+        (c::<S>(2.0) - (c::<S>(10.0) - c::<S>(20.0) * t).exp2()) / c(2.0)
+    }
+}
+
+/// Elastic easing, winding up with oscillations before springing to the end.
+#[inline]
+pub fn elastic_in<S: BaseFloat>(t: S) -> S {
+    if t <= S::zero() {
+        S::zero()
+    } else if t >= S::one() {
+        S::one()
+    } else {
+        let period = c::<S>(f64::consts::PI * 2.0 / 3.0);
+        -(c::<S>(10.0) * t - c(10.0)).exp2() * ((c::<S>(10.0) * t - c(10.75)) * period).sin()
+    }
+}
+
+/// Elastic easing, overshooting the end and oscillating before settling.
+#[inline]
+pub fn elastic_out<S: BaseFloat>(t: S) -> S {
+    if t <= S::zero() {
+        S::zero()
+    } else if t >= S::one() {
+        S::one()
+    } else {
+        let period = c::<S>(f64::consts::PI * 2.0 / 3.0);
+        (c::<S>(-10.0) * t).exp2() * ((c::<S>(10.0) * t - c(0.75)) * period).sin() + S::one()
+    }
+}
+
+/// Elastic easing, oscillating at both the start and the end.
+#[inline]
+pub fn elastic_in_out<S: BaseFloat>(t: S) -> S {
+    if t <= S::zero() {
+        S::zero()
+    } else if t >= S::one() {
+        S::one()
+    } else {
+        let period = c::<S>(f64::consts::PI * 2.0 / 4.5);
+        let wave = ((c::<S>(20.0) * t - c(11.125)) * period).sin();
+        if t < c(0.5) {
+            -(c::<S>(20.0) * t - c(10.0)).exp2() * wave / c(2.0)
+        } else {
+            (c::<S>(10.0) - c::<S>(20.0) * t).exp2() * wave / c(2.0) + S::one()
+        }
+    }
+}
+
+/// The amount by which the `back` curves overshoot.
+const BACK: f64 = 1.70158;
+
+/// Easing that pulls back slightly before moving towards the end.
+#[inline]
+pub fn back_in<S: BaseFloat>(t: S) -> S {
+    let k = c::<S>(BACK);
+    t * t * ((k + S::one()) * t - k)
+}
+
+/// Easing that overshoots the end slightly before settling.
+#[inline]
+pub fn back_out<S: BaseFloat>(t: S) -> S {
+    S::one() - back_in(S::one() - t)
+}
+
+/// Easing that pulls back at the start, and overshoots at the end.
+#[inline]
+pub fn back_in_out<S: BaseFloat>(t: S) -> S {
+    let k = c::<S>(BACK * 1.525);
+    let u = c::<S>(2.0) * t;
+    if t < c(0.5) {
+        u * u * ((k + S::one()) * u - k) / c(2.0)
+    } else {
+        let u = u - c(2.0);
+        (u * u * ((k + S::one()) * u + k) + c(2.0)) / c(2.0)
+    }
+}
+
+/// Easing that bounces against the end, like a dropped ball.
+#[inline]
+pub fn bounce_out<S: BaseFloat>(t: S) -> S {
+    let n = c::<S>(7.5625);
+    let d = c::<S>(2.75);
+    if t < S::one() / d {
+        n * t * t
+    } else if t < c::<S>(2.0) / d {
+        let t = t - c::<S>(1.5) / d;
+        n * t * t + c(0.75)
+    } else if t < c::<S>(2.5) / d {
+        let t = t - c::<S>(2.25) / d;
+        n * t * t + c(0.9375)
+    } else {
+        let t = t - c::<S>(2.625) / d;
+        n * t * t + c(0.984375)
+    }
+}
+
+/// Easing that bounces against the start before moving to the end.
+#[inline]
+pub fn bounce_in<S: BaseFloat>(t: S) -> S {
+    S::one() - bounce_out(S::one() - t)
+}
+
+/// Easing that bounces at both the start and the end.
+#[inline]
+pub fn bounce_in_out<S: BaseFloat>(t: S) -> S {
+    let two = c::<S>(2.0);
+    if t < c(0.5) {
+        (S::one() - bounce_out(S::one() - two * t)) / two
+    } else {
+        (S::one() + bounce_out(two * t - S::one())) / two
+    }
+}
@@ -13,6 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "rand")]
 use rand::{Rand, Rng};
 use num_traits::cast;
 
@@ -20,8 +24,11 @@ use structure::*;
 
 use angle::Rad;
 use approx::ApproxEq;
+use format::{self, ParseError};
 use quaternion::Quaternion;
 use num::BaseFloat;
+#[cfg(feature = "rand")]
+use sample;
 
 /// A set of [Euler angles] representing a rotation in three-dimensional space.
 ///
@@ -76,7 +83,6 @@ use num::BaseFloat;
 #[derive(Copy, Clone, Debug)]
 #[derive(PartialEq, Eq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Euler<A: Angle> {
     /// The angle to apply around the _x_ axis. Also known at the _pitch_.
     pub x: A,
@@ -86,6 +92,9 @@ pub struct Euler<A: Angle> {
     pub z: A,
 }
 
+impl_eders!(<A: Angle> Euler { x, y, z });
+impl_arbitrary!(<A: Angle> Euler { x, y, z });
+
 impl<A: Angle> Euler<A> {
     /// Construct a set of euler angles.
     ///
@@ -99,6 +108,32 @@ impl<A: Angle> Euler<A> {
     }
 }
 
+/// Formatted as a list of the angles, such as `[90°, 45°, 15°]`.
+impl<A: Angle + fmt::Display> fmt::Display for Euler<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(f.write_str("["));
+        try!(fmt::Display::fmt(&self.x, f));
+        try!(f.write_str(", "));
+        try!(fmt::Display::fmt(&self.y, f));
+        try!(f.write_str(", "));
+        try!(fmt::Display::fmt(&self.z, f));
+        f.write_str("]")
+    }
+}
+
+impl<A: Angle + FromStr<Err = ParseError>> FromStr for Euler<A> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Euler<A>, ParseError> {
+        let mut angles = try!(format::parse_list(s, 3));
+        Ok(Euler {
+            x: try!(format::parse_next(&mut angles, A::from_str)),
+            y: try!(format::parse_next(&mut angles, A::from_str)),
+            z: try!(format::parse_next(&mut angles, A::from_str)),
+        })
+    }
+}
+
 impl<S: BaseFloat> From<Quaternion<S>> for Euler<Rad<S>> {
     fn from(src: Quaternion<S>) -> Euler<Rad<S>> {
         let sig: S = cast(0.499).unwrap();
@@ -152,9 +187,19 @@ impl<A: Angle> ApproxEq for Euler<A> {
     }
 }
 
-impl<A: Angle + Rand> Rand for Euler<A> {
+#[cfg(feature = "rand")]
+impl<A> Rand for Euler<A> where
+    A: Angle + From<Rad<<A as Angle>::Unitless>>,
+    A::Unitless: Rand,
+{
+    /// Generate a set of euler angles representing a rotation that is
+    /// uniformly distributed over the space of rotations.
+    ///
+    /// Note that this is not the same as choosing each angle uniformly, which
+    /// biases the rotations towards the poles of the `y` axis.
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Euler<A> {
-        Euler { x: rng.gen(), y: rng.gen(), z: rng.gen() }
+        let euler = Euler::from(sample::unit_quaternion::<A::Unitless, R>(rng));
+        Euler { x: euler.x.into(), y: euler.y.into(), z: euler.z.into() }
     }
 }
@@ -0,0 +1,343 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers shared by the `Display` and `FromStr` implementations.
+
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use num_traits::Float;
+
+/// An error returned when parsing a value from a string fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    description: &'static str,
+}
+
+/// Construct a parse error with the given description.
+#[inline]
+pub fn error(description: &'static str) -> ParseError {
+    ParseError { description: description }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseError {
+    fn description(&self) -> &str {
+        self.description
+    }
+}
+
+/// Write a list of scalars as `[a, b, c]`, forwarding the flags of the
+/// formatter to each scalar.
+pub fn fmt_list<S: fmt::Display>(f: &mut fmt::Formatter, values: &[S]) -> fmt::Result {
+    try!(f.write_str("["));
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 { try!(f.write_str(", ")); }
+        try!(fmt::Display::fmt(value, f));
+    }
+    f.write_str("]")
+}
+
+/// Write an `n` by `n` matrix as a list of its rows. With the alternate flag,
+/// each row is written on its own line, and the columns are aligned.
+pub fn fmt_matrix<'a, S, F>(f: &mut fmt::Formatter, n: usize, element: F) -> fmt::Result where
+    S: 'a + fmt::Display,
+    F: Fn(usize, usize) -> &'a S,
+{
+    if !f.alternate() {
+        try!(f.write_str("["));
+        for row in 0..n {
+            if row > 0 { try!(f.write_str(", ")); }
+            try!(f.write_str("["));
+            for col in 0..n {
+                if col > 0 { try!(f.write_str(", ")); }
+                try!(fmt::Display::fmt(element(row, col), f));
+            }
+            try!(f.write_str("]"));
+        }
+        return f.write_str("]");
+    }
+
+    let width = f.width();
+    let precision = f.precision();
+    let len = |value: &S| {
+        let mut counter = Counter(0);
+        let _ = write_scalar(&mut counter, value, width, precision);
+        counter.0
+    };
+
+    for row in 0..n {
+        try!(f.write_str(if row == 0 { "[[" } else { " [" }));
+        for col in 0..n {
+            if col > 0 { try!(f.write_str(", ")); }
+            let col_len = (0..n).map(|row| len(element(row, col))).max().unwrap_or(0);
+            for _ in len(element(row, col))..col_len {
+                try!(f.write_str(" "));
+            }
+            try!(write_scalar(f, element(row, col), width, precision));
+        }
+        try!(f.write_str(if row + 1 == n { "]]" } else { "],\n" }));
+    }
+    Ok(())
+}
+
+/// Write a scalar followed by a suffix, such as a unit. The width of the
+/// formatter applies to the whole, which is aligned to the right by default
+/// like a number.
+pub fn fmt_suffixed<S: fmt::Display>(f: &mut fmt::Formatter, value: &S, suffix: &str) -> fmt::Result {
+    let width = match f.width() {
+        Some(width) => width,
+        None => {
+            try!(fmt::Display::fmt(value, f));
+            return f.write_str(suffix);
+        },
+    };
+
+    let precision = f.precision();
+    let mut counter = Counter(0);
+    let _ = write_scalar(&mut counter, value, None, precision);
+    let padding = width.saturating_sub(counter.0 + suffix.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before { try!(f.write_char(fill)); }
+    try!(write_scalar(f, value, None, precision));
+    try!(f.write_str(suffix));
+    for _ in 0..after { try!(f.write_char(fill)); }
+    Ok(())
+}
+
+/// Write a scalar with the given width and precision.
+fn write_scalar<W, S>(w: &mut W, value: &S, width: Option<usize>, precision: Option<usize>) -> fmt::Result where
+    W: fmt::Write,
+    S: fmt::Display,
+{
+    match (width, precision) {
+        (Some(width), Some(precision)) => write!(w, "{:1$.2$}", value, width, precision),
+        (Some(width), None) => write!(w, "{:1$}", value, width),
+        (None, Some(precision)) => write!(w, "{:.1$}", value, precision),
+        (None, None) => write!(w, "{}", value),
+    }
+}
+
+/// Counts the characters written to it, for aligning columns without
+/// allocating.
+struct Counter(usize);
+
+impl fmt::Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Parse a scalar, ignoring surrounding whitespace.
+pub fn parse_scalar<S: FromStr>(s: &str) -> Result<S, ParseError> {
+    s.trim().parse().map_err(|_| error("invalid number"))
+}
+
+/// Split a list of the form `[a, b, c]` into its `n` elements, which may be
+/// nested lists.
+pub fn parse_list(s: &str, n: usize) -> Result<ListElements, ParseError> {
+    let s = s.trim();
+    if !s.starts_with('[') || !s.ends_with(']') {
+        return Err(error("expected a list in square brackets"));
+    }
+
+    let elements = ListElements { rest: Some(&s[1..s.len() - 1]) };
+    let mut count = 0;
+    for element in elements.clone() {
+        if try!(element).trim().is_empty() {
+            return Err(error("empty list element"));
+        }
+        count += 1;
+    }
+
+    if count == n {
+        Ok(elements)
+    } else {
+        Err(error("wrong number of list elements"))
+    }
+}
+
+/// The elements of a list, separated by commas outside of any nested lists.
+#[derive(Clone)]
+pub struct ListElements<'a> {
+    rest: Option<&'a str>,
+}
+
+impl<'a> Iterator for ListElements<'a> {
+    type Item = Result<&'a str, ParseError>;
+
+    fn next(&mut self) -> Option<Result<&'a str, ParseError>> {
+        let rest = match self.rest {
+            Some(rest) => rest,
+            None => return None,
+        };
+
+        let mut depth = 0usize;
+        for (i, c) in rest.char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' if depth == 0 => {
+                    self.rest = None;
+                    return Some(Err(error("unbalanced square brackets")));
+                },
+                ']' => depth -= 1,
+                ',' if depth == 0 => {
+                    self.rest = Some(&rest[i + 1..]);
+                    return Some(Ok(&rest[..i]));
+                },
+                _ => {},
+            }
+        }
+
+        self.rest = None;
+        if depth == 0 {
+            Some(Ok(rest))
+        } else {
+            Some(Err(error("unbalanced square brackets")))
+        }
+    }
+}
+
+/// Parse the next element of a list that has already been checked to have
+/// enough elements.
+#[inline]
+pub fn parse_next<'a, T, E>(elements: &mut ListElements<'a>, parse: E) -> Result<T, ParseError> where
+    E: Fn(&'a str) -> Result<T, ParseError>,
+{
+    match elements.next() {
+        Some(element) => parse(try!(element)),
+        None => Err(error("wrong number of list elements")),
+    }
+}
+
+/// Write a sum of terms, such as `1 + 2i - 3j`, each given as a scalar and the
+/// unit that follows it. The flags of the formatter are forwarded to each
+/// scalar.
+pub fn fmt_sum<S: Float + fmt::Display>(f: &mut fmt::Formatter, terms: &[(S, &str)]) -> fmt::Result {
+    for (i, &(value, unit)) in terms.iter().enumerate() {
+        if i == 0 {
+            try!(fmt::Display::fmt(&value, f));
+        } else if value.is_sign_negative() {
+            try!(f.write_str(" - "));
+            try!(fmt::Display::fmt(&-value, f));
+        } else {
+            try!(f.write_str(" + "));
+            try!(fmt::Display::fmt(&value, f));
+        }
+        try!(f.write_str(unit));
+    }
+    Ok(())
+}
+
+/// Parse a sum in the format written by `fmt_sum`, storing the scalar of each
+/// unit in `values`. The terms may be given in any order, and any that are
+/// left out are zero. A term without a unit is only accepted if one of the
+/// units is empty.
+pub fn parse_sum<S: Float + FromStr>(s: &str, units: &[&str], values: &mut [S]) -> Result<(), ParseError> {
+    debug_assert!(units.len() == values.len() && units.len() <= 32);
+    let mut seen = 0u32;
+    for term in parse_terms(s) {
+        let (negative, term) = try!(term);
+        let index = units.iter().position(|unit| !unit.is_empty() && term.ends_with(unit))
+            .or_else(|| units.iter().position(|unit| unit.is_empty()));
+        let index = match index {
+            Some(index) => index,
+            None => return Err(error("unknown term")),
+        };
+
+        if seen & (1 << index) != 0 {
+            return Err(error("repeated term"));
+        }
+        seen |= 1 << index;
+        let value: S = try!(parse_scalar(&term[..term.len() - units[index].len()]));
+        values[index] = if negative { -value } else { value };
+    }
+
+    if seen == 0 {
+        return Err(error("empty sum"));
+    }
+    for (i, value) in values.iter_mut().enumerate() {
+        if seen & (1 << i) == 0 {
+            *value = S::zero();
+        }
+    }
+    Ok(())
+}
+
+/// Split a sum of terms, such as `1 + 2i - 3j`, into the terms, each paired
+/// with whether it is negated. Signs directly following an exponent are part
+/// of the number.
+pub fn parse_terms(s: &str) -> Terms {
+    Terms { rest: s.trim() }
+}
+
+/// The terms of a sum, as returned by `parse_terms`.
+pub struct Terms<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Terms<'a> {
+    type Item = Result<(bool, &'a str), ParseError>;
+
+    fn next(&mut self) -> Option<Result<(bool, &'a str), ParseError>> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let (negative, body) = match self.rest.chars().next() {
+            Some('-') => (true, self.rest[1..].trim_left()),
+            Some('+') => (false, self.rest[1..].trim_left()),
+            _ => (false, self.rest),
+        };
+
+        let mut previous = None;
+        let mut end = body.len();
+        for (i, c) in body.char_indices() {
+            if (c == '+' || c == '-') && i > 0 {
+                match previous {
+                    Some('e') | Some('E') | Some('+') | Some('-') => {},
+                    _ => { end = i; break; },
+                }
+            }
+            if !c.is_whitespace() {
+                previous = Some(c);
+            }
+        }
+
+        let term = body[..end].trim();
+        self.rest = body[end..].trim();
+        if term.is_empty() {
+            Some(Err(error("empty term")))
+        } else {
+            Some(Ok((negative, term)))
+        }
+    }
+}
+
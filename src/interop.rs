@@ -0,0 +1,160 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions to and from the plain structs of the [`mint`] crate, which
+//! other math libraries use as a common interchange format.
+//!
+//! With the `mint` feature enabled, the following types implement `From` and
+//! `Into` in both directions, as well as `mint::IntoMint`:
+//!
+//! | cgmath                  | mint                                          |
+//! |-------------------------|-----------------------------------------------|
+//! | `Vector2` to `Vector4`  | `Vector2` to `Vector4`                        |
+//! | `Point2`, `Point3`      | `Point2`, `Point3`                            |
+//! | `Matrix2` to `Matrix4`  | `ColumnMatrix2` to `ColumnMatrix4`            |
+//! | `Matrix2` to `Matrix4`  | `RowMatrix2` to `RowMatrix4`, by transposing  |
+//! | `Quaternion`            | `Quaternion`                                  |
+//!
+//! # Column-major and row-major order
+//!
+//! cgmath matrices are column-major: the fields `x`, `y`, `z` and `w` are the
+//! columns of the matrix. They have the same fields as mint's column-major
+//! matrices, so `m.x.y` is the element in the first column and second row of
+//! both. The row-major matrices of mint store the rows instead, so
+//! converting to or from them transposes the storage, but the matrix itself
+//! is unchanged: element `(row, col)` is the same on both sides.
+//!
+//! # Zero-copy conversions
+//!
+//! The vectors, points and column-major matrices have the same fields, in the
+//! same order, as their mint equivalents, so the conversions compile to plain
+//! copies, and slices of them can be viewed as each other without copying
+//! using `pod::cast_slice`. Since the cgmath types are packed, a slice of mint
+//! values can always be viewed as cgmath values, while the reverse requires
+//! the slice to be aligned for the scalar type.
+//!
+//! This does not apply to `Quaternion`, which stores its scalar part first,
+//! while mint stores it last, so mint quaternions do not implement `Pod`.
+//!
+//! ```rust
+//! extern crate cgmath;
+//! extern crate mint;
+//!
+//! use cgmath::{Matrix2, Vector3};
+//! use cgmath::pod;
+//!
+//! # fn main() {
+//! let v: mint::Vector3<f32> = Vector3::new(1.0, 2.0, 3.0).into();
+//! assert_eq!(v, mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+//!
+//! // Row-major matrices are transposed
+//! let m = Matrix2::new(1.0, 2.0,
+//!                      3.0, 4.0);
+//! let rows: mint::RowMatrix2<f32> = m.into();
+//! assert_eq!(rows.x, mint::Vector2 { x: 1.0, y: 3.0 });
+//! assert_eq!(Matrix2::from(rows), m);
+//!
+//! // Slices are converted in place
+//! let positions = [mint::Vector3 { x: 1.0f32, y: 2.0, z: 3.0 }];
+//! let positions: &[Vector3<f32>] = pod::cast_slice(&positions).unwrap();
+//! assert_eq!(positions, &[Vector3::new(1.0, 2.0, 3.0)]);
+//! # }
+//! ```
+//!
+//! [`mint`]: https://crates.io/crates/mint
+
+use mint;
+
+use matrix::{Matrix2, Matrix3, Matrix4};
+use point::{Point2, Point3};
+use quaternion::Quaternion;
+use vector::{Vector2, Vector3, Vector4};
+
+macro_rules! impl_mint_conversions {
+    ($Type:ident { $($field:ident),+ }, $Mint:ident) => {
+        impl<S> From<$Type<S>> for mint::$Mint<S> {
+            #[inline]
+            fn from(v: $Type<S>) -> mint::$Mint<S> {
+                match v { $Type { $($field),+ } => mint::$Mint { $($field: $field.into()),+ } }
+            }
+        }
+
+        impl<S> From<mint::$Mint<S>> for $Type<S> {
+            #[inline]
+            fn from(v: mint::$Mint<S>) -> $Type<S> {
+                match v { mint::$Mint { $($field),+ } => $Type { $($field: $field.into()),+ } }
+            }
+        }
+
+        impl<S> mint::IntoMint for $Type<S> {
+            type MintType = mint::$Mint<S>;
+        }
+    }
+}
+
+impl_mint_conversions!(Vector2 { x, y }, Vector2);
+impl_mint_conversions!(Vector3 { x, y, z }, Vector3);
+impl_mint_conversions!(Vector4 { x, y, z, w }, Vector4);
+
+impl_mint_conversions!(Point2 { x, y }, Point2);
+impl_mint_conversions!(Point3 { x, y, z }, Point3);
+
+impl_mint_conversions!(Matrix2 { x, y }, ColumnMatrix2);
+impl_mint_conversions!(Matrix3 { x, y, z }, ColumnMatrix3);
+impl_mint_conversions!(Matrix4 { x, y, z, w }, ColumnMatrix4);
+
+macro_rules! impl_mint_row_conversions {
+    ($MatrixN:ident, $ColumnMatrixN:ident, $RowMatrixN:ident) => {
+        /// Transposes the storage of the matrix, as mint's row-major matrices
+        /// store the rows rather than the columns.
+        impl<S> From<$MatrixN<S>> for mint::$RowMatrixN<S> {
+            #[inline]
+            fn from(m: $MatrixN<S>) -> mint::$RowMatrixN<S> {
+                mint::$ColumnMatrixN::from(m).into()
+            }
+        }
+
+        /// Transposes the storage of the matrix, as mint's row-major matrices
+        /// store the rows rather than the columns.
+        impl<S> From<mint::$RowMatrixN<S>> for $MatrixN<S> {
+            #[inline]
+            fn from(m: mint::$RowMatrixN<S>) -> $MatrixN<S> {
+                mint::$ColumnMatrixN::from(m).into()
+            }
+        }
+    }
+}
+
+impl_mint_row_conversions!(Matrix2, ColumnMatrix2, RowMatrix2);
+impl_mint_row_conversions!(Matrix3, ColumnMatrix3, RowMatrix3);
+impl_mint_row_conversions!(Matrix4, ColumnMatrix4, RowMatrix4);
+
+impl<S> From<Quaternion<S>> for mint::Quaternion<S> {
+    #[inline]
+    fn from(q: Quaternion<S>) -> mint::Quaternion<S> {
+        match q { Quaternion { s, v } => mint::Quaternion { s: s, v: v.into() } }
+    }
+}
+
+impl<S> From<mint::Quaternion<S>> for Quaternion<S> {
+    #[inline]
+    fn from(q: mint::Quaternion<S>) -> Quaternion<S> {
+        match q { mint::Quaternion { s, v } => Quaternion { s: s, v: v.into() } }
+    }
+}
+
+impl<S> mint::IntoMint for Quaternion<S> {
+    type MintType = mint::Quaternion<S>;
+}
@@ -0,0 +1,177 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use structure::*;
+
+use angle::{Deg, Grad, Rad, Turns};
+use euler::Euler;
+use matrix::Matrix2;
+use num::BaseFloat;
+use point::{Point1, Point2, Point3};
+use quaternion::Quaternion;
+use rotation::{Basis2, Basis3, Rotation, Rotation2};
+use transform::Decomposed;
+use unit_complex::UnitComplex;
+use vector::{Vector1, Vector2, Vector3, Vector4};
+
+/// A value that can be smoothly interpolated towards another, such as when
+/// animating between two states.
+///
+/// Combined with the functions in the `ease` module, this allows tweens to be
+/// written generically over the type being animated:
+///
+/// ```rust
+/// use cgmath::prelude::*;
+/// use cgmath::{ease, Deg, Vector2};
+///
+/// fn tween<T: Interpolate<f32>>(from: T, to: T, t: f32) -> T {
+///     from.interpolate(to, ease::cubic_in_out(t))
+/// }
+///
+/// assert_eq!(tween(Vector2::new(0.0, 0.0), Vector2::new(2.0, 4.0), 0.5),
+///            Vector2::new(1.0, 2.0));
+/// // Angles are interpolated the short way around the circle
+/// assert_eq!(tween(Deg::new(350.0), Deg::new(30.0), 0.5), Deg::new(370.0));
+/// ```
+pub trait Interpolate<S>: Sized {
+    /// Interpolate from `self` towards `other`, returning `self` when `amount`
+    /// is `0`, and `other` when `amount` is `1`.
+    ///
+    /// Amounts outside of `[0, 1]` extrapolate beyond the two values, allowing
+    /// for easing curves that overshoot.
+    fn interpolate(self, other: Self, amount: S) -> Self;
+}
+
+macro_rules! impl_interpolate_scalar {
+    ($S:ident) => {
+        impl Interpolate<$S> for $S {
+            #[inline]
+            fn interpolate(self, other: $S, amount: $S) -> $S {
+                self + (other - self) * amount
+            }
+        }
+    }
+}
+
+impl_interpolate_scalar!(f32);
+impl_interpolate_scalar!(f64);
+
+macro_rules! impl_interpolate_linear {
+    ($T:ident) => {
+        impl<S: BaseFloat> Interpolate<S> for $T<S> {
+            #[inline]
+            fn interpolate(self, other: $T<S>, amount: S) -> $T<S> {
+                self + (other - self) * amount
+            }
+        }
+    }
+}
+
+impl_interpolate_linear!(Vector1);
+impl_interpolate_linear!(Vector2);
+impl_interpolate_linear!(Vector3);
+impl_interpolate_linear!(Vector4);
+impl_interpolate_linear!(Point1);
+impl_interpolate_linear!(Point2);
+impl_interpolate_linear!(Point3);
+
+macro_rules! impl_interpolate_angle {
+    ($Angle:ident) => {
+        /// Interpolates along the shortest arc between the two angles. The
+        /// result is not normalized, so it may lie outside of the range of
+        /// either angle.
+        impl<S: BaseFloat> Interpolate<S> for $Angle<S> {
+            #[inline]
+            fn interpolate(self, other: $Angle<S>, amount: S) -> $Angle<S> {
+                self.lerp_shortest(other, amount)
+            }
+        }
+    }
+}
+
+impl_interpolate_angle!(Rad);
+impl_interpolate_angle!(Deg);
+impl_interpolate_angle!(Turns);
+impl_interpolate_angle!(Grad);
+
+/// Uses spherical linear interpolation along the shortest arc between the two
+/// rotations. Both quaternions should be normalized.
+impl<S: BaseFloat> Interpolate<S> for Quaternion<S> {
+    fn interpolate(self, other: Quaternion<S>, amount: S) -> Quaternion<S> {
+        // `q` and `-q` represent the same rotation, but only one of them is
+        // the short way around
+        if self.dot(other) < S::zero() {
+            self.slerp(-other, amount)
+        } else {
+            self.slerp(other, amount)
+        }
+    }
+}
+
+/// Interpolates the angle of rotation along the shortest arc.
+impl<S: BaseFloat> Interpolate<S> for Basis2<S> {
+    fn interpolate(self, other: Basis2<S>, amount: S) -> Basis2<S> {
+        let delta = Matrix2::from(self.invert() * other);
+        let angle: Rad<S> = Rad::atan2(delta.x.y, delta.x.x);
+        self * Basis2::from_angle(angle * amount)
+    }
+}
+
+/// Uses spherical linear interpolation along the shortest arc between the two
+/// rotations.
+impl<S: BaseFloat> Interpolate<S> for UnitComplex<S> {
+    #[inline]
+    fn interpolate(self, other: UnitComplex<S>, amount: S) -> UnitComplex<S> {
+        self.slerp(other, amount)
+    }
+}
+
+/// Uses spherical linear interpolation along the shortest arc between the two
+/// rotations.
+impl<S: BaseFloat> Interpolate<S> for Basis3<S> {
+    #[inline]
+    fn interpolate(self, other: Basis3<S>, amount: S) -> Basis3<S> {
+        let (a, b) = (Quaternion::from(self), Quaternion::from(other));
+        Basis3::from_quaternion(&a.interpolate(b, amount))
+    }
+}
+
+/// Interpolates the rotations described by the angles, rather than each angle
+/// individually, using spherical linear interpolation along the shortest arc.
+impl<A> Interpolate<A::Unitless> for Euler<A> where
+    A: Angle + From<Rad<<A as Angle>::Unitless>> + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn interpolate(self, other: Euler<A>, amount: A::Unitless) -> Euler<A> {
+        let (a, b) = (Quaternion::from(self), Quaternion::from(other));
+        let euler = Euler::from(a.interpolate(b, amount));
+        Euler { x: euler.x.into(), y: euler.y.into(), z: euler.z.into() }
+    }
+}
+
+/// Interpolates the scale, rotation and displacement independently.
+impl<S, V, R> Interpolate<S> for Decomposed<V, R> where
+    S: BaseFloat,
+    V: VectorSpace<Scalar = S> + Interpolate<S>,
+    R: Interpolate<S>,
+{
+    #[inline]
+    fn interpolate(self, other: Decomposed<V, R>, amount: S) -> Decomposed<V, R> {
+        Decomposed {
+            scale: self.scale + (other.scale - self.scale) * amount,
+            rot: self.rot.interpolate(other.rot, amount),
+            disp: self.disp.interpolate(other.disp, amount),
+        }
+    }
+}
@@ -49,11 +49,42 @@
 //! ```rust
 //! use cgmath::prelude::*;
 //! ```
+//!
+//! # Using cgmath without the standard library
+//!
+//! The `std` feature is enabled by default. Without it, cgmath only depends on
+//! `core`, so it can be used on embedded and WebAssembly targets. The float
+//! functions of `BaseFloat` then come from the `Float` trait of `num-traits`,
+//! which must be given a backend by enabling the `libm` feature, or by using a
+//! scalar type that implements `Float` itself. The following need `std`, as
+//! they allocate:
+//!
+//! - `ArcLengthTable`, `Spline::rotation_minimizing_frames` and `Track`.
+//! - The `uniform` module.
+//! - The `Error` implementations of `ParseError` and `SliceLengthError`.
+//! - The `quickcheck` feature.
+//!
+//! Random sampling is provided by the `rand` feature, which is also enabled by
+//! default.
+//!
+//! ```toml
+//! [dependencies]
+//! cgmath = { version = "0.10", default-features = false, features = ["libm"] }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("cgmath needs either the `std` or the `libm` feature for its float functions");
 
-#![cfg_attr(feature = "eders", feature(plugin, custom_derive))]
-#![cfg_attr(feature = "eders", plugin(serde_macros))]
+#[cfg(all(feature = "quickcheck", not(feature = "std")))]
+compile_error!("the `quickcheck` feature of cgmath needs the `std` feature");
+
+#[cfg(feature = "std")]
+extern crate core;
 
 pub extern crate num_traits;
+#[cfg(feature = "rand")]
 extern crate rand;
 
 #[cfg(feature = "rustc-serialize")]
@@ -62,43 +93,87 @@ extern crate rustc_serialize;
 #[cfg(feature = "eders")]
 extern crate serde;
 
+#[cfg(feature = "mint")]
+pub extern crate mint;
+
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
+
 // Re-exports
 
 pub use approx::*;
 pub use num::*;
 pub use structure::*;
 
-pub use matrix::{Matrix2, Matrix3, Matrix4};
+pub use matrix::{Matrix2, Matrix3, Matrix4, SliceLengthError};
 pub use quaternion::Quaternion;
+pub use dual_quaternion::DualQuaternion;
+pub use unit_complex::UnitComplex;
+pub use rotor::{Bivector3, Rotor3};
 pub use vector::{Vector1, Vector2, Vector3, Vector4, dot, vec1, vec2, vec3, vec4};
 
-pub use angle::{Deg, Rad, deg, rad};
+pub use angle::{Deg, Grad, Rad, Turns, deg, grad, rad, turns};
 pub use euler::Euler;
+pub use format::ParseError;
 pub use point::{Point1, Point2, Point3};
 pub use rotation::*;
+pub use convention::{ChangeConvention, Convention, Handedness, UpAxis};
 pub use transform::*;
+pub use typed::{TypedPoint3, TypedTransform, TypedVector3};
 
 pub use projection::*;
 
+pub use spline::*;
+
+pub use interpolate::Interpolate;
+#[cfg(feature = "std")]
+pub use track::*;
+
 // Modules
 
+mod macros;
+
+#[cfg(feature = "quickcheck")]
+pub mod arbitrary;
+pub mod camera;
+#[cfg(feature = "eders")]
+pub mod compact;
 pub mod conv;
+#[cfg(feature = "rand")]
+pub mod distributions;
+pub mod ease;
+#[cfg(feature = "mint")]
+pub mod interop;
+pub mod pod;
 pub mod prelude;
-
-mod macros;
+pub mod sample;
+#[cfg(feature = "std")]
+pub mod uniform;
 
 mod approx;
+mod format;
 mod num;
 mod structure;
 
 mod matrix;
 mod quaternion;
+mod dual_quaternion;
+mod unit_complex;
+mod rotor;
 mod vector;
 
 mod angle;
 mod euler;
 mod point;
 mod rotation;
+mod convention;
 mod transform;
+mod typed;
 
 mod projection;
+
+mod spline;
+
+mod interpolate;
+#[cfg(feature = "std")]
+mod track;
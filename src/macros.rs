@@ -254,3 +254,338 @@ macro_rules! impl_index_operators {
         }
     }
 }
+
+/// Generates `Display` and `FromStr` implementations for a compound array
+/// type, formatted as a list of its components such as `[1, 2, 3]`
+macro_rules! impl_fmt_list {
+    ($VectorN:ident { $($field:ident),+ }, $n:expr) => {
+        impl<S: fmt::Display> fmt::Display for $VectorN<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let v: &[S; $n] = self.as_ref();
+                format::fmt_list(f, v)
+            }
+        }
+
+        impl<S: FromStr> FromStr for $VectorN<S> {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<$VectorN<S>, ParseError> {
+                let mut elements = try!(format::parse_list(s, $n));
+                Ok($VectorN { $($field: try!(format::parse_next(&mut elements, format::parse_scalar))),+ })
+            }
+        }
+    }
+}
+
+/// Generates `Serialize` and `Deserialize` implementations for a struct, as a
+/// map from the names of its fields to their values. The types listed after
+/// `where`, such as associated types of the parameters, must implement the
+/// traits as well as the parameters themselves.
+///
+/// Types with an invariant can give an expression after `=>` that builds the
+/// value from its fields, returning `None` if they are invalid. Deserializing
+/// invalid fields is then an error.
+#[cfg(feature = "eders")]
+macro_rules! impl_eders {
+    (<$($T:ident $(: $Bound:ident)*),*> $Type:ident { $($field:ident),+ }) => {
+        impl_eders!(<$($T $(: $Bound)*),*> $Type { $($field),+ } where);
+    };
+    (<$($T:ident $(: $Bound:ident)*),*> $Type:ident { $($field:ident),+ } => $new:expr) => {
+        impl_eders!(<$($T $(: $Bound)*),*> $Type { $($field),+ } where => $new);
+    };
+    (<$($T:ident $(: $Bound:ident)*),*> $Type:ident { $($field:ident),+ } where $($Extra:ty),*) => {
+        impl_eders!(<$($T $(: $Bound)*),*> $Type { $($field),+ } where $($Extra),* => Some($Type { $($field: $field),+ }));
+    };
+    (<$($T:ident $(: $Bound:ident)*),*> $Type:ident { $($field:ident),+ } where $($Extra:ty),* => $new:expr) => {
+        impl<$($T $(: $Bound)*),*> ::serde::Serialize for $Type<$($T),*> where
+            $($T: ::serde::Serialize,)* $($Extra: ::serde::Serialize,)*
+        {
+            fn serialize<Ser>(&self, serializer: &mut Ser) -> Result<(), Ser::Error> where
+                Ser: ::serde::Serializer,
+            {
+                const FIELDS: &'static [&'static str] = &[$(stringify!($field)),+];
+
+                struct Visitor<'a, $($T: 'a $(+ $Bound)*),*> {
+                    value: &'a $Type<$($T),*>,
+                    state: usize,
+                }
+
+                impl<'a, $($T: 'a $(+ $Bound)*),*> ::serde::ser::MapVisitor for Visitor<'a, $($T),*> where
+                    $($T: ::serde::Serialize,)* $($Extra: ::serde::Serialize,)*
+                {
+                    fn visit<Ser>(&mut self, serializer: &mut Ser) -> Result<Option<()>, Ser::Error> where
+                        Ser: ::serde::Serializer,
+                    {
+                        let mut index = 0;
+                        $(
+                            if self.state == index {
+                                self.state += 1;
+                                return Ok(Some(try!(serializer.serialize_struct_elt(stringify!($field), &self.value.$field))));
+                            }
+                            index += 1;
+                        )+
+                        let _ = index;
+                        Ok(None)
+                    }
+
+                    #[inline]
+                    fn len(&self) -> Option<usize> {
+                        Some(FIELDS.len())
+                    }
+                }
+
+                serializer.serialize_struct(stringify!($Type), Visitor { value: self, state: 0 })
+            }
+        }
+
+        impl<$($T $(: $Bound)*),*> ::serde::Deserialize for $Type<$($T),*> where
+            $($T: ::serde::Deserialize,)* $($Extra: ::serde::Deserialize,)*
+        {
+            fn deserialize<De>(deserializer: &mut De) -> Result<$Type<$($T),*>, De::Error> where
+                De: ::serde::Deserializer,
+            {
+                const FIELDS: &'static [&'static str] = &[$(stringify!($field)),+];
+
+                #[allow(non_camel_case_types)]
+                enum Field { $($field),+ }
+
+                impl ::serde::Deserialize for Field {
+                    fn deserialize<De>(deserializer: &mut De) -> Result<Field, De::Error> where
+                        De: ::serde::Deserializer,
+                    {
+                        struct FieldVisitor;
+
+                        impl ::serde::de::Visitor for FieldVisitor {
+                            type Value = Field;
+
+                            fn visit_str<E>(&mut self, value: &str) -> Result<Field, E> where
+                                E: ::serde::de::Error,
+                            {
+                                $(if value == stringify!($field) { return Ok(Field::$field); })+
+                                Err(::serde::de::Error::unknown_field(value))
+                            }
+                        }
+
+                        deserializer.deserialize_struct_field(FieldVisitor)
+                    }
+                }
+
+                struct Visitor<$($T $(: $Bound)*),*>(::core::marker::PhantomData<($($T,)*)>);
+
+                impl<$($T $(: $Bound)*),*> ::serde::de::Visitor for Visitor<$($T),*> where
+                    $($T: ::serde::Deserialize,)* $($Extra: ::serde::Deserialize,)*
+                {
+                    type Value = $Type<$($T),*>;
+
+                    fn visit_map<Vis>(&mut self, mut visitor: Vis) -> Result<$Type<$($T),*>, Vis::Error> where
+                        Vis: ::serde::de::MapVisitor,
+                    {
+                        $(let mut $field = None;)+
+
+                        while let Some(key) = try!(visitor.visit_key()) {
+                            match key {
+                                $(Field::$field => {
+                                    if $field.is_some() {
+                                        return Err(::serde::de::Error::duplicate_field(stringify!($field)));
+                                    }
+                                    $field = Some(try!(visitor.visit_value()));
+                                },)+
+                            }
+                        }
+
+                        $(let $field = match $field {
+                            Some(value) => value,
+                            None => try!(visitor.missing_field(stringify!($field))),
+                        };)+
+
+                        try!(visitor.end());
+                        match $new {
+                            Some(value) => Ok(value),
+                            None => Err(::serde::de::Error::custom(concat!("invalid ", stringify!($Type)))),
+                        }
+                    }
+
+                    fn visit_seq<Vis>(&mut self, mut visitor: Vis) -> Result<$Type<$($T),*>, Vis::Error> where
+                        Vis: ::serde::de::SeqVisitor,
+                    {
+                        $(let $field = match try!(visitor.visit()) {
+                            Some(value) => value,
+                            None => return Err(::serde::de::Error::end_of_stream()),
+                        };)+
+
+                        try!(visitor.end());
+                        match $new {
+                            Some(value) => Ok(value),
+                            None => Err(::serde::de::Error::custom(concat!("invalid ", stringify!($Type)))),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_struct(stringify!($Type), FIELDS, Visitor(::core::marker::PhantomData))
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "eders"))]
+macro_rules! impl_eders {
+    ($($tokens:tt)*) => {}
+}
+
+/// Generates `Serialize` and `Deserialize` implementations for an enum whose
+/// variants have no fields, as the names of the variants.
+#[cfg(feature = "eders")]
+macro_rules! impl_eders_enum {
+    ($Type:ident { $($variant:ident),+ }) => {
+        impl ::serde::Serialize for $Type {
+            fn serialize<Ser>(&self, serializer: &mut Ser) -> Result<(), Ser::Error> where
+                Ser: ::serde::Serializer,
+            {
+                match *self {
+                    $($Type::$variant => {
+                        serializer.serialize_unit_variant(stringify!($Type), *self as usize, stringify!($variant))
+                    },)+
+                }
+            }
+        }
+
+        impl ::serde::Deserialize for $Type {
+            fn deserialize<De>(deserializer: &mut De) -> Result<$Type, De::Error> where
+                De: ::serde::Deserializer,
+            {
+                const VARIANTS: &'static [&'static str] = &[$(stringify!($variant)),+];
+                const VALUES: &'static [$Type] = &[$($Type::$variant),+];
+
+                struct Variant($Type);
+
+                impl ::serde::Deserialize for Variant {
+                    fn deserialize<De>(deserializer: &mut De) -> Result<Variant, De::Error> where
+                        De: ::serde::Deserializer,
+                    {
+                        struct VariantVisitor;
+
+                        impl ::serde::de::Visitor for VariantVisitor {
+                            type Value = Variant;
+
+                            fn visit_str<E>(&mut self, value: &str) -> Result<Variant, E> where
+                                E: ::serde::de::Error,
+                            {
+                                match VARIANTS.iter().position(|&variant| variant == value) {
+                                    Some(index) => Ok(Variant(VALUES[index])),
+                                    None => Err(::serde::de::Error::unknown_variant(value)),
+                                }
+                            }
+
+                            fn visit_u64<E>(&mut self, value: u64) -> Result<Variant, E> where
+                                E: ::serde::de::Error,
+                            {
+                                match VALUES.get(value as usize) {
+                                    Some(&variant) => Ok(Variant(variant)),
+                                    None => Err(::serde::de::Error::invalid_value("variant index out of range")),
+                                }
+                            }
+                        }
+
+                        deserializer.deserialize_struct_field(VariantVisitor)
+                    }
+                }
+
+                struct Visitor;
+
+                impl ::serde::de::EnumVisitor for Visitor {
+                    type Value = $Type;
+
+                    fn visit<Vis>(&mut self, mut visitor: Vis) -> Result<$Type, Vis::Error> where
+                        Vis: ::serde::de::VariantVisitor,
+                    {
+                        let Variant(value) = try!(visitor.visit_variant());
+                        try!(visitor.visit_unit());
+                        Ok(value)
+                    }
+                }
+
+                deserializer.deserialize_enum(stringify!($Type), VARIANTS, Visitor)
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "eders"))]
+macro_rules! impl_eders_enum {
+    ($($tokens:tt)*) => {}
+}
+
+/// Shrinks each field of a value in turn, keeping the other fields fixed.
+#[cfg(feature = "quickcheck")]
+macro_rules! shrink_fields {
+    ($value:expr, { $($field:ident),+ }) => {{
+        let value = $value.clone();
+        let shrinks: Box<Iterator<Item = _>> = Box::new(::core::iter::empty());
+        $(
+            let field = value.clone().$field;
+            let original = value.clone();
+            let shrinks: Box<Iterator<Item = _>> = Box::new(shrinks.chain(
+                ::quickcheck::Arbitrary::shrink(&field).map(move |field| {
+                    let mut shrunk = original.clone();
+                    shrunk.$field = field;
+                    shrunk
+                })
+            ));
+        )+
+        shrinks
+    }}
+}
+
+/// Generates an `Arbitrary` implementation for a struct, generating and
+/// shrinking each of its fields independently. The types listed after
+/// `where`, such as associated types of the parameters, must implement
+/// `Arbitrary` as well as the parameters themselves.
+#[cfg(feature = "quickcheck")]
+macro_rules! impl_arbitrary {
+    (<$($T:ident $(: $Bound:ident)*),*> $Type:ident { $($field:ident),+ }) => {
+        impl_arbitrary!(<$($T $(: $Bound)*),*> $Type { $($field),+ } where);
+    };
+    (<$($T:ident $(: $Bound:ident)*),*> $Type:ident { $($field:ident),+ } where $($Extra:ty),*) => {
+        impl<$($T $(: $Bound)*),*> ::quickcheck::Arbitrary for $Type<$($T),*> where
+            $($T: ::quickcheck::Arbitrary,)* $($Extra: ::quickcheck::Arbitrary,)*
+        {
+            fn arbitrary<G: ::quickcheck::Gen>(g: &mut G) -> $Type<$($T),*> {
+                $Type { $($field: ::quickcheck::Arbitrary::arbitrary(g)),+ }
+            }
+
+            fn shrink(&self) -> Box<Iterator<Item = $Type<$($T),*>>> {
+                shrink_fields!(self, { $($field),+ })
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "quickcheck"))]
+macro_rules! impl_arbitrary {
+    ($($tokens:tt)*) => {}
+}
+
+/// Generates an `Arbitrary` implementation for an enum whose variants have no
+/// fields, choosing each variant with equal probability and shrinking towards
+/// the first variant.
+#[cfg(feature = "quickcheck")]
+macro_rules! impl_arbitrary_enum {
+    ($Type:ident { $($variant:ident),+ }) => {
+        impl ::quickcheck::Arbitrary for $Type {
+            fn arbitrary<G: ::quickcheck::Gen>(g: &mut G) -> $Type {
+                const VALUES: &'static [$Type] = &[$($Type::$variant),+];
+                *::quickcheck::Rng::choose(g, VALUES).unwrap()
+            }
+
+            fn shrink(&self) -> Box<Iterator<Item = $Type>> {
+                const VALUES: &'static [$Type] = &[$($Type::$variant),+];
+                Box::new(VALUES[..*self as usize].iter().cloned())
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "quickcheck"))]
+macro_rules! impl_arbitrary_enum {
+    ($($tokens:tt)*) => {}
+}
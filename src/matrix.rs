@@ -13,18 +13,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "rand")]
 use rand::{Rand, Rng};
 use num_traits::cast;
-use std::fmt;
-use std::mem;
-use std::ops::*;
-use std::ptr;
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::fmt;
+use core::mem;
+use core::ops::*;
+use core::ptr;
+use core::str::FromStr;
 
 use structure::*;
 
 use angle::Rad;
 use approx::ApproxEq;
 use euler::Euler;
+use format::{self, ParseError};
 use num::BaseFloat;
 use point::{Point2, Point3};
 use quaternion::Quaternion;
@@ -37,7 +42,6 @@ use vector::{Vector2, Vector3, Vector4};
 #[repr(C, packed)]
 #[derive(Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Matrix2<S> {
     /// The first column of the matrix.
     pub x: Vector2<S>,
@@ -51,7 +55,6 @@ pub struct Matrix2<S> {
 #[repr(C, packed)]
 #[derive(Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Matrix3<S> {
     /// The first column of the matrix.
     pub x: Vector3<S>,
@@ -67,7 +70,6 @@ pub struct Matrix3<S> {
 #[repr(C, packed)]
 #[derive(Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Matrix4<S> {
     /// The first column of the matrix.
     pub x: Vector4<S>,
@@ -185,6 +187,22 @@ impl<S: BaseFloat> Matrix3<S> {
                      _1subc * axis.y * axis.z - s * axis.x,
                      _1subc * axis.z * axis.z + c)
     }
+
+    /// Re-orthonormalize the columns of the matrix using the [Gram–Schmidt
+    /// process](https://en.wikipedia.org/wiki/Gram%E2%80%93Schmidt_process).
+    ///
+    /// This is useful for correcting the numerical drift that accumulates in
+    /// rotation matrices after many incremental updates. The direction of the
+    /// `x` column is preserved, and the columns must be linearly independent.
+    #[must_use]
+    pub fn orthonormalize(&self) -> Matrix3<S> {
+        let x = self.x.normalize();
+        let y = (self.y - x * x.dot(self.y)).normalize();
+        let z = self.z - x * x.dot(self.z);
+        let z = (z - y * y.dot(z)).normalize();
+
+        Matrix3::from_cols(x, y, z)
+    }
 }
 
 impl<S: BaseFloat> Matrix4<S> {
@@ -243,6 +261,21 @@ impl<S: BaseFloat> Matrix4<S> {
                      -eye.dot(s), -eye.dot(u), eye.dot(f), S::one())
     }
 
+    /// Create a homogeneous transformation matrix that will cause a vector to
+    /// point at `dir`, using `up` for orientation, in a left-handed coordinate
+    /// system. The view looks along the positive `z` axis, rather than the
+    /// negative `z` axis as with `look_at`.
+    pub fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
+        let f = (center - eye).normalize();
+        let s = up.cross(f).normalize();
+        let u = f.cross(s);
+
+        Matrix4::new(s.x, u.x, f.x, S::zero(),
+                     s.y, u.y, f.y, S::zero(),
+                     s.z, u.z, f.z, S::zero(),
+                     -eye.dot(s), -eye.dot(u), -eye.dot(f), S::one())
+    }
+
     /// Create a homogeneous transformation matrix from a rotation around the `x` axis (pitch).
     pub fn from_angle_x(theta: Rad<S>) -> Matrix4<S> {
         // http://en.wikipedia.org/wiki/Rotation_matrix#Basic_rotations
@@ -927,6 +960,14 @@ impl_operators!(Matrix2, Vector2 { x: 0, y: 1 });
 impl_operators!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
 impl_operators!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
 
+impl_eders!(<S> Matrix2 { x, y });
+impl_eders!(<S> Matrix3 { x, y, z });
+impl_eders!(<S> Matrix4 { x, y, z, w });
+
+impl_arbitrary!(<S> Matrix2 { x, y });
+impl_arbitrary!(<S> Matrix3 { x, y, z });
+impl_arbitrary!(<S> Matrix4 { x, y, z, w });
+
 impl_operator!(<S: BaseFloat> Mul<Matrix2<S> > for Matrix2<S> {
     fn mul(lhs, rhs) -> Matrix2<S> {
         Matrix2::new(lhs.row(0).dot(rhs[0]), lhs.row(1).dot(rhs[0]),
@@ -1128,6 +1169,140 @@ fixed_array_conversions!(Matrix2<S> { x:0, y:1 }, 2);
 fixed_array_conversions!(Matrix3<S> { x:0, y:1, z:2 }, 3);
 fixed_array_conversions!(Matrix4<S> { x:0, y:1, z:2, w:3 }, 4);
 
+/// An error returned when constructing a matrix from a slice of the wrong
+/// length.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SliceLengthError {
+    expected: usize,
+    found: usize,
+}
+
+impl SliceLengthError {
+    /// The length that the slice should have had.
+    #[inline]
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// The length of the slice.
+    #[inline]
+    pub fn found(&self) -> usize {
+        self.found
+    }
+}
+
+impl fmt::Display for SliceLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected a slice of length {}, found {}", self.expected, self.found)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for SliceLengthError {
+    fn description(&self) -> &str {
+        "slice has the wrong length"
+    }
+}
+
+#[inline]
+fn check_len<T>(slice: &[T], expected: usize) -> Result<(), SliceLengthError> {
+    if slice.len() == expected {
+        Ok(())
+    } else {
+        Err(SliceLengthError { expected: expected, found: slice.len() })
+    }
+}
+
+macro_rules! impl_ordered_arrays {
+    ($MatrixN:ident, $n:expr) => {
+        /// Conversions to and from arrays, with explicit column-major (`cols`)
+        /// or row-major (`rows`) ordering. The `Into` and `From` conversions
+        /// for arrays are column-major, like `to_cols_array` and
+        /// `from_cols_slice`.
+        impl<S: Copy> $MatrixN<S> {
+            /// The columns of the matrix, such that `m.to_cols_array()[col][row]`
+            /// is the element in the given column and row.
+            #[inline]
+            pub fn to_cols_array(&self) -> [[S; $n]; $n] {
+                *AsRef::<[[S; $n]; $n]>::as_ref(self)
+            }
+
+            /// The rows of the matrix, such that `m.to_rows_array()[row][col]`
+            /// is the element in the given row and column.
+            pub fn to_rows_array(&self) -> [[S; $n]; $n] {
+                let cols = self.to_cols_array();
+                let mut rows = cols;
+                for (col, column) in cols.iter().enumerate() {
+                    for (row, &value) in column.iter().enumerate() {
+                        rows[row][col] = value;
+                    }
+                }
+                rows
+            }
+
+            /// The elements of the matrix in column-major order, as used by
+            /// OpenGL.
+            #[inline]
+            pub fn to_cols_array_flat(&self) -> [S; $n * $n] {
+                *AsRef::<[S; $n * $n]>::as_ref(self)
+            }
+
+            /// The elements of the matrix in row-major order, as used by
+            /// DirectX.
+            pub fn to_rows_array_flat(&self) -> [S; $n * $n] {
+                let rows = self.to_rows_array();
+                let mut flat = self.to_cols_array_flat();
+                for (i, row) in rows.iter().enumerate() {
+                    flat[i * $n..(i + 1) * $n].copy_from_slice(row);
+                }
+                flat
+            }
+
+            /// Construct a matrix from a slice of its columns, returning an
+            /// error if the slice does not contain exactly as many columns as
+            /// the matrix.
+            pub fn from_cols_slice(cols: &[[S; $n]]) -> Result<$MatrixN<S>, SliceLengthError> {
+                try!(check_len(cols, $n));
+                let mut m = [cols[0]; $n];
+                m.copy_from_slice(cols);
+                Ok(m.into())
+            }
+
+            /// Construct a matrix from a slice of its rows, returning an error
+            /// if the slice does not contain exactly as many rows as the
+            /// matrix.
+            pub fn from_rows_slice(rows: &[[S; $n]]) -> Result<$MatrixN<S>, SliceLengthError> {
+                let m = try!($MatrixN::from_cols_slice(rows));
+                Ok($MatrixN::from(m.to_rows_array()))
+            }
+
+            /// Construct a matrix from its elements in column-major order,
+            /// returning an error if the slice does not contain exactly as
+            /// many elements as the matrix.
+            pub fn from_cols_slice_flat(elements: &[S]) -> Result<$MatrixN<S>, SliceLengthError> {
+                try!(check_len(elements, $n * $n));
+                let mut m = [[elements[0]; $n]; $n];
+                for (i, col) in m.iter_mut().enumerate() {
+                    col.copy_from_slice(&elements[i * $n..(i + 1) * $n]);
+                }
+                Ok(m.into())
+            }
+
+            /// Construct a matrix from its elements in row-major order,
+            /// returning an error if the slice does not contain exactly as
+            /// many elements as the matrix.
+            pub fn from_rows_slice_flat(elements: &[S]) -> Result<$MatrixN<S>, SliceLengthError> {
+                let m = try!($MatrixN::from_cols_slice_flat(elements));
+                Ok($MatrixN::from(m.to_rows_array()))
+            }
+        }
+    }
+}
+
+impl_ordered_arrays!(Matrix2, 2);
+impl_ordered_arrays!(Matrix3, 3);
+impl_ordered_arrays!(Matrix4, 4);
+
 impl<S: BaseFloat> From<Matrix2<S>> for Matrix3<S> {
     /// Clone the elements of a 2-dimensional matrix into the top-left corner
     /// of a 3-dimensional identity matrix.
@@ -1224,6 +1399,41 @@ impl<S: fmt::Debug> fmt::Debug for Matrix4<S> {
     }
 }
 
+macro_rules! impl_fmt_matrix {
+    ($MatrixN:ident, $VectorN:ident { $($field:ident),+ }, $n:expr) => {
+        /// Formatted as a list of the rows of the matrix, such as
+        /// `[[1, 2], [3, 4]]`, so that it reads in the usual mathematical
+        /// layout. The alternate flag, `{:#}`, writes each row on its own line
+        /// with the columns aligned.
+        impl<S: fmt::Display> fmt::Display for $MatrixN<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let cols: &[[S; $n]; $n] = self.as_ref();
+                format::fmt_matrix(f, $n, |row, col| &cols[col][row])
+            }
+        }
+
+        /// Parses a list of the rows of the matrix, in the same format as
+        /// `Display`.
+        impl<S: Copy + FromStr> FromStr for $MatrixN<S> {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<$MatrixN<S>, ParseError> {
+                let mut rows = try!(format::parse_list(s, $n));
+                let rows = $MatrixN {
+                    $($field: try!(format::parse_next(&mut rows, $VectorN::from_str))),+
+                };
+                // The rows were parsed into the columns
+                Ok($MatrixN::from(rows.to_rows_array()))
+            }
+        }
+    }
+}
+
+impl_fmt_matrix!(Matrix2, Vector2 { x, y }, 2);
+impl_fmt_matrix!(Matrix3, Vector3 { x, y, z }, 3);
+impl_fmt_matrix!(Matrix4, Vector4 { x, y, z, w }, 4);
+
+#[cfg(feature = "rand")]
 impl<S: BaseFloat + Rand> Rand for Matrix2<S> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Matrix2<S> {
@@ -1231,6 +1441,7 @@ impl<S: BaseFloat + Rand> Rand for Matrix2<S> {
     }
 }
 
+#[cfg(feature = "rand")]
 impl<S: BaseFloat + Rand> Rand for Matrix3<S> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Matrix3<S> {
@@ -1238,6 +1449,7 @@ impl<S: BaseFloat + Rand> Rand for Matrix3<S> {
     }
 }
 
+#[cfg(feature = "rand")]
 impl<S: BaseFloat + Rand> Rand for Matrix4<S> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Matrix4<S> {
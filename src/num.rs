@@ -15,9 +15,9 @@
 
 use approx::ApproxEq;
 
-use std::cmp;
-use std::fmt;
-use std::ops::*;
+use core::cmp;
+use core::fmt;
+use core::ops::*;
 
 use num_traits::{Float, Num, NumCast};
 
@@ -0,0 +1,199 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Safe casts between slices of plain old data, such as for uploading
+//! vertices to the GPU.
+//!
+//! ```rust
+//! use cgmath::Vector3;
+//! use cgmath::pod;
+//!
+//! let vertices = [Vector3::new(1.0f32, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+//!
+//! // Upload to a buffer
+//! let bytes: &[u8] = pod::as_bytes(&vertices);
+//! assert_eq!(bytes.len(), 24);
+//!
+//! // Read it back
+//! let read: &[Vector3<f32>] = pod::cast_slice(bytes).unwrap();
+//! assert_eq!(read, &vertices[..]);
+//!
+//! // Group a flat list of components into vectors
+//! let floats = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+//! let grouped: &[Vector3<f32>] = pod::cast_slice(&floats).unwrap();
+//! assert_eq!(grouped, &vertices[..]);
+//! ```
+
+use core::mem;
+use core::slice;
+
+use angle::{Deg, Grad, Rad, Turns};
+use dual_quaternion::DualQuaternion;
+use euler::Euler;
+use matrix::{Matrix2, Matrix3, Matrix4};
+use point::{Point1, Point2, Point3};
+use quaternion::Quaternion;
+use rotor::{Bivector3, Rotor3};
+use structure::Angle;
+use typed::{TypedPoint3, TypedTransform, TypedVector3};
+use unit_complex::UnitComplex;
+use vector::{Vector1, Vector2, Vector3, Vector4};
+
+mod private {
+    /// Prevents `Pod` from being implemented outside of this crate.
+    pub trait Sealed {}
+}
+
+/// Plain old data: a type with no padding, for which every pattern of bytes is
+/// a valid value.
+///
+/// This is implemented for the primitive integer and floating point types,
+/// and for the `#[repr(C, packed)]` types made up of them. It is sealed, so
+/// that it can't be implemented for types that don't uphold these guarantees.
+pub trait Pod: Copy + private::Sealed {
+    /// The bytes of the value, in the native byte order.
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self as *const Self as *const u8, mem::size_of::<Self>()) }
+    }
+}
+
+/// View a slice as its bytes, in the native byte order.
+#[inline]
+pub fn as_bytes<T: Pod>(slice: &[T]) -> &[u8] {
+    unsafe { slice::from_raw_parts(slice.as_ptr() as *const u8, slice.len() * mem::size_of::<T>()) }
+}
+
+/// View a mutable slice as its bytes, in the native byte order.
+#[inline]
+pub fn as_bytes_mut<T: Pod>(slice: &mut [T]) -> &mut [u8] {
+    unsafe { slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut u8, slice.len() * mem::size_of::<T>()) }
+}
+
+/// Whether a slice of `A` can be viewed as a slice of `B`, returning the
+/// length in `B`s if it can.
+fn cast_len<A: Pod, B: Pod>(ptr: *const A, len: usize) -> Option<usize> {
+    let bytes = len * mem::size_of::<A>();
+    if bytes % mem::size_of::<B>() == 0 && ptr as usize % mem::align_of::<B>() == 0 {
+        Some(bytes / mem::size_of::<B>())
+    } else {
+        None
+    }
+}
+
+/// View a slice of one type of plain old data as a slice of another.
+///
+/// Returns `None` if the slice does not cover a whole number of `B`s, or if it
+/// is not aligned for `B`. As the cgmath types are packed, they can always be
+/// cast from a slice of bytes, but casting them to their components requires
+/// the slice to be aligned for the component type.
+#[inline]
+pub fn cast_slice<A: Pod, B: Pod>(slice: &[A]) -> Option<&[B]> {
+    if slice.is_empty() {
+        return Some(&[]);
+    }
+    cast_len::<A, B>(slice.as_ptr(), slice.len()).map(|len| unsafe {
+        slice::from_raw_parts(slice.as_ptr() as *const B, len)
+    })
+}
+
+/// View a mutable slice of one type of plain old data as a slice of another.
+///
+/// Returns `None` under the same conditions as `cast_slice`.
+#[inline]
+pub fn cast_slice_mut<A: Pod, B: Pod>(slice: &mut [A]) -> Option<&mut [B]> {
+    if slice.is_empty() {
+        return Some(&mut []);
+    }
+    cast_len::<A, B>(slice.as_ptr(), slice.len()).map(|len| unsafe {
+        slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut B, len)
+    })
+}
+
+macro_rules! impl_pod_primitive {
+    ($($T:ty),+) => {
+        $(
+            impl private::Sealed for $T {}
+            impl Pod for $T {}
+        )+
+    }
+}
+
+impl_pod_primitive!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+macro_rules! impl_pod {
+    ($($Type:ident),+) => {
+        $(
+            impl<S: Pod> private::Sealed for $Type<S> {}
+            impl<S: Pod> Pod for $Type<S> {}
+        )+
+    }
+}
+
+impl_pod!(Vector1, Vector2, Vector3, Vector4);
+impl_pod!(Point1, Point2, Point3);
+impl_pod!(Matrix2, Matrix3, Matrix4);
+impl_pod!(Quaternion, DualQuaternion, UnitComplex, Bivector3, Rotor3);
+impl_pod!(Rad, Deg, Turns, Grad);
+
+impl<A: Angle + Pod> private::Sealed for Euler<A> {}
+impl<A: Angle + Pod> Pod for Euler<A> {}
+
+// The spaces are zero-sized markers, so the typed values have the same layout
+// as the untyped ones.
+impl<S: Pod, U> private::Sealed for TypedPoint3<S, U> {}
+impl<S: Pod, U> Pod for TypedPoint3<S, U> {}
+impl<S: Pod, U> private::Sealed for TypedVector3<S, U> {}
+impl<S: Pod, U> Pod for TypedVector3<S, U> {}
+impl<S: Pod, Src, Dst> private::Sealed for TypedTransform<S, Src, Dst> {}
+impl<S: Pod, Src, Dst> Pod for TypedTransform<S, Src, Dst> {}
+
+// The mint types are `#[repr(C)]`, so they have no padding when their fields
+// all have the same type. Quaternions are left out, as their components are in
+// a different order to `Quaternion`.
+#[cfg(feature = "mint")]
+mod mint_impls {
+    use mint::{ColumnMatrix2, ColumnMatrix3, ColumnMatrix4, Point2, Point3};
+    use mint::{RowMatrix2, RowMatrix3, RowMatrix4, Vector2, Vector3, Vector4};
+
+    use super::{Pod, private};
+
+    impl_pod!(Vector2, Vector3, Vector4);
+    impl_pod!(Point2, Point3);
+    impl_pod!(ColumnMatrix2, ColumnMatrix3, ColumnMatrix4);
+    impl_pod!(RowMatrix2, RowMatrix3, RowMatrix4);
+}
+
+// `mem::transmute` only compiles between types of the same size, so these
+// check at compile time that none of the types have padding.
+macro_rules! assert_size {
+    ($($Type:ty = $size:expr),+) => {
+        #[allow(dead_code)]
+        unsafe fn assert_no_padding() {
+            $(let _: [u8; $size] = mem::transmute(mem::zeroed::<$Type>());)+
+        }
+    }
+}
+
+assert_size!(
+    Vector1<f32> = 4, Vector2<f32> = 8, Vector3<f32> = 12, Vector4<f32> = 16,
+    Point1<f32> = 4, Point2<f32> = 8, Point3<f32> = 12,
+    Matrix2<f32> = 16, Matrix3<f32> = 36, Matrix4<f32> = 64,
+    Quaternion<f32> = 16, DualQuaternion<f32> = 32, UnitComplex<f32> = 8,
+    Bivector3<f32> = 12, Rotor3<f32> = 16,
+    Rad<f32> = 4, Deg<f32> = 4, Turns<f32> = 4, Grad<f32> = 4,
+    Euler<Rad<f32>> = 12,
+    TypedPoint3<f32, ()> = 12, TypedVector3<f32, ()> = 12, TypedTransform<f32, (), ()> = 64
+);
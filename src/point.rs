@@ -17,13 +17,15 @@
 //! disinguishes them from vectors, which have a length and direction, but do
 //! not have a fixed position.
 
-use std::fmt;
-use std::mem;
-use std::ops::*;
+use core::fmt;
+use core::mem;
+use core::ops::*;
+use core::str::FromStr;
 
 use structure::*;
 
 use approx::ApproxEq;
+use format::{self, ParseError};
 use num::{BaseNum, BaseFloat};
 use vector::{Vector1, Vector2, Vector3, Vector4};
 
@@ -33,7 +35,6 @@ use vector::{Vector1, Vector2, Vector3, Vector4};
 #[repr(C, packed)]
 #[derive(PartialEq, Eq, Copy, Clone, Hash)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Point1<S> {
     pub x: S,
 }
@@ -44,7 +45,6 @@ pub struct Point1<S> {
 #[repr(C, packed)]
 #[derive(PartialEq, Eq, Copy, Clone, Hash)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Point2<S> {
     pub x: S,
     pub y: S,
@@ -56,7 +56,6 @@ pub struct Point2<S> {
 #[repr(C, packed)]
 #[derive(PartialEq, Eq, Copy, Clone, Hash)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Point3<S> {
     pub x: S,
     pub y: S,
@@ -107,6 +106,11 @@ macro_rules! impl_point {
                 $PointN { $($field: scalar),+ }
             }
 
+            #[inline]
+            fn len() -> usize {
+                $n
+            }
+
             #[inline]
             fn sum(self) -> S where S: Add<Output = S> {
                 fold_array!(add, { $(self.$field),+ })
@@ -248,6 +252,14 @@ impl_tuple_conversions!(Point1<S> { x }, (S,));
 impl_tuple_conversions!(Point2<S> { x, y }, (S, S));
 impl_tuple_conversions!(Point3<S> { x, y, z }, (S, S, S));
 
+impl_eders!(<S> Point1 { x });
+impl_eders!(<S> Point2 { x, y });
+impl_eders!(<S> Point3 { x, y, z });
+
+impl_arbitrary!(<S> Point1 { x });
+impl_arbitrary!(<S> Point2 { x, y });
+impl_arbitrary!(<S> Point3 { x, y, z });
+
 impl<S: fmt::Debug> fmt::Debug for Point1<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         try!(write!(f, "Point1 "));
@@ -269,6 +281,10 @@ impl<S: fmt::Debug> fmt::Debug for Point3<S> {
     }
 }
 
+impl_fmt_list!(Point1 { x }, 1);
+impl_fmt_list!(Point2 { x, y }, 2);
+impl_fmt_list!(Point3 { x, y, z }, 3);
+
 #[cfg(test)]
 mod tests {
     mod point2 {
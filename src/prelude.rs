@@ -11,3 +11,10 @@ pub use rotation::Rotation3;
 pub use transform::Transform;
 pub use transform::Transform2;
 pub use transform::Transform3;
+
+pub use spline::ControlPoint;
+pub use spline::Spline;
+
+pub use interpolate::Interpolate;
+
+pub use convention::ChangeConvention;
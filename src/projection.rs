@@ -13,12 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core::fmt;
+use core::str::FromStr;
+
 use num_traits::{Zero};
 use num_traits::cast;
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
 
 use structure::Angle;
 
 use angle::Rad;
+use format::{self, ParseError};
 use matrix::Matrix4;
 use num::BaseFloat;
 
@@ -65,10 +71,66 @@ pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S
     }.into()
 }
 
+/// Create a perspective projection matrix for a left-handed coordinate
+/// system, where the camera looks along the positive `z` axis.
+///
+/// Like `perspective`, this maps the near and far planes to `-1` and `1` in
+/// normalized device coordinates.
+pub fn perspective_lh<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S, far: S) -> Matrix4<S> {
+    flip_z(perspective(fovy, aspect, near, far))
+}
+
+/// Create a perspective matrix from a view frustrum, for a left-handed
+/// coordinate system where the camera looks along the positive `z` axis.
+pub fn frustum_lh<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
+    flip_z(frustum(left, right, bottom, top, near, far))
+}
+
+/// Create an orthographic projection matrix for a left-handed coordinate
+/// system, where the camera looks along the positive `z` axis.
+pub fn ortho_lh<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
+    flip_z(ortho(left, right, bottom, top, near, far))
+}
+
+/// Negate the `z` coordinate of the input to a projection, converting it
+/// between right-handed and left-handed view spaces.
+#[inline]
+fn flip_z<S: BaseFloat>(mut m: Matrix4<S>) -> Matrix4<S> {
+    m.z = -m.z;
+    m
+}
+
+macro_rules! impl_fmt_frustum {
+    ($Projection:ident) => {
+        /// Formatted as a list of the fields in order, such as
+        /// `[-1, 1, -1, 1, 0.1, 100]`.
+        impl<S: fmt::Display> fmt::Display for $Projection<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                format::fmt_list(f, &[&self.left, &self.right, &self.bottom, &self.top, &self.near, &self.far])
+            }
+        }
+
+        impl<S: FromStr> FromStr for $Projection<S> {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<$Projection<S>, ParseError> {
+                let mut fields = try!(format::parse_list(s, 6));
+                Ok($Projection {
+                    left: try!(format::parse_next(&mut fields, format::parse_scalar)),
+                    right: try!(format::parse_next(&mut fields, format::parse_scalar)),
+                    bottom: try!(format::parse_next(&mut fields, format::parse_scalar)),
+                    top: try!(format::parse_next(&mut fields, format::parse_scalar)),
+                    near: try!(format::parse_next(&mut fields, format::parse_scalar)),
+                    far: try!(format::parse_next(&mut fields, format::parse_scalar)),
+                })
+            }
+        }
+    }
+}
+
 /// A perspective projection based on a vertical field-of-view angle.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct PerspectiveFov<S> {
     pub fovy:   Rad<S>,
     pub aspect: S,
@@ -76,6 +138,38 @@ pub struct PerspectiveFov<S> {
     pub far:    S,
 }
 
+impl_eders!(<S> PerspectiveFov { fovy, aspect, near, far });
+
+/// Formatted as a list of the fields in order, such as
+/// `[1.5 rad, 1.25, 0.1, 100]`.
+impl<S: fmt::Display> fmt::Display for PerspectiveFov<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(f.write_str("["));
+        try!(fmt::Display::fmt(&self.fovy, f));
+        for value in &[&self.aspect, &self.near, &self.far] {
+            try!(f.write_str(", "));
+            try!(fmt::Display::fmt(value, f));
+        }
+        f.write_str("]")
+    }
+}
+
+/// Parses a list in the same format as `Display`. The field of view may be
+/// given in any unit accepted by `Rad`.
+impl<S: BaseFloat + FromStr> FromStr for PerspectiveFov<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<PerspectiveFov<S>, ParseError> {
+        let mut fields = try!(format::parse_list(s, 4));
+        Ok(PerspectiveFov {
+            fovy: try!(format::parse_next(&mut fields, Rad::from_str)),
+            aspect: try!(format::parse_next(&mut fields, format::parse_scalar)),
+            near: try!(format::parse_next(&mut fields, format::parse_scalar)),
+            far: try!(format::parse_next(&mut fields, format::parse_scalar)),
+        })
+    }
+}
+
 impl<S: BaseFloat> PerspectiveFov<S> {
     pub fn to_perspective(&self) -> Perspective<S> {
         let two: S = cast(2).unwrap();
@@ -136,7 +230,6 @@ impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
 /// A perspective projection with arbitrary left/right/bottom/top distances
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Perspective<S> {
     pub left:   S,
     pub right:  S,
@@ -146,6 +239,9 @@ pub struct Perspective<S> {
     pub far:    S,
 }
 
+impl_eders!(<S> Perspective { left, right, bottom, top, near, far });
+impl_fmt_frustum!(Perspective);
+
 impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
     fn from(persp: Perspective<S>) -> Matrix4<S> {
         assert!(persp.left   <= persp.right, "`left` cannot be greater than `right`, found: left: {:?} right: {:?}", persp.left, persp.right);
@@ -184,7 +280,6 @@ impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
 /// An orthographic projection with arbitrary left/right/bottom/top distances
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Ortho<S> {
     pub left:   S,
     pub right:  S,
@@ -194,6 +289,9 @@ pub struct Ortho<S> {
     pub far:    S,
 }
 
+impl_eders!(<S> Ortho { left, right, bottom, top, near, far });
+impl_fmt_frustum!(Ortho);
+
 impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
     fn from(ortho: Ortho<S>) -> Matrix4<S> {
         let two: S = cast(2).unwrap();
@@ -224,3 +322,98 @@ impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
                      c3r0, c3r1, c3r2, c3r3)
     }
 }
+
+/// Order a pair of scalars, for generating the bounds of projections.
+#[cfg(feature = "quickcheck")]
+#[inline]
+fn ordered<S: BaseFloat>(a: S, b: S) -> (S, S) {
+    (a.min(b), a.max(b))
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat> PerspectiveFov<S> {
+    fn is_valid(&self) -> bool {
+        self.fovy > Rad::zero() && self.fovy < Rad::turn_div_2() &&
+        self.aspect > S::zero() && self.near > S::zero() && self.far > self.near
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for PerspectiveFov<S> {
+    /// Generate a projection with a field of view of less than a half turn,
+    /// a positive aspect ratio, and the far plane beyond the near plane.
+    fn arbitrary<G: Gen>(g: &mut G) -> PerspectiveFov<S> {
+        loop {
+            let (near, far) = ordered(S::arbitrary(g).abs(), S::arbitrary(g).abs());
+            let persp = PerspectiveFov {
+                fovy: Rad::turn_div_2() * cast(g.gen::<f64>()).unwrap(),
+                aspect: S::arbitrary(g).abs(),
+                near: near,
+                far: far,
+            };
+            if persp.is_valid() {
+                return persp;
+            }
+        }
+    }
+
+    fn shrink(&self) -> Box<Iterator<Item = PerspectiveFov<S>>> {
+        Box::new(shrink_fields!(self, { fovy, aspect, near, far }).filter(PerspectiveFov::is_valid))
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat> Perspective<S> {
+    fn is_valid(&self) -> bool {
+        self.left < self.right && self.bottom < self.top &&
+        self.near > S::zero() && self.far > self.near
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for Perspective<S> {
+    /// Generate a projection with a non-empty view volume in front of the
+    /// camera.
+    fn arbitrary<G: Gen>(g: &mut G) -> Perspective<S> {
+        loop {
+            let (left, right) = ordered(S::arbitrary(g), S::arbitrary(g));
+            let (bottom, top) = ordered(S::arbitrary(g), S::arbitrary(g));
+            let (near, far) = ordered(S::arbitrary(g).abs(), S::arbitrary(g).abs());
+            let persp = Perspective { left: left, right: right, bottom: bottom, top: top, near: near, far: far };
+            if persp.is_valid() {
+                return persp;
+            }
+        }
+    }
+
+    fn shrink(&self) -> Box<Iterator<Item = Perspective<S>>> {
+        Box::new(shrink_fields!(self, { left, right, bottom, top, near, far }).filter(Perspective::is_valid))
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat> Ortho<S> {
+    fn is_valid(&self) -> bool {
+        self.left < self.right && self.bottom < self.top && self.near < self.far
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for Ortho<S> {
+    /// Generate a projection with a non-empty view volume.
+    fn arbitrary<G: Gen>(g: &mut G) -> Ortho<S> {
+        loop {
+            let (left, right) = ordered(S::arbitrary(g), S::arbitrary(g));
+            let (bottom, top) = ordered(S::arbitrary(g), S::arbitrary(g));
+            let (near, far) = ordered(S::arbitrary(g), S::arbitrary(g));
+            let ortho = Ortho { left: left, right: right, bottom: bottom, top: top, near: near, far: far };
+            if ortho.is_valid() {
+                return ortho;
+            }
+        }
+    }
+
+    fn shrink(&self) -> Box<Iterator<Item = Ortho<S>>> {
+        Box::new(shrink_fields!(self, { left, right, bottom, top, near, far }).filter(Ortho::is_valid))
+    }
+}
@@ -13,21 +13,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::mem;
-use std::ops::*;
+use core::fmt;
+use core::mem;
+use core::ops::*;
+use core::str::FromStr;
 
+#[cfg(feature = "rand")]
 use rand::{Rand, Rng};
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
 use num_traits::cast;
 
 use structure::*;
 
 use angle::Rad;
+#[cfg(feature = "quickcheck")]
+use arbitrary;
 use approx::ApproxEq;
 use euler::Euler;
+use format::{self, ParseError};
 use matrix::{Matrix3, Matrix4};
 use num::BaseFloat;
 use point::Point3;
 use rotation::{Rotation, Rotation3, Basis3};
+#[cfg(feature = "rand")]
+use sample;
 use vector::Vector3;
 
 
@@ -38,7 +48,6 @@ use vector::Vector3;
 #[repr(C, packed)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Quaternion<S> {
     /// The scalar part of the quaternion.
     pub s: S,
@@ -46,6 +55,8 @@ pub struct Quaternion<S> {
     pub v: Vector3<S>,
 }
 
+impl_eders!(<S> Quaternion { s, v });
+
 impl<S: BaseFloat> Quaternion<S> {
     /// Construct a new quaternion from one scalar component and three
     /// imaginary components
@@ -307,6 +318,25 @@ impl<S: BaseFloat> ApproxEq for Quaternion<S> {
     }
 }
 
+/// Formatted as a sum, such as `1 + 2i - 3j + 4k`.
+impl<S: BaseFloat + fmt::Display> fmt::Display for Quaternion<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format::fmt_sum(f, &[(self.s, ""), (self.v.x, "i"), (self.v.y, "j"), (self.v.z, "k")])
+    }
+}
+
+/// Parses a sum in the same format as `Display`. The terms may be given in any
+/// order, and any that are left out are zero.
+impl<S: BaseFloat + FromStr> FromStr for Quaternion<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Quaternion<S>, ParseError> {
+        let mut parts = [S::zero(); 4];
+        try!(format::parse_sum(s, &["", "i", "j", "k"], &mut parts));
+        Ok(Quaternion::new(parts[0], parts[1], parts[2], parts[3]))
+    }
+}
+
 impl<S: BaseFloat> From<Quaternion<S>> for Matrix3<S> {
     /// Convert the quaternion to a 3 x 3 rotation matrix
     fn from(quat: Quaternion<S>) -> Matrix3<S> {
@@ -503,10 +533,27 @@ index_operators!(S, [S], RangeTo<usize>);
 index_operators!(S, [S], RangeFrom<usize>);
 index_operators!(S, [S], RangeFull);
 
+#[cfg(feature = "rand")]
 impl<S: BaseFloat + Rand> Rand for Quaternion<S> {
+    /// Generate a unit quaternion, uniformly distributed over the space of
+    /// rotations. See `sample::unit_quaternion`.
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Quaternion<S> {
-       Quaternion::from_sv(rng.gen(), rng.gen())
+        sample::unit_quaternion(rng)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for Quaternion<S> {
+    /// Generate a unit quaternion, uniformly distributed over the space of
+    /// rotations. See `arbitrary::unit_quaternion`.
+    fn arbitrary<G: Gen>(g: &mut G) -> Quaternion<S> {
+        arbitrary::unit_quaternion(g)
+    }
+
+    /// Shrink towards the identity. See `arbitrary::shrink_rotation`.
+    fn shrink(&self) -> Box<Iterator<Item = Quaternion<S>>> {
+        arbitrary::shrink_rotation(*self)
     }
 }
 
@@ -13,18 +13,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
+use core::str::FromStr;
+
+#[cfg(feature = "rand")]
+use rand::{Rand, Rng};
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
 
 use structure::*;
 
 use angle::Rad;
+#[cfg(feature = "quickcheck")]
+use arbitrary;
 use approx::ApproxEq;
 use euler::Euler;
+use format::{self, ParseError};
 use matrix::{Matrix2, Matrix3};
 use num::BaseFloat;
 use point::{Point2, Point3};
 use quaternion::Quaternion;
+#[cfg(feature = "rand")]
+use sample;
+use unit_complex::UnitComplex;
 use vector::{Vector2, Vector3};
 
 /// A trait for a generic rotation. A rotation is a transformation that
@@ -141,11 +153,12 @@ pub trait Rotation3<S: BaseFloat>: Rotation<Point3<S>>
 /// ```
 #[derive(PartialEq, Copy, Clone)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Basis2<S> {
     mat: Matrix2<S>
 }
 
+impl_eders!(<S> Basis2 { mat });
+
 impl<S: BaseFloat> AsRef<Matrix2<S>> for Basis2<S> {
     #[inline]
     fn as_ref(&self) -> &Matrix2<S> {
@@ -158,6 +171,11 @@ impl<S: BaseFloat> From<Basis2<S>> for Matrix2<S> {
     fn from(b: Basis2<S>) -> Matrix2<S> { b.mat }
 }
 
+impl<S: BaseFloat> From<UnitComplex<S>> for Basis2<S> {
+    #[inline]
+    fn from(c: UnitComplex<S>) -> Basis2<S> { Basis2 { mat: c.into() } }
+}
+
 impl<S: BaseFloat> Rotation<Point2<S>> for Basis2<S> {
     #[inline]
     fn look_at(dir: Vector2<S>, up: Vector2<S>) -> Basis2<S> {
@@ -200,6 +218,19 @@ impl<S: BaseFloat> Rotation2<S> for Basis2<S> {
     fn from_angle(theta: Rad<S>) -> Basis2<S> { Basis2 { mat: Matrix2::from_angle(theta) } }
 }
 
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for Basis2<S> {
+    /// Generate a rotation by an arbitrary angle.
+    fn arbitrary<G: Gen>(g: &mut G) -> Basis2<S> {
+        UnitComplex::arbitrary(g).into()
+    }
+
+    /// Shrink the angle of the rotation towards zero.
+    fn shrink(&self) -> Box<Iterator<Item = Basis2<S>>> {
+        Box::new(UnitComplex::from(*self).shrink().map(Basis2::from))
+    }
+}
+
 impl<S: fmt::Debug> fmt::Debug for Basis2<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         try!(write!(f, "Basis2 "));
@@ -207,6 +238,27 @@ impl<S: fmt::Debug> fmt::Debug for Basis2<S> {
     }
 }
 
+impl<S: fmt::Display> fmt::Display for Basis2<S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.mat, f)
+    }
+}
+
+/// Parses a `Matrix2`, failing if it is not orthonormal.
+impl<S: BaseFloat + FromStr> FromStr for Basis2<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Basis2<S>, ParseError> {
+        let mat: Matrix2<S> = try!(s.parse());
+        if mat.is_orthonormal() {
+            Ok(Basis2 { mat: mat })
+        } else {
+            Err(format::error("matrix is not orthonormal"))
+        }
+    }
+}
+
 /// A three-dimensional rotation matrix.
 ///
 /// The matrix is guaranteed to be orthogonal, so some operations, specifically
@@ -215,17 +267,33 @@ impl<S: fmt::Debug> fmt::Debug for Basis2<S> {
 /// been restricted to a subeset of those implemented on `Matrix3`.
 #[derive(PartialEq, Copy, Clone)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Basis3<S> {
     mat: Matrix3<S>
 }
 
+impl_eders!(<S> Basis3 { mat });
+
 impl<S: BaseFloat> Basis3<S> {
     /// Create a new rotation matrix from a quaternion.
     #[inline]
     pub fn from_quaternion(quaternion: &Quaternion<S>) -> Basis3<S> {
         Basis3 { mat: quaternion.clone().into() }
     }
+
+    /// Returns `true` if the underlying matrix is still orthonormal, within
+    /// the default `ApproxEq` tolerance.
+    #[inline]
+    pub fn is_orthonormal(&self) -> bool {
+        self.mat.is_orthonormal()
+    }
+
+    /// Correct any numerical drift in the rotation by re-orthonormalizing the
+    /// underlying matrix. See `Matrix3::orthonormalize` for details.
+    #[inline]
+    #[must_use]
+    pub fn orthonormalize(&self) -> Basis3<S> {
+        Basis3 { mat: self.mat.orthonormalize() }
+    }
 }
 
 impl<S> AsRef<Matrix3<S>> for Basis3<S> {
@@ -313,9 +381,54 @@ impl<A: Angle> From<Euler<A>> for Basis3<<A as Angle>::Unitless> where
     }
 }
 
+#[cfg(feature = "rand")]
+impl<S: BaseFloat + Rand> Rand for Basis3<S> {
+    /// Generate a rotation, uniformly distributed over the space of rotations.
+    /// See `sample::basis3`.
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Basis3<S> {
+        sample::basis3(rng)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for Basis3<S> {
+    /// Generate a rotation, uniformly distributed over the space of rotations.
+    /// See `arbitrary::unit_quaternion`.
+    fn arbitrary<G: Gen>(g: &mut G) -> Basis3<S> {
+        arbitrary::unit_quaternion(g).into()
+    }
+
+    /// Shrink towards the identity. See `arbitrary::shrink_rotation`.
+    fn shrink(&self) -> Box<Iterator<Item = Basis3<S>>> {
+        Box::new(arbitrary::shrink_rotation((*self).into()).map(Basis3::from))
+    }
+}
+
 impl<S: fmt::Debug> fmt::Debug for Basis3<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         try!(write!(f, "Basis3 "));
         <[[S; 3]; 3] as fmt::Debug>::fmt(self.mat.as_ref(), f)
     }
 }
+
+impl<S: fmt::Display> fmt::Display for Basis3<S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.mat, f)
+    }
+}
+
+/// Parses a `Matrix3`, failing if it is not orthonormal.
+impl<S: BaseFloat + FromStr> FromStr for Basis3<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Basis3<S>, ParseError> {
+        let mat: Matrix3<S> = try!(s.parse());
+        if mat.is_orthonormal() {
+            Ok(Basis3 { mat: mat })
+        } else {
+            Err(format::error("matrix is not orthonormal"))
+        }
+    }
+}
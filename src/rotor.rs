@@ -0,0 +1,473 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::ops::*;
+use core::str::FromStr;
+
+#[cfg(feature = "rand")]
+use rand::{Rand, Rng};
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
+
+use num_traits::cast;
+
+use structure::*;
+
+use angle::Rad;
+#[cfg(feature = "quickcheck")]
+use arbitrary;
+use approx::ApproxEq;
+use euler::Euler;
+use format::{self, ParseError};
+use interpolate::Interpolate;
+use matrix::Matrix3;
+use num::{BaseFloat, BaseNum};
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::{Basis3, Rotation, Rotation3};
+use vector::Vector3;
+
+/// A bivector in three dimensions, representing an oriented plane segment.
+///
+/// Bivectors are produced by the wedge product of two vectors, `a ∧ b`. Their
+/// components are the projections of the plane onto each of the coordinate
+/// planes, and the magnitude is the area of the parallelogram spanned by the
+/// two vectors. The components of `a ∧ b` are those of the cross product
+/// `a × b`, but they transform as a plane rather than as an axis.
+///
+/// This type is marked as `#[repr(C, packed)]`.
+#[repr(C, packed)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct Bivector3<S> {
+    /// The component in the `e1 ∧ e2` plane.
+    pub xy: S,
+    /// The component in the `e2 ∧ e3` plane.
+    pub yz: S,
+    /// The component in the `e3 ∧ e1` plane.
+    pub zx: S,
+}
+
+impl_eders!(<S> Bivector3 { xy, yz, zx });
+impl_arbitrary!(<S> Bivector3 { xy, yz, zx });
+
+impl<S: BaseNum> Bivector3<S> {
+    /// Construct a new bivector from its components.
+    #[inline]
+    pub fn new(xy: S, yz: S, zx: S) -> Bivector3<S> {
+        Bivector3 { xy: xy, yz: yz, zx: zx }
+    }
+
+    /// The unit bivector in the `e1 ∧ e2` plane.
+    #[inline]
+    pub fn unit_xy() -> Bivector3<S> {
+        Bivector3::new(S::one(), S::zero(), S::zero())
+    }
+
+    /// The unit bivector in the `e2 ∧ e3` plane.
+    #[inline]
+    pub fn unit_yz() -> Bivector3<S> {
+        Bivector3::new(S::zero(), S::one(), S::zero())
+    }
+
+    /// The unit bivector in the `e3 ∧ e1` plane.
+    #[inline]
+    pub fn unit_zx() -> Bivector3<S> {
+        Bivector3::new(S::zero(), S::zero(), S::one())
+    }
+
+    /// The bivector representing the plane perpendicular to `axis`, oriented
+    /// so that rotations in the plane are right-handed about the axis.
+    #[inline]
+    pub fn from_axis(axis: Vector3<S>) -> Bivector3<S> {
+        Bivector3::new(axis.z, axis.x, axis.y)
+    }
+
+    /// The vector perpendicular to the plane, with the same magnitude. This
+    /// is the inverse of `from_axis`.
+    #[inline]
+    pub fn axis(self) -> Vector3<S> {
+        Vector3::new(self.yz, self.zx, self.xy)
+    }
+
+    /// The dot product of two bivectors.
+    #[inline]
+    pub fn dot(self, other: Bivector3<S>) -> S {
+        self.xy * other.xy + self.yz * other.yz + self.zx * other.zx
+    }
+
+    /// The squared magnitude of the bivector.
+    #[inline]
+    pub fn magnitude2(self) -> S {
+        self.dot(self)
+    }
+}
+
+impl<S: BaseFloat> Bivector3<S> {
+    /// The magnitude of the bivector, which is the area of the oriented plane
+    /// segment.
+    #[inline]
+    pub fn magnitude(self) -> S {
+        self.magnitude2().sqrt()
+    }
+
+    /// Rescale the bivector to have a magnitude of `1`.
+    #[inline]
+    pub fn normalize(self) -> Bivector3<S> {
+        self / self.magnitude()
+    }
+}
+
+impl<S: BaseNum> Zero for Bivector3<S> {
+    #[inline]
+    fn zero() -> Bivector3<S> {
+        Bivector3::new(S::zero(), S::zero(), S::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == Bivector3::zero()
+    }
+}
+
+impl_operator!(<S: BaseFloat> Neg for Bivector3<S> {
+    fn neg(b) -> Bivector3<S> { Bivector3::new(-b.xy, -b.yz, -b.zx) }
+});
+
+impl_operator!(<S: BaseNum> Add<Bivector3<S> > for Bivector3<S> {
+    fn add(lhs, rhs) -> Bivector3<S> {
+        Bivector3::new(lhs.xy + rhs.xy, lhs.yz + rhs.yz, lhs.zx + rhs.zx)
+    }
+});
+
+impl_operator!(<S: BaseNum> Sub<Bivector3<S> > for Bivector3<S> {
+    fn sub(lhs, rhs) -> Bivector3<S> {
+        Bivector3::new(lhs.xy - rhs.xy, lhs.yz - rhs.yz, lhs.zx - rhs.zx)
+    }
+});
+
+impl_operator!(<S: BaseNum> Mul<S> for Bivector3<S> {
+    fn mul(lhs, scalar) -> Bivector3<S> {
+        Bivector3::new(lhs.xy * scalar, lhs.yz * scalar, lhs.zx * scalar)
+    }
+});
+
+impl_operator!(<S: BaseNum> Div<S> for Bivector3<S> {
+    fn div(lhs, scalar) -> Bivector3<S> {
+        Bivector3::new(lhs.xy / scalar, lhs.yz / scalar, lhs.zx / scalar)
+    }
+});
+
+impl<S: BaseFloat> ApproxEq for Bivector3<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Bivector3<S>, epsilon: &S) -> bool {
+        self.xy.approx_eq_eps(&other.xy, epsilon) &&
+        self.yz.approx_eq_eps(&other.yz, epsilon) &&
+        self.zx.approx_eq_eps(&other.zx, epsilon)
+    }
+}
+
+/// Formatted as a sum of its components in each plane, such as
+/// `1xy + 2yz - 3zx`.
+impl<S: BaseFloat + fmt::Display> fmt::Display for Bivector3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format::fmt_sum(f, &[(self.xy, "xy"), (self.yz, "yz"), (self.zx, "zx")])
+    }
+}
+
+/// Parses a sum in the same format as `Display`. The terms may be given in any
+/// order, and any that are left out are zero.
+impl<S: BaseFloat + FromStr> FromStr for Bivector3<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Bivector3<S>, ParseError> {
+        let mut parts = [S::zero(); 3];
+        try!(format::parse_sum(s, &["xy", "yz", "zx"], &mut parts));
+        Ok(Bivector3::new(parts[0], parts[1], parts[2]))
+    }
+}
+
+/// A rotor in three dimensions, made up of a scalar and a bivector.
+///
+/// Rotors are elements of the even subalgebra of the geometric algebra
+/// Cl(3,0). A unit rotor `R` rotates a vector `v` with the sandwich product
+/// `R v R̃`, where `R̃` is the reverse of `R`. The rotor taking `a` to `b`
+/// is the geometric product `b a = b · a + b ∧ a`, normalized and with its
+/// angle halved.
+///
+/// Rotors are isomorphic to unit quaternions, and can be converted to and
+/// from them without loss. Unlike quaternions, the plane of rotation is
+/// explicit, which generalises to other dimensions.
+///
+/// ```rust
+/// use cgmath::prelude::*;
+/// use cgmath::{ApproxEq, Bivector3, Rad, Rotor3, Vector3};
+/// use std::f64;
+///
+/// // A quarter turn in the xy plane
+/// let rotor = Rotor3::from_plane_angle(Bivector3::unit_xy(), Rad::new(f64::consts::FRAC_PI_2));
+/// assert!(rotor.rotate_vector(Vector3::unit_x()).approx_eq(&Vector3::unit_y()));
+///
+/// let rotor: Rotor3<f64> = Rotor3::between_vectors(Vector3::unit_y(), Vector3::unit_z());
+/// assert!(rotor.rotate_vector(Vector3::unit_y()).approx_eq(&Vector3::unit_z()));
+/// ```
+///
+/// This type is marked as `#[repr(C, packed)]`.
+#[repr(C, packed)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct Rotor3<S> {
+    /// The scalar part.
+    pub s: S,
+    /// The bivector part.
+    pub b: Bivector3<S>,
+}
+
+impl_eders!(<S> Rotor3 { s, b });
+
+impl<S: BaseFloat> Rotor3<S> {
+    /// Construct a new rotor from its scalar and bivector parts.
+    #[inline]
+    pub fn new(s: S, b: Bivector3<S>) -> Rotor3<S> {
+        Rotor3 { s: s, b: b }
+    }
+
+    /// A rotor rotating by `angle` in the given plane, in the direction from
+    /// the first vector of the plane towards the second. The plane must be
+    /// normalized.
+    #[inline]
+    pub fn from_plane_angle(plane: Bivector3<S>, angle: Rad<S>) -> Rotor3<S> {
+        let half: S = cast(0.5f64).unwrap();
+        let (s, c) = Rad::sin_cos(angle * half);
+        Rotor3::new(c, -plane * s)
+    }
+
+    /// The reverse of the rotor, which negates the bivector part.
+    #[inline]
+    pub fn reverse(self) -> Rotor3<S> {
+        Rotor3::new(self.s, -self.b)
+    }
+
+    /// The squared magnitude of the rotor.
+    #[inline]
+    pub fn magnitude2(self) -> S {
+        self.s * self.s + self.b.magnitude2()
+    }
+
+    /// The magnitude of the rotor, which should be `1` for a valid rotation.
+    #[inline]
+    pub fn magnitude(self) -> S {
+        self.magnitude2().sqrt()
+    }
+
+    /// Rescale the rotor to have a magnitude of `1`.
+    #[inline]
+    pub fn normalize(self) -> Rotor3<S> {
+        let magnitude = self.magnitude();
+        Rotor3::new(self.s / magnitude, self.b / magnitude)
+    }
+
+    /// The plane and angle of the rotation. The plane is arbitrary when the
+    /// rotation is the identity.
+    pub fn to_plane_angle(self) -> (Bivector3<S>, Rad<S>) {
+        let two: S = cast(2).unwrap();
+        let magnitude = self.b.magnitude();
+        let angle = Rad::atan2(magnitude, self.s) * two;
+        if magnitude.is_zero() {
+            (Bivector3::unit_xy(), angle)
+        } else {
+            (-self.b / magnitude, angle)
+        }
+    }
+
+    /// Spherical linear interpolation along the shortest arc between two unit
+    /// rotors.
+    #[inline]
+    pub fn slerp(self, other: Rotor3<S>, amount: S) -> Rotor3<S> {
+        Quaternion::from(self).interpolate(Quaternion::from(other), amount).into()
+    }
+}
+
+impl<S: BaseFloat> One for Rotor3<S> {
+    #[inline]
+    fn one() -> Rotor3<S> {
+        Rotor3::new(S::one(), Bivector3::zero())
+    }
+}
+
+impl_operator!(<S: BaseFloat> Mul<Rotor3<S> > for Rotor3<S> {
+    fn mul(lhs, rhs) -> Rotor3<S> {{
+        // The commutator of two bivectors is the negated cross product of
+        // their axes
+        let (a, b) = (lhs.b.axis(), rhs.b.axis());
+        Rotor3::new(lhs.s * rhs.s - a.dot(b),
+                    rhs.b * lhs.s + lhs.b * rhs.s - Bivector3::from_axis(a.cross(b)))
+    }}
+});
+
+impl_operator!(<S: BaseFloat> Mul<Vector3<S> > for Rotor3<S> {
+    fn mul(lhs, rhs) -> Vector3<S> {{
+        // The sandwich product `R v R̃`, expanded. First `R v`, which has a
+        // vector part `u` and a trivector part `t`.
+        let (s, b, v) = (lhs.s, lhs.b, rhs);
+        let u = Vector3::new(s * v.x + b.xy * v.y - b.zx * v.z,
+                             s * v.y + b.yz * v.z - b.xy * v.x,
+                             s * v.z + b.zx * v.x - b.yz * v.y);
+        let t = b.xy * v.z + b.yz * v.x + b.zx * v.y;
+
+        // Then the vector part of `(u + t) R̃`. The trivector part vanishes
+        // for unit rotors.
+        Vector3::new(s * u.x + b.xy * u.y - b.zx * u.z + t * b.yz,
+                     s * u.y + b.yz * u.z - b.xy * u.x + t * b.zx,
+                     s * u.z + b.zx * u.x - b.yz * u.y + t * b.xy)
+    }}
+});
+
+impl_assignment_operator!(<S: BaseFloat> MulAssign<Rotor3<S> > for Rotor3<S> {
+    fn mul_assign(&mut self, other) { *self = *self * other; }
+});
+
+impl<S: BaseFloat> ApproxEq for Rotor3<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Rotor3<S>, epsilon: &S) -> bool {
+        self.s.approx_eq_eps(&other.s, epsilon) &&
+        self.b.approx_eq_eps(&other.b, epsilon)
+    }
+}
+
+/// Formatted as the scalar plus the bivector, such as `0.5 + 0.5xy - 0.5yz +
+/// 0.5zx`.
+impl<S: BaseFloat + fmt::Display> fmt::Display for Rotor3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format::fmt_sum(f, &[(self.s, ""), (self.b.xy, "xy"), (self.b.yz, "yz"), (self.b.zx, "zx")])
+    }
+}
+
+/// Parses a sum in the same format as `Display`. The terms may be given in any
+/// order, and any that are left out are zero.
+impl<S: BaseFloat + FromStr> FromStr for Rotor3<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Rotor3<S>, ParseError> {
+        let mut parts = [S::zero(); 4];
+        try!(format::parse_sum(s, &["", "xy", "yz", "zx"], &mut parts));
+        Ok(Rotor3::new(parts[0], Bivector3::new(parts[1], parts[2], parts[3])))
+    }
+}
+
+impl<S: BaseFloat> Rotation<Point3<S>> for Rotor3<S> {
+    #[inline]
+    fn look_at(dir: Vector3<S>, up: Vector3<S>) -> Rotor3<S> {
+        Quaternion::look_at(dir, up).into()
+    }
+
+    #[inline]
+    fn between_vectors(a: Vector3<S>, b: Vector3<S>) -> Rotor3<S> {
+        // `1 + b a`, which has half the angle of `b a`
+        Rotor3::new(S::one() + b.dot(a), b.wedge(a)).normalize()
+    }
+
+    #[inline]
+    fn rotate_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self * vec
+    }
+
+    #[inline]
+    fn invert(&self) -> Rotor3<S> {
+        let r = self.reverse();
+        let magnitude2 = self.magnitude2();
+        Rotor3::new(r.s / magnitude2, r.b / magnitude2)
+    }
+}
+
+impl<S: BaseFloat> Rotation3<S> for Rotor3<S> {
+    #[inline]
+    fn from_axis_angle(axis: Vector3<S>, angle: Rad<S>) -> Rotor3<S> {
+        Rotor3::from_plane_angle(Bivector3::from_axis(axis), angle)
+    }
+}
+
+impl<S: BaseFloat> From<Quaternion<S>> for Rotor3<S> {
+    #[inline]
+    fn from(q: Quaternion<S>) -> Rotor3<S> {
+        Rotor3::new(q.s, -Bivector3::from_axis(q.v))
+    }
+}
+
+impl<S: BaseFloat> From<Rotor3<S>> for Quaternion<S> {
+    #[inline]
+    fn from(r: Rotor3<S>) -> Quaternion<S> {
+        Quaternion::from_sv(r.s, -r.b.axis())
+    }
+}
+
+impl<S: BaseFloat> From<Rotor3<S>> for Matrix3<S> {
+    #[inline]
+    fn from(r: Rotor3<S>) -> Matrix3<S> {
+        Quaternion::from(r).into()
+    }
+}
+
+impl<S: BaseFloat> From<Rotor3<S>> for Basis3<S> {
+    #[inline]
+    fn from(r: Rotor3<S>) -> Basis3<S> {
+        Quaternion::from(r).into()
+    }
+}
+
+impl<S: BaseFloat> From<Euler<Rad<S>>> for Rotor3<S> {
+    #[inline]
+    fn from(euler: Euler<Rad<S>>) -> Rotor3<S> {
+        Quaternion::from(euler).into()
+    }
+}
+
+/// Uses spherical linear interpolation along the shortest arc between the two
+/// rotations.
+impl<S: BaseFloat> Interpolate<S> for Rotor3<S> {
+    #[inline]
+    fn interpolate(self, other: Rotor3<S>, amount: S) -> Rotor3<S> {
+        self.slerp(other, amount)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<S: BaseFloat + Rand> Rand for Rotor3<S> {
+    /// Generate a rotation, uniformly distributed over the space of rotations.
+    /// See `sample::unit_quaternion`.
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Rotor3<S> {
+        Quaternion::rand(rng).into()
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for Rotor3<S> {
+    /// Generate a rotation, uniformly distributed over the space of rotations.
+    /// See `arbitrary::unit_quaternion`.
+    fn arbitrary<G: Gen>(g: &mut G) -> Rotor3<S> {
+        arbitrary::unit_quaternion(g).into()
+    }
+
+    /// Shrink towards the identity. See `arbitrary::shrink_rotation`.
+    fn shrink(&self) -> Box<Iterator<Item = Rotor3<S>>> {
+        Box::new(arbitrary::shrink_rotation((*self).into()).map(Rotor3::from))
+    }
+}
@@ -0,0 +1,381 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functions for drawing uniformly distributed rotations, directions and
+//! points from a random number generator.
+//!
+//! Filling each component of a type with `rng.gen()` rarely gives the
+//! distribution you want for geometric quantities. For example normalizing a
+//! random vector with components in `[0, 1)` only ever produces directions in
+//! the first octant, and even using `[-1, 1)` biases the results towards the
+//! corners of the cube. The functions in this module take care to produce the
+//! correct distributions.
+//!
+//! # Random sampling
+//!
+//! The random sampling functions need the `rand` feature. They are also
+//! available as the distributions of the `distributions` module.
+//!
+//! ```rust
+//! # #[cfg(feature = "rand")]
+//! extern crate rand;
+//! extern crate cgmath;
+//!
+//! use cgmath::prelude::*;
+//! use cgmath::{Quaternion, Vector3};
+//! use cgmath::sample;
+//!
+//! # #[cfg(not(feature = "rand"))] fn main() {}
+//! # #[cfg(feature = "rand")]
+//! # fn main() {
+//! let mut rng = rand::thread_rng();
+//!
+//! let rotation: Quaternion<f32> = sample::unit_quaternion(&mut rng);
+//! let direction: Vector3<f32> = sample::on_unit_sphere(&mut rng);
+//! let rotated = rotation.rotate_vector(direction);
+//! # }
+//! ```
+//!
+//! # Low-discrepancy sequences
+//!
+//! For numerical integration, such as in lightmap baking or ambient occlusion,
+//! it is often better to use a deterministic sequence of points that covers
+//! the unit square or cube more evenly than random samples would. This module
+//! provides the Halton, Hammersley, Sobol and R2 sequences, which can then be
+//! passed to the `square_to_*` functions to produce stratified directions:
+//!
+//! ```rust
+//! use cgmath::Vector3;
+//! use cgmath::sample;
+//!
+//! let directions: Vec<Vector3<f32>> = (0..64)
+//!     .map(|i| sample::square_to_unit_sphere(sample::sobol2(i)))
+//!     .collect();
+//! ```
+
+#[cfg(feature = "rand")]
+use rand::{Rand, Rng};
+use num_traits::cast;
+
+use structure::*;
+
+use angle::Rad;
+use num::BaseFloat;
+use point::Point2;
+#[cfg(feature = "rand")]
+use point::Point3;
+#[cfg(feature = "rand")]
+use quaternion::Quaternion;
+#[cfg(feature = "rand")]
+use rotation::Basis3;
+use vector::{Vector2, Vector3};
+
+/// A random angle in `[0, full_turn)`.
+#[cfg(feature = "rand")]
+#[inline]
+pub(crate) fn turn<S: BaseFloat + Rand, R: Rng>(rng: &mut R) -> Rad<S> {
+    Rad::full_turn() * rng.gen::<S>()
+}
+
+/// A unit quaternion representing a rotation drawn uniformly from the space
+/// of all rotations.
+///
+/// This uses the method described by Ken Shoemake in [Uniform Random
+/// Rotations](http://planning.cs.uiuc.edu/node198.html) (Graphics Gems III).
+#[cfg(feature = "rand")]
+pub fn unit_quaternion<S: BaseFloat + Rand, R: Rng>(rng: &mut R) -> Quaternion<S> {
+    let u: S = rng.gen();
+    let (a, b) = ((S::one() - u).sqrt(), u.sqrt());
+    let (s1, c1) = Rad::sin_cos(turn(rng));
+    let (s2, c2) = Rad::sin_cos(turn(rng));
+
+    Quaternion::new(b * c2, a * s1, a * c1, b * s2)
+}
+
+/// A rotation matrix drawn uniformly from the space of all rotations.
+#[cfg(feature = "rand")]
+#[inline]
+pub fn basis3<S: BaseFloat + Rand, R: Rng>(rng: &mut R) -> Basis3<S> {
+    unit_quaternion(rng).into()
+}
+
+/// A unit vector drawn uniformly from the directions in the plane.
+#[cfg(feature = "rand")]
+#[inline]
+pub fn on_unit_circle<S: BaseFloat + Rand, R: Rng>(rng: &mut R) -> Vector2<S> {
+    let (s, c) = Rad::sin_cos(turn(rng));
+    Vector2::new(c, s)
+}
+
+/// A unit vector drawn uniformly from the surface of the unit sphere.
+#[cfg(feature = "rand")]
+#[inline]
+pub fn on_unit_sphere<S: BaseFloat + Rand, R: Rng>(rng: &mut R) -> Vector3<S> {
+    square_to_unit_sphere(Vector2::new(rng.gen(), rng.gen()))
+}
+
+/// A point drawn uniformly from the interior of the unit disk, centered at the
+/// origin.
+#[cfg(feature = "rand")]
+#[inline]
+pub fn in_unit_disk<S: BaseFloat + Rand, R: Rng>(rng: &mut R) -> Point2<S> {
+    square_to_unit_disk(Vector2::new(rng.gen(), rng.gen()))
+}
+
+/// A point drawn uniformly from the interior of the unit ball, centered at the
+/// origin.
+#[cfg(feature = "rand")]
+pub fn in_unit_ball<S: BaseFloat + Rand, R: Rng>(rng: &mut R) -> Point3<S> {
+    let r = rng.gen::<S>().cbrt();
+    Point3::from_vec(on_unit_sphere(rng) * r)
+}
+
+/// A point drawn uniformly from the axis-aligned rectangle spanning `min` to
+/// `max`.
+#[cfg(feature = "rand")]
+#[inline]
+pub fn in_rect<S: BaseFloat + Rand, R: Rng>(rng: &mut R, min: Point2<S>, max: Point2<S>) -> Point2<S> {
+    min + (max - min).mul_element_wise(Vector2::new(rng.gen(), rng.gen()))
+}
+
+/// A point drawn uniformly from the axis-aligned box spanning `min` to `max`.
+#[cfg(feature = "rand")]
+#[inline]
+pub fn in_box<S: BaseFloat + Rand, R: Rng>(rng: &mut R, min: Point3<S>, max: Point3<S>) -> Point3<S> {
+    min + (max - min).mul_element_wise(Vector3::new(rng.gen(), rng.gen(), rng.gen()))
+}
+
+/// A point drawn uniformly from the interior of the triangle `abc`.
+///
+/// This works for triangles in any dimension, for example with `Point2` or
+/// `Point3`.
+#[cfg(feature = "rand")]
+pub fn in_triangle<P, R>(rng: &mut R, a: P, b: P, c: P) -> P where
+    P: EuclideanSpace,
+    P::Scalar: BaseFloat + Rand,
+    R: Rng,
+{
+    let (mut u, mut v): (P::Scalar, P::Scalar) = (rng.gen(), rng.gen());
+    // Reflect samples from the far half of the parallelogram back into the
+    // triangle
+    if u + v > P::Scalar::one() {
+        u = P::Scalar::one() - u;
+        v = P::Scalar::one() - v;
+    }
+
+    a + (b - a) * u + (c - a) * v
+}
+
+/// A unit vector drawn from the hemisphere around the positive `z` axis, with
+/// a probability density proportional to the cosine of the angle from the
+/// axis.
+///
+/// This is the ideal distribution for importance sampling diffuse
+/// reflections. To orient the hemisphere around a surface normal, combine the
+/// result with the tangents returned by `Vector3::orthonormal_basis`.
+#[cfg(feature = "rand")]
+#[inline]
+pub fn cosine_weighted_hemisphere<S: BaseFloat + Rand, R: Rng>(rng: &mut R) -> Vector3<S> {
+    square_to_cosine_weighted_hemisphere(Vector2::new(rng.gen(), rng.gen()))
+}
+
+/// Map a point in the unit square `[0, 1)²` to a point in the unit disk,
+/// preserving relative areas.
+pub fn square_to_unit_disk<S: BaseFloat>(u: Vector2<S>) -> Point2<S> {
+    let r = u.x.sqrt();
+    let (s, c) = Rad::sin_cos(Rad::full_turn() * u.y);
+
+    Point2::new(r * c, r * s)
+}
+
+/// Map a point in the unit square `[0, 1)²` to a direction on the unit sphere,
+/// preserving relative areas.
+pub fn square_to_unit_sphere<S: BaseFloat>(u: Vector2<S>) -> Vector3<S> {
+    let two: S = cast(2).unwrap();
+    let z = S::one() - two * u.x;
+    let r = (S::one() - z * z).max(S::zero()).sqrt();
+    let (s, c) = Rad::sin_cos(Rad::full_turn() * u.y);
+
+    Vector3::new(r * c, r * s, z)
+}
+
+/// Map a point in the unit square `[0, 1)²` to a direction in the hemisphere
+/// around the positive `z` axis, with a density proportional to the cosine of
+/// the angle from the axis.
+///
+/// This uses Malley's method, projecting a point in the unit disk up onto the
+/// hemisphere.
+pub fn square_to_cosine_weighted_hemisphere<S: BaseFloat>(u: Vector2<S>) -> Vector3<S> {
+    let p = square_to_unit_disk(u);
+    let z = (S::one() - p.x * p.x - p.y * p.y).max(S::zero()).sqrt();
+
+    Vector3::new(p.x, p.y, z)
+}
+
+/// Convert a 32-bit fixed point fraction to a scalar in `[0, 1)`.
+#[inline]
+fn fraction<S: BaseFloat>(bits: u32) -> S {
+    unit_interval(bits as f64 / 4294967296.0)
+}
+
+/// Convert a value in `[0, 1)` to a scalar, making sure that rounding does not
+/// produce `1`.
+#[inline]
+fn unit_interval<S: BaseFloat>(value: f64) -> S {
+    let value: S = cast(value).unwrap();
+    if value < S::one() { value } else { cast(1.0 - 1.0 / 16777216.0).unwrap() }
+}
+
+/// Reverse the bits of `i`, producing the base 2 radical inverse as a 32-bit
+/// fraction.
+#[inline]
+fn reverse_bits(mut i: u32) -> u32 {
+    i = i.rotate_left(16);
+    i = ((i & 0x00ff00ff) << 8) | ((i & 0xff00ff00) >> 8);
+    i = ((i & 0x0f0f0f0f) << 4) | ((i & 0xf0f0f0f0) >> 4);
+    i = ((i & 0x33333333) << 2) | ((i & 0xcccccccc) >> 2);
+    ((i & 0x55555555) << 1) | ((i & 0xaaaaaaaa) >> 1)
+}
+
+/// The [radical inverse](https://en.wikipedia.org/wiki/Van_der_Corput_sequence)
+/// of `i` in the given `base`. That is, the digits of `i` mirrored around the
+/// radix point.
+///
+/// With a base of `2`, this produces the van der Corput sequence.
+///
+/// # Panics
+///
+/// If `base` is less than `2`.
+pub fn radical_inverse<S: BaseFloat>(base: u32, i: u32) -> S {
+    assert!(base >= 2, "The base of the radical inverse must be at least 2, found: {:?}", base);
+    if base == 2 {
+        return fraction(reverse_bits(i));
+    }
+
+    let inv_base = 1.0 / base as f64;
+    let (mut i, mut inv_bi, mut result) = (i, inv_base, 0.0);
+    while i > 0 {
+        result += (i % base) as f64 * inv_bi;
+        inv_bi *= inv_base;
+        i /= base;
+    }
+    unit_interval(result)
+}
+
+/// The `i`th point of the two-dimensional [Halton
+/// sequence](https://en.wikipedia.org/wiki/Halton_sequence), using bases 2 and
+/// 3.
+#[inline]
+pub fn halton2<S: BaseFloat>(i: u32) -> Vector2<S> {
+    Vector2::new(radical_inverse(2, i), radical_inverse(3, i))
+}
+
+/// The `i`th point of the three-dimensional [Halton
+/// sequence](https://en.wikipedia.org/wiki/Halton_sequence), using bases 2, 3
+/// and 5.
+#[inline]
+pub fn halton3<S: BaseFloat>(i: u32) -> Vector3<S> {
+    Vector3::new(radical_inverse(2, i), radical_inverse(3, i), radical_inverse(5, i))
+}
+
+/// The `i`th of `n` points of the two-dimensional Hammersley set.
+///
+/// Unlike the other sequences, the total number of points must be known in
+/// advance, but in exchange the points are more evenly distributed.
+///
+/// `i` must be less than `n`, otherwise the first coordinate falls outside
+/// `[0, 1)`. This is only checked in debug builds.
+#[inline]
+pub fn hammersley2<S: BaseFloat>(i: u32, n: u32) -> Vector2<S> {
+    debug_assert!(i < n, "Hammersley point index out of range");
+    Vector2::new(unit_interval(i as f64 / n as f64), radical_inverse(2, i))
+}
+
+/// The `i`th of `n` points of the three-dimensional Hammersley set, using
+/// bases 2 and 3 for the second and third dimensions.
+///
+/// As with `hammersley2`, `i` must be less than `n`.
+#[inline]
+pub fn hammersley3<S: BaseFloat>(i: u32, n: u32) -> Vector3<S> {
+    debug_assert!(i < n, "Hammersley point index out of range");
+    Vector3::new(unit_interval(i as f64 / n as f64), radical_inverse(2, i), radical_inverse(3, i))
+}
+
+/// Direction numbers for the second dimension of the Sobol sequence, using
+/// the primitive polynomial `x + 1`.
+const SOBOL_DIRECTIONS_1: [u32; 32] = [
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x88000000, 0xcc000000, 0xaa000000, 0xff000000,
+    0x80800000, 0xc0c00000, 0xa0a00000, 0xf0f00000, 0x88880000, 0xcccc0000, 0xaaaa0000, 0xffff0000,
+    0x80008000, 0xc000c000, 0xa000a000, 0xf000f000, 0x88008800, 0xcc00cc00, 0xaa00aa00, 0xff00ff00,
+    0x80808080, 0xc0c0c0c0, 0xa0a0a0a0, 0xf0f0f0f0, 0x88888888, 0xcccccccc, 0xaaaaaaaa, 0xffffffff,
+];
+
+/// Direction numbers for the third dimension of the Sobol sequence, using the
+/// primitive polynomial `x² + x + 1` and initial values `1, 3`.
+const SOBOL_DIRECTIONS_2: [u32; 32] = [
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0xe8000000, 0x5c000000, 0x8e000000, 0xc5000000,
+    0x68800000, 0x9cc00000, 0xee600000, 0x55900000, 0x80680000, 0xc09c0000, 0x60ee0000, 0x90550000,
+    0xe8808000, 0x5cc0c000, 0x8e606000, 0xc5909000, 0x6868e800, 0x9c9c5c00, 0xeeee8e00, 0x5555c500,
+    0x8000e880, 0xc0005cc0, 0x60008e60, 0x9000c590, 0xe8006868, 0x5c009c9c, 0x8e00eeee, 0xc5005555,
+];
+
+/// Compute a component of a Sobol point from the given direction numbers.
+#[inline]
+fn sobol_component(directions: &[u32; 32], mut i: u32) -> u32 {
+    let mut result = 0;
+    let mut j = 0;
+    while i > 0 {
+        if i & 1 == 1 { result ^= directions[j]; }
+        i >>= 1;
+        j += 1;
+    }
+    result
+}
+
+/// The `i`th point of the two-dimensional [Sobol
+/// sequence](https://en.wikipedia.org/wiki/Sobol_sequence).
+///
+/// The first dimension is the van der Corput sequence, and the direction
+/// numbers of the second are the same as those of Joe and Kuo.
+#[inline]
+pub fn sobol2<S: BaseFloat>(i: u32) -> Vector2<S> {
+    Vector2::new(fraction(reverse_bits(i)),
+                 fraction(sobol_component(&SOBOL_DIRECTIONS_1, i)))
+}
+
+/// The `i`th point of the three-dimensional [Sobol
+/// sequence](https://en.wikipedia.org/wiki/Sobol_sequence).
+#[inline]
+pub fn sobol3<S: BaseFloat>(i: u32) -> Vector3<S> {
+    Vector3::new(fraction(reverse_bits(i)),
+                 fraction(sobol_component(&SOBOL_DIRECTIONS_1, i)),
+                 fraction(sobol_component(&SOBOL_DIRECTIONS_2, i)))
+}
+
+/// The `i`th point of Martin Roberts' [R2
+/// sequence](http://extremelearning.com.au/unreasonable-effectiveness-of-quasirandom-sequences/),
+/// an additive recurrence based on the plastic number.
+///
+/// Unlike the Halton and Sobol sequences, this has no preferred sample counts,
+/// and can be cheaply evaluated for any index.
+#[inline]
+pub fn r2<S: BaseFloat>(i: u32) -> Vector2<S> {
+    // The plastic number, the real root of `x³ = x + 1`
+    const G: f64 = 1.324717957244746;
+    let (a1, a2) = (1.0 / G, 1.0 / (G * G));
+
+    Vector2::new(unit_interval((0.5 + a1 * i as f64).fract()),
+                 unit_interval((0.5 + a2 * i as f64).fract()))
+}
@@ -0,0 +1,825 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::{cast, Float};
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
+#[cfg(all(feature = "rustc-serialize", feature = "std"))]
+use rustc_serialize::{Decodable, Decoder};
+
+use structure::*;
+
+use approx::ApproxEq;
+use matrix::Matrix3;
+use num::BaseFloat;
+use point::{Point1, Point2, Point3};
+use rotation::Basis3;
+use vector::Vector3;
+
+/// A value that can be used as the control point of a spline.
+///
+/// This is implemented for all of the vector types, and for points, whose
+/// curves are computed using their displacements from the origin.
+pub trait ControlPoint: Copy {
+    /// The type of the scalar components.
+    type Scalar: BaseFloat;
+
+    /// The vector type that the curve is computed in. This is also the type
+    /// of the derivatives of the curve.
+    type Diff: VectorSpace<Scalar = Self::Scalar> + Array<Element = Self::Scalar>;
+
+    /// Convert the control point to a vector.
+    fn to_diff(self) -> Self::Diff;
+
+    /// Convert a vector back into a control point.
+    fn from_diff(diff: Self::Diff) -> Self;
+}
+
+impl<V> ControlPoint for V where
+    // FIXME: Ugly type signatures - blocked by rust-lang/rust#24092
+    V: VectorSpace + Array<Element = <V as VectorSpace>::Scalar>,
+    <V as VectorSpace>::Scalar: BaseFloat,
+{
+    type Scalar = <V as VectorSpace>::Scalar;
+    type Diff = V;
+
+    #[inline]
+    fn to_diff(self) -> V {
+        self
+    }
+
+    #[inline]
+    fn from_diff(diff: V) -> V {
+        diff
+    }
+}
+
+macro_rules! impl_control_point {
+    ($PointN:ident) => {
+        impl<S: BaseFloat> ControlPoint for $PointN<S> {
+            type Scalar = S;
+            type Diff = <$PointN<S> as EuclideanSpace>::Diff;
+
+            #[inline]
+            fn to_diff(self) -> Self::Diff {
+                self.to_vec()
+            }
+
+            #[inline]
+            fn from_diff(diff: Self::Diff) -> $PointN<S> {
+                $PointN::from_vec(diff)
+            }
+        }
+    }
+}
+
+impl_control_point!(Point1);
+impl_control_point!(Point2);
+impl_control_point!(Point3);
+
+/// A polynomial curve segment of up to the third degree, parameterized over
+/// the interval `[0, 1]`.
+pub trait Spline<P: ControlPoint>: Copy {
+    /// The coefficients `[a, b, c, d]` of the curve in the power basis, such
+    /// that the curve is `a + b*t + c*t^2 + d*t^3`.
+    fn coefficients(&self) -> [P::Diff; 4];
+
+    /// Evaluate the curve at the parameter `t`.
+    #[inline]
+    fn eval(&self, t: P::Scalar) -> P {
+        let k = self.coefficients();
+        P::from_diff(k[0] + (k[1] + (k[2] + k[3] * t) * t) * t)
+    }
+
+    /// The first derivative of the curve at the parameter `t`. This is the
+    /// tangent of the curve, scaled by the speed at which it is traversed.
+    #[inline]
+    fn derivative(&self, t: P::Scalar) -> P::Diff {
+        let k = self.coefficients();
+        let two: P::Scalar = cast(2).unwrap();
+        let three: P::Scalar = cast(3).unwrap();
+        k[1] + (k[2] * two + k[3] * (three * t)) * t
+    }
+
+    /// The second derivative of the curve at the parameter `t`.
+    #[inline]
+    fn second_derivative(&self, t: P::Scalar) -> P::Diff {
+        let k = self.coefficients();
+        let two: P::Scalar = cast(2).unwrap();
+        let six: P::Scalar = cast(6).unwrap();
+        k[2] * two + k[3] * (six * t)
+    }
+
+    /// The smallest axis-aligned box containing the curve, returned as its
+    /// minimum and maximum corners.
+    ///
+    /// This is computed exactly from the extrema of each component, so it is
+    /// generally tighter than the bounds of the control points.
+    fn bounding_box(&self) -> (P, P) {
+        let k = self.coefficients();
+        let two: P::Scalar = cast(2).unwrap();
+        let three: P::Scalar = cast(3).unwrap();
+
+        let start = k[0];
+        let end = k[0] + k[1] + k[2] + k[3];
+        let (mut min, mut max) = (start, start);
+        for i in 0..P::Diff::len() {
+            min[i] = min[i].min(end[i]);
+            max[i] = max[i].max(end[i]);
+
+            for &t in &quadratic_roots(three * k[3][i], two * k[2][i], k[1][i]) {
+                if t > P::Scalar::zero() && t < P::Scalar::one() {
+                    let x = k[0][i] + (k[1][i] + (k[2][i] + k[3][i] * t) * t) * t;
+                    min[i] = min[i].min(x);
+                    max[i] = max[i].max(x);
+                }
+            }
+        }
+
+        (P::from_diff(min), P::from_diff(max))
+    }
+
+    /// The length of the curve.
+    #[inline]
+    fn arc_length(&self) -> P::Scalar where P::Diff: InnerSpace {
+        self.arc_length_between(P::Scalar::zero(), P::Scalar::one())
+    }
+
+    /// The length of the curve between the parameters `t0` and `t1`.
+    ///
+    /// This is computed using adaptive Gauss-Legendre quadrature, subdividing
+    /// the interval until the estimate converges.
+    fn arc_length_between(&self, t0: P::Scalar, t1: P::Scalar) -> P::Scalar where
+        P::Diff: InnerSpace,
+    {
+        let whole = gauss_legendre(self, t0, t1);
+        let tolerance = whole.abs() * cast(1.0e-6).unwrap();
+        adaptive_gauss_legendre(self, t0, t1, whole, tolerance, 12)
+    }
+
+    /// The [Frenet-Serret frame](https://en.wikipedia.org/wiki/Frenet%E2%80%93Serret_formulas)
+    /// of the curve at the parameter `t`.
+    ///
+    /// The frame rotates the `x`, `y` and `z` axes onto the tangent, normal and
+    /// binormal of the curve respectively. The normal points towards the
+    /// center of curvature, so the frame can flip suddenly at inflection
+    /// points. Consider `rotation_minimizing_frames` for orienting objects
+    /// moving along the curve.
+    ///
+    /// Returns `None` if the curve is not moving, or not turning, at `t`.
+    /// The curve is considered not to be turning if the angle between its
+    /// first and second derivatives is within `approx_epsilon` of zero or a
+    /// half turn, regardless of the scale of the curve.
+    fn frenet_frame(&self, t: P::Scalar) -> Option<Basis3<P::Scalar>> where
+        P: ControlPoint<Diff = Vector3<<P as ControlPoint>::Scalar>>,
+    {
+        let d = self.derivative(t);
+        let dd = self.second_derivative(t);
+        let b = d.cross(dd);
+        // The magnitude of `b` is that of `d` and `dd` times the sine of the
+        // angle between them
+        let epsilon = P::Scalar::approx_epsilon();
+        if d.magnitude2() == P::Scalar::zero() ||
+           b.magnitude2() <= epsilon * epsilon * d.magnitude2() * dd.magnitude2() {
+            return None;
+        }
+
+        let tangent = d.normalize();
+        let binormal = b.normalize();
+        Some(frame(tangent, binormal.cross(tangent), binormal))
+    }
+
+    /// Compute rotation minimizing frames at each of the increasing parameters
+    /// in `ts`, using the double reflection method from "Computation of
+    /// Rotation Minimizing Frames" by Wang et al.
+    ///
+    /// These frames follow the tangent of the curve while twisting as little
+    /// as possible, making them well suited for orienting cameras and objects
+    /// that travel along it. As with `frenet_frame`, the frames rotate the `x`
+    /// axis onto the tangent, and the `y` axis onto the normal, which starts
+    /// out as `normal` projected to be perpendicular to the curve at `ts[0]`.
+    ///
+    /// The accuracy of the frames depends on how densely the curve is
+    /// sampled. To travel along the curve at a constant speed, the parameters
+    /// can be found using an `ArcLengthTable`.
+    ///
+    /// Where the curve stops moving, such as at a cusp, the tangent of the
+    /// previous frame is carried forward. The curve must be moving at
+    /// `ts[0]`, in a direction that is not parallel to `normal`, otherwise
+    /// the frames will be `NaN`.
+    #[cfg(feature = "std")]
+    fn rotation_minimizing_frames(&self, normal: Vector3<P::Scalar>, ts: &[P::Scalar]) -> Vec<Basis3<P::Scalar>> where
+        P: ControlPoint<Diff = Vector3<<P as ControlPoint>::Scalar>>,
+    {
+        let two: P::Scalar = cast(2).unwrap();
+        let mut frames = Vec::with_capacity(ts.len());
+        if ts.is_empty() {
+            return frames;
+        }
+
+        let mut x = self.eval(ts[0]).to_diff();
+        let mut tangent = self.derivative(ts[0]).normalize();
+        let mut normal = (normal - tangent * normal.dot(tangent)).normalize();
+        frames.push(frame(tangent, normal, tangent.cross(normal)));
+
+        for &t in &ts[1..] {
+            let next_x = self.eval(t).to_diff();
+            let d = self.derivative(t);
+
+            // Reflect the frame across the plane bisecting the two points
+            let v1 = next_x - x;
+            let c1 = v1.magnitude2();
+            let (reflected_normal, reflected_tangent) = if c1 > P::Scalar::zero() {
+                (normal - v1 * (two / c1 * v1.dot(normal)),
+                 tangent - v1 * (two / c1 * v1.dot(tangent)))
+            } else {
+                (normal, tangent)
+            };
+
+            let next_tangent = if d.magnitude2() > P::Scalar::zero() {
+                d.normalize()
+            } else {
+                reflected_tangent
+            };
+
+            // Reflect again to bring the tangents into alignment
+            let v2 = next_tangent - reflected_tangent;
+            let c2 = v2.magnitude2();
+            normal = if c2 > P::Scalar::zero() {
+                reflected_normal - v2 * (two / c2 * v2.dot(reflected_normal))
+            } else {
+                reflected_normal
+            };
+
+            x = next_x;
+            tangent = next_tangent;
+            frames.push(frame(tangent, normal, tangent.cross(normal)));
+        }
+
+        frames
+    }
+}
+
+/// The real roots of `a*x^2 + b*x + c`, or `NaN` if there are fewer than two.
+///
+/// This avoids the cancellation of the textbook formula, and degrades
+/// gracefully to the root of `b*x + c` when `a` is zero.
+fn quadratic_roots<S: BaseFloat>(a: S, b: S, c: S) -> [S; 2] {
+    let two: S = cast(2).unwrap();
+    let four: S = cast(4).unwrap();
+
+    let discriminant = b * b - four * a * c;
+    if discriminant < S::zero() {
+        return [S::nan(), S::nan()];
+    }
+
+    let q = -(b + b.signum() * discriminant.sqrt()) / two;
+    [q / a, c / q]
+}
+
+/// Estimate the length of a curve between two parameters with five point
+/// Gauss-Legendre quadrature.
+fn gauss_legendre<P, C>(curve: &C, t0: P::Scalar, t1: P::Scalar) -> P::Scalar where
+    P: ControlPoint,
+    P::Diff: InnerSpace,
+    C: Spline<P> + ?Sized,
+{
+    const NODES: [(f64, f64); 5] = [
+        (0.0, 0.5688888888888889),
+        (-0.5384693101056831, 0.4786286704993665),
+        (0.5384693101056831, 0.4786286704993665),
+        (-0.9061798459386640, 0.2369268850561891),
+        (0.9061798459386640, 0.2369268850561891),
+    ];
+
+    let two: P::Scalar = cast(2).unwrap();
+    let half_width = (t1 - t0) / two;
+    let center = (t0 + t1) / two;
+    NODES.iter().fold(P::Scalar::zero(), |sum, &(x, w)| {
+        let t = center + half_width * cast(x).unwrap();
+        sum + curve.derivative(t).magnitude() * cast(w).unwrap()
+    }) * half_width
+}
+
+fn adaptive_gauss_legendre<P, C>(curve: &C, t0: P::Scalar, t1: P::Scalar,
+                                 whole: P::Scalar, tolerance: P::Scalar, depth: u32) -> P::Scalar where
+    P: ControlPoint,
+    P::Diff: InnerSpace,
+    C: Spline<P> + ?Sized,
+{
+    let two: P::Scalar = cast(2).unwrap();
+    let mid = (t0 + t1) / two;
+    let left = gauss_legendre(curve, t0, mid);
+    let right = gauss_legendre(curve, mid, t1);
+
+    if depth == 0 || (left + right - whole).abs() <= tolerance {
+        left + right
+    } else {
+        adaptive_gauss_legendre(curve, t0, mid, left, tolerance / two, depth - 1) +
+        adaptive_gauss_legendre(curve, mid, t1, right, tolerance / two, depth - 1)
+    }
+}
+
+/// Construct the rotation from the standard axes to an orthonormal frame.
+#[inline]
+fn frame<S: BaseFloat>(x: Vector3<S>, y: Vector3<S>, z: Vector3<S>) -> Basis3<S> {
+    Basis3::from_quaternion(&Matrix3::from_cols(x, y, z).into())
+}
+
+#[inline]
+fn lerp<V: VectorSpace>(a: V, b: V, t: V::Scalar) -> V {
+    a + (b - a) * t
+}
+
+/// A quadratic [Bézier curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve),
+/// passing through `p0` and `p2` and pulled towards `p1`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct QuadraticBezier<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+}
+
+impl_eders!(<P> QuadraticBezier { p0, p1, p2 });
+impl_arbitrary!(<P> QuadraticBezier { p0, p1, p2 });
+
+impl<P: ControlPoint> QuadraticBezier<P> {
+    /// Construct a new curve from its control points.
+    #[inline]
+    pub fn new(p0: P, p1: P, p2: P) -> QuadraticBezier<P> {
+        QuadraticBezier { p0: p0, p1: p1, p2: p2 }
+    }
+
+    /// Split the curve into two at the parameter `t`, using de Casteljau's
+    /// algorithm.
+    pub fn split(&self, t: P::Scalar) -> (QuadraticBezier<P>, QuadraticBezier<P>) {
+        let (p0, p1, p2) = (self.p0.to_diff(), self.p1.to_diff(), self.p2.to_diff());
+        let (p01, p12) = (lerp(p0, p1, t), lerp(p1, p2, t));
+        let mid = P::from_diff(lerp(p01, p12, t));
+
+        (QuadraticBezier::new(self.p0, P::from_diff(p01), mid),
+         QuadraticBezier::new(mid, P::from_diff(p12), self.p2))
+    }
+
+    /// Convert the curve to an identical cubic Bézier curve.
+    pub fn to_cubic(&self) -> CubicBezier<P> {
+        let (p0, p1, p2) = (self.p0.to_diff(), self.p1.to_diff(), self.p2.to_diff());
+        let two_thirds: P::Scalar = cast(2.0 / 3.0).unwrap();
+
+        CubicBezier::new(self.p0,
+                         P::from_diff(lerp(p0, p1, two_thirds)),
+                         P::from_diff(lerp(p2, p1, two_thirds)),
+                         self.p2)
+    }
+}
+
+impl<P: ControlPoint> Spline<P> for QuadraticBezier<P> {
+    fn coefficients(&self) -> [P::Diff; 4] {
+        let (p0, p1, p2) = (self.p0.to_diff(), self.p1.to_diff(), self.p2.to_diff());
+        let two: P::Scalar = cast(2).unwrap();
+
+        [p0, (p1 - p0) * two, p0 - p1 * two + p2, P::Diff::zero()]
+    }
+}
+
+/// A cubic [Bézier curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve),
+/// passing through `p0` and `p3`, leaving `p0` towards `p1` and arriving at
+/// `p3` from the direction of `p2`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct CubicBezier<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+    pub p3: P,
+}
+
+impl_eders!(<P> CubicBezier { p0, p1, p2, p3 });
+impl_arbitrary!(<P> CubicBezier { p0, p1, p2, p3 });
+
+impl<P: ControlPoint> CubicBezier<P> {
+    /// Construct a new curve from its control points.
+    #[inline]
+    pub fn new(p0: P, p1: P, p2: P, p3: P) -> CubicBezier<P> {
+        CubicBezier { p0: p0, p1: p1, p2: p2, p3: p3 }
+    }
+
+    /// Split the curve into two at the parameter `t`, using de Casteljau's
+    /// algorithm.
+    pub fn split(&self, t: P::Scalar) -> (CubicBezier<P>, CubicBezier<P>) {
+        let (p0, p1, p2, p3) = (self.p0.to_diff(), self.p1.to_diff(),
+                                self.p2.to_diff(), self.p3.to_diff());
+        let (p01, p12, p23) = (lerp(p0, p1, t), lerp(p1, p2, t), lerp(p2, p3, t));
+        let (p012, p123) = (lerp(p01, p12, t), lerp(p12, p23, t));
+        let mid = P::from_diff(lerp(p012, p123, t));
+
+        (CubicBezier::new(self.p0, P::from_diff(p01), P::from_diff(p012), mid),
+         CubicBezier::new(mid, P::from_diff(p123), P::from_diff(p23), self.p3))
+    }
+}
+
+impl<P: ControlPoint> Spline<P> for CubicBezier<P> {
+    fn coefficients(&self) -> [P::Diff; 4] {
+        let (p0, p1, p2, p3) = (self.p0.to_diff(), self.p1.to_diff(),
+                                self.p2.to_diff(), self.p3.to_diff());
+        let three: P::Scalar = cast(3).unwrap();
+
+        [p0,
+         (p1 - p0) * three,
+         (p0 - p1 - p1 + p2) * three,
+         p3 - p0 + (p1 - p2) * three]
+    }
+}
+
+/// A cubic [Hermite curve](https://en.wikipedia.org/wiki/Cubic_Hermite_spline),
+/// travelling from `p0` to `p1` with the derivatives `m0` and `m1` at either
+/// end.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct CubicHermite<P: ControlPoint> {
+    pub p0: P,
+    pub m0: P::Diff,
+    pub p1: P,
+    pub m1: P::Diff,
+}
+
+impl_eders!(<P: ControlPoint> CubicHermite { p0, m0, p1, m1 } where P::Diff);
+impl_arbitrary!(<P: ControlPoint> CubicHermite { p0, m0, p1, m1 } where P::Diff);
+
+impl<P: ControlPoint> CubicHermite<P> {
+    /// Construct a new curve from its end points and their tangents.
+    #[inline]
+    pub fn new(p0: P, m0: P::Diff, p1: P, m1: P::Diff) -> CubicHermite<P> {
+        CubicHermite { p0: p0, m0: m0, p1: p1, m1: m1 }
+    }
+
+    /// Split the curve into two at the parameter `t`. The tangents are
+    /// rescaled so that each half is still parameterized over `[0, 1]`.
+    pub fn split(&self, t: P::Scalar) -> (CubicHermite<P>, CubicHermite<P>) {
+        let mid = self.eval(t);
+        let m = self.derivative(t);
+        let u = P::Scalar::one() - t;
+
+        (CubicHermite::new(self.p0, self.m0 * t, mid, m * t),
+         CubicHermite::new(mid, m * u, self.p1, self.m1 * u))
+    }
+
+    /// Convert the curve to an identical cubic Bézier curve.
+    pub fn to_bezier(&self) -> CubicBezier<P> {
+        let three: P::Scalar = cast(3).unwrap();
+
+        CubicBezier::new(self.p0,
+                         P::from_diff(self.p0.to_diff() + self.m0 / three),
+                         P::from_diff(self.p1.to_diff() - self.m1 / three),
+                         self.p1)
+    }
+}
+
+impl<P: ControlPoint> Spline<P> for CubicHermite<P> {
+    fn coefficients(&self) -> [P::Diff; 4] {
+        let (p0, p1) = (self.p0.to_diff(), self.p1.to_diff());
+        let (m0, m1) = (self.m0, self.m1);
+        let two: P::Scalar = cast(2).unwrap();
+        let three: P::Scalar = cast(3).unwrap();
+
+        [p0,
+         m0,
+         (p1 - p0) * three - m0 * two - m1,
+         (p0 - p1) * two + m0 + m1]
+    }
+}
+
+/// A segment of a [Catmull-Rom
+/// spline](https://en.wikipedia.org/wiki/Centripetal_Catmull%E2%80%93Rom_spline),
+/// travelling from `p1` to `p2`, with tangents determined by the neighbouring
+/// points `p0` and `p3`.
+///
+/// The knot spacing is controlled by `alpha`: `0` produces the uniform
+/// spline, `0.5` the centripetal spline, and `1` the chordal spline. Unlike
+/// the uniform spline, the centripetal spline is guaranteed not to form cusps
+/// or self-intersections within a segment.
+///
+/// A spline through a sequence of points can be drawn by creating a segment
+/// for each window of four consecutive points.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct CatmullRom<P: ControlPoint> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+    pub p3: P,
+    pub alpha: P::Scalar,
+}
+
+impl_eders!(<P: ControlPoint> CatmullRom { p0, p1, p2, p3, alpha } where P::Scalar);
+
+#[cfg(feature = "quickcheck")]
+impl<P: ControlPoint + Arbitrary> Arbitrary for CatmullRom<P> where P::Scalar: Arbitrary {
+    /// Generate a uniform, centripetal or chordal segment.
+    fn arbitrary<G: Gen>(g: &mut G) -> CatmullRom<P> {
+        CatmullRom {
+            p0: P::arbitrary(g),
+            p1: P::arbitrary(g),
+            p2: P::arbitrary(g),
+            p3: P::arbitrary(g),
+            alpha: cast(*g.choose(&[0.0, 0.5, 1.0]).unwrap()).unwrap(),
+        }
+    }
+
+    /// Shrink the control points, and the knot spacing towards the uniform
+    /// spline.
+    fn shrink(&self) -> Box<Iterator<Item = CatmullRom<P>>> {
+        let segment = *self;
+        let alphas: Vec<P::Scalar> = vec![P::Scalar::zero(), cast(0.5).unwrap()];
+        let alphas = alphas.into_iter().filter(move |&alpha| alpha < segment.alpha);
+        Box::new(shrink_fields!(self, { p0, p1, p2, p3 })
+            .chain(alphas.map(move |alpha| CatmullRom { alpha: alpha, ..segment })))
+    }
+}
+
+impl<P: ControlPoint> CatmullRom<P> where P::Diff: InnerSpace {
+    /// Construct a new segment with the given knot parameterization.
+    #[inline]
+    pub fn new(p0: P, p1: P, p2: P, p3: P, alpha: P::Scalar) -> CatmullRom<P> {
+        CatmullRom { p0: p0, p1: p1, p2: p2, p3: p3, alpha: alpha }
+    }
+
+    /// Construct a new segment of a uniform Catmull-Rom spline.
+    #[inline]
+    pub fn uniform(p0: P, p1: P, p2: P, p3: P) -> CatmullRom<P> {
+        CatmullRom::new(p0, p1, p2, p3, P::Scalar::zero())
+    }
+
+    /// Construct a new segment of a centripetal Catmull-Rom spline.
+    #[inline]
+    pub fn centripetal(p0: P, p1: P, p2: P, p3: P) -> CatmullRom<P> {
+        CatmullRom::new(p0, p1, p2, p3, cast(0.5).unwrap())
+    }
+
+    /// Convert the segment to an identical cubic Hermite curve.
+    pub fn to_hermite(&self) -> CubicHermite<P> {
+        let (p0, p1, p2, p3) = (self.p0.to_diff(), self.p1.to_diff(),
+                                self.p2.to_diff(), self.p3.to_diff());
+
+        // The knot intervals, falling back to uniform spacing for coincident
+        // points to avoid dividing by zero
+        let interval = |a: P::Diff, b: P::Diff| {
+            let d = (b - a).magnitude().powf(self.alpha);
+            if d > P::Scalar::zero() { d } else { P::Scalar::one() }
+        };
+        let (d0, d1, d2) = (interval(p0, p1), interval(p1, p2), interval(p2, p3));
+
+        let m1 = ((p1 - p0) / d0 - (p2 - p0) / (d0 + d1) + (p2 - p1) / d1) * d1;
+        let m2 = ((p2 - p1) / d1 - (p3 - p1) / (d1 + d2) + (p3 - p2) / d2) * d1;
+
+        CubicHermite::new(self.p1, m1, self.p2, m2)
+    }
+
+    /// Convert the segment to an identical cubic Bézier curve.
+    #[inline]
+    pub fn to_bezier(&self) -> CubicBezier<P> {
+        self.to_hermite().to_bezier()
+    }
+
+    /// Split the segment into two at the parameter `t`.
+    ///
+    /// The halves of a non-uniform segment cannot in general be expressed as
+    /// Catmull-Rom segments themselves, so they are returned as Bézier curves.
+    #[inline]
+    pub fn split(&self, t: P::Scalar) -> (CubicBezier<P>, CubicBezier<P>) {
+        self.to_bezier().split(t)
+    }
+}
+
+impl<P: ControlPoint> Spline<P> for CatmullRom<P> where P::Diff: InnerSpace {
+    #[inline]
+    fn coefficients(&self) -> [P::Diff; 4] {
+        self.to_hermite().coefficients()
+    }
+}
+
+/// A segment of a uniform cubic
+/// [B-spline](https://en.wikipedia.org/wiki/B-spline), influenced by the four
+/// control points `p0` to `p3`.
+///
+/// B-splines do not generally pass through their control points, but
+/// consecutive segments, created from each window of four consecutive points,
+/// join with continuous first and second derivatives.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct UniformBSpline<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+    pub p3: P,
+}
+
+impl_eders!(<P> UniformBSpline { p0, p1, p2, p3 });
+impl_arbitrary!(<P> UniformBSpline { p0, p1, p2, p3 });
+
+impl<P: ControlPoint> UniformBSpline<P> {
+    /// Construct a new segment from its control points.
+    #[inline]
+    pub fn new(p0: P, p1: P, p2: P, p3: P) -> UniformBSpline<P> {
+        UniformBSpline { p0: p0, p1: p1, p2: p2, p3: p3 }
+    }
+
+    /// Split the segment into two at the parameter `t`.
+    pub fn split(&self, t: P::Scalar) -> (UniformBSpline<P>, UniformBSpline<P>) {
+        let (a, b) = self.to_bezier().split(t);
+        (UniformBSpline::from_bezier(&a), UniformBSpline::from_bezier(&b))
+    }
+
+    /// Convert the segment to an identical cubic Bézier curve.
+    pub fn to_bezier(&self) -> CubicBezier<P> {
+        let (p0, p1, p2, p3) = (self.p0.to_diff(), self.p1.to_diff(),
+                                self.p2.to_diff(), self.p3.to_diff());
+        let two: P::Scalar = cast(2).unwrap();
+        let three: P::Scalar = cast(3).unwrap();
+        let four: P::Scalar = cast(4).unwrap();
+        let six: P::Scalar = cast(6).unwrap();
+
+        CubicBezier::new(P::from_diff((p0 + p1 * four + p2) / six),
+                         P::from_diff((p1 * two + p2) / three),
+                         P::from_diff((p1 + p2 * two) / three),
+                         P::from_diff((p1 + p2 * four + p3) / six))
+    }
+
+    /// Construct the segment that traces the same curve as a cubic Bézier
+    /// curve.
+    pub fn from_bezier(bezier: &CubicBezier<P>) -> UniformBSpline<P> {
+        let (b0, b1, b2, b3) = (bezier.p0.to_diff(), bezier.p1.to_diff(),
+                                bezier.p2.to_diff(), bezier.p3.to_diff());
+        let two: P::Scalar = cast(2).unwrap();
+        let six: P::Scalar = cast(6).unwrap();
+        let seven: P::Scalar = cast(7).unwrap();
+
+        UniformBSpline::new(P::from_diff(b0 * six - b1 * seven + b2 * two),
+                            P::from_diff(b1 * two - b2),
+                            P::from_diff(b2 * two - b1),
+                            P::from_diff(b3 * six - b2 * seven + b1 * two))
+    }
+}
+
+impl<P: ControlPoint> Spline<P> for UniformBSpline<P> {
+    fn coefficients(&self) -> [P::Diff; 4] {
+        let (p0, p1, p2, p3) = (self.p0.to_diff(), self.p1.to_diff(),
+                                self.p2.to_diff(), self.p3.to_diff());
+        let two: P::Scalar = cast(2).unwrap();
+        let three: P::Scalar = cast(3).unwrap();
+        let four: P::Scalar = cast(4).unwrap();
+        let six: P::Scalar = cast(6).unwrap();
+
+        [(p0 + p1 * four + p2) / six,
+         (p2 - p0) / two,
+         (p0 - p1 * two + p2) / two,
+         (p3 - p0 + (p1 - p2) * three) / six]
+    }
+}
+
+/// A table mapping distances along a curve to the parameters at which they
+/// are reached.
+///
+/// Splines are generally not traversed at a constant speed as their parameter
+/// increases, so this is needed to move along a curve at a constant speed, or
+/// to place objects at regular intervals along it.
+///
+/// ```rust
+/// use cgmath::prelude::*;
+/// use cgmath::{ArcLengthTable, CubicBezier, Point3};
+///
+/// let curve = CubicBezier::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0),
+///                              Point3::new(1.0, 1.0, 0.0), Point3::new(1.0, 0.0, 1.0));
+/// let table = ArcLengthTable::new(&curve, 32);
+///
+/// // Place a point every 0.1 units along the curve
+/// let n = (table.length() / 0.1) as usize;
+/// let points: Vec<_> = (0..n + 1)
+///     .map(|i| curve.eval(table.parameter(i as f64 * 0.1)))
+///     .collect();
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable))]
+pub struct ArcLengthTable<S> {
+    /// The cumulative length of the curve at evenly spaced parameters.
+    lengths: Vec<S>,
+}
+
+#[cfg(feature = "std")]
+impl_eders!(<S: BaseFloat> ArcLengthTable { lengths } => ArcLengthTable::from_lengths(lengths));
+
+#[cfg(all(feature = "rustc-serialize", feature = "std"))]
+impl<S: BaseFloat + Decodable> Decodable for ArcLengthTable<S> {
+    /// Decode a table, failing if its lengths are not valid.
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<ArcLengthTable<S>, D::Error> {
+        decoder.read_struct("ArcLengthTable", 1, |decoder| {
+            let lengths = try!(decoder.read_struct_field("lengths", 0, Decodable::decode));
+            ArcLengthTable::from_lengths(lengths).ok_or_else(|| decoder.error("invalid ArcLengthTable"))
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: BaseFloat> ArcLengthTable<S> {
+    /// Build a table for `curve`, dividing it into `segments` evenly spaced
+    /// intervals of its parameter.
+    ///
+    /// Distances are interpolated linearly within each interval, so more
+    /// segments will be needed for an accurate table if the speed of the
+    /// curve varies greatly.
+    ///
+    /// # Panics
+    ///
+    /// If `segments` is zero.
+    pub fn new<P, C>(curve: &C, segments: usize) -> ArcLengthTable<S> where
+        P: ControlPoint<Scalar = S>,
+        P::Diff: InnerSpace,
+        C: Spline<P>,
+    {
+        assert!(segments > 0, "An arc length table needs at least one segment");
+        let n: S = cast(segments).unwrap();
+
+        let mut lengths = Vec::with_capacity(segments + 1);
+        let mut total = S::zero();
+        lengths.push(total);
+        for i in 0..segments {
+            let t0 = cast::<_, S>(i).unwrap() / n;
+            let t1 = cast::<_, S>(i + 1).unwrap() / n;
+            total = total + curve.arc_length_between(t0, t1);
+            lengths.push(total);
+        }
+
+        ArcLengthTable { lengths: lengths }
+    }
+
+    /// Construct a table from its cumulative lengths, checking that there
+    /// are at least two of them, and that they are finite and non-decreasing.
+    fn from_lengths(lengths: Vec<S>) -> Option<ArcLengthTable<S>> {
+        if lengths.len() < 2 || !lengths.iter().all(|l| l.is_finite()) {
+            return None;
+        }
+        if lengths.windows(2).any(|w| !(w[0] <= w[1])) {
+            return None;
+        }
+        Some(ArcLengthTable { lengths: lengths })
+    }
+
+    /// The total length of the curve.
+    #[inline]
+    pub fn length(&self) -> S {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// The parameter at which the given distance along the curve is reached.
+    /// Distances outside of the length of the curve are clamped.
+    pub fn parameter(&self, distance: S) -> S {
+        let segments = self.lengths.len() - 1;
+        if !(distance > S::zero()) {
+            return S::zero();
+        } else if distance >= self.length() {
+            return S::one();
+        }
+
+        // Find the last entry that is no further than the distance
+        let (mut lo, mut hi) = (0, segments);
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if self.lengths[mid] <= distance { lo = mid; } else { hi = mid; }
+        }
+
+        let (l0, l1) = (self.lengths[lo], self.lengths[lo + 1]);
+        let fraction = if l1 > l0 { (distance - l0) / (l1 - l0) } else { S::zero() };
+        (cast::<_, S>(lo).unwrap() + fraction) / cast(segments).unwrap()
+    }
+
+    /// The distance along the curve at which the parameter `t` is reached.
+    /// Parameters outside of `[0, 1]` are clamped.
+    pub fn distance(&self, t: S) -> S {
+        let segments = self.lengths.len() - 1;
+        if !(t > S::zero()) {
+            return S::zero();
+        } else if t >= S::one() {
+            return self.length();
+        }
+
+        let x = t * cast(segments).unwrap();
+        let i = x.floor();
+        let index = cast::<_, usize>(i).unwrap();
+        let (l0, l1) = (self.lengths[index], self.lengths[index + 1]);
+        l0 + (l1 - l0) * (x - i)
+    }
+}
@@ -16,8 +16,9 @@
 //! Generic algebraic structures
 
 use num_traits::{cast, Float};
-use std::cmp;
-use std::ops::*;
+use core::cmp;
+use core::mem;
+use core::ops::*;
 
 use approx::ApproxEq;
 
@@ -45,6 +46,22 @@ pub trait Array where
     /// ```
     fn from_value(value: Self::Element) -> Self;
 
+    /// The number of elements in the array.
+    ///
+    /// By default this assumes that the array consists only of its elements,
+    /// stored contiguously, as `as_ptr` does.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Vector3;
+    ///
+    /// assert_eq!(Vector3::<f32>::len(), 3);
+    /// ```
+    #[inline]
+    fn len() -> usize where Self: Sized {
+        mem::size_of::<Self>() / mem::size_of::<Self::Element>()
+    }
+
     /// Get the pointer to the first element of the array.
     #[inline]
     fn as_ptr(&self) -> *const Self::Element {
@@ -60,7 +77,7 @@ pub trait Array where
     /// Swap the elements at indices `i` and `j` in-place.
     #[inline]
     fn swap_elements(&mut self, i: usize, j: usize) {
-        use std::ptr;
+        use core::ptr;
 
         // Yeah, ok borrow checker – I know what I'm doing here
         unsafe { ptr::swap(&mut self[i], &mut self[j]) };
@@ -431,7 +448,7 @@ pub trait Matrix: VectorSpace where
     /// Replace a column in the array.
     #[inline]
     fn replace_col(&mut self, c: usize, src: Self::Column) -> Self::Column {
-        use std::mem;
+        use core::mem;
 
         mem::replace(&mut self[c], src)
     }
@@ -525,6 +542,12 @@ pub trait SquareMatrix where
     /// Test if this matrix is symmetric. That is, it is equal to its
     /// transpose.
     fn is_symmetric(&self) -> bool;
+
+    /// Test if this matrix is orthonormal. That is, its columns are unit
+    /// vectors that are mutually perpendicular, so that its transpose is also
+    /// its inverse.
+    #[inline]
+    fn is_orthonormal(&self) -> bool { (self.transpose() * *self).is_identity() }
 }
 
 /// Angles and their associated trigonometric functions.
@@ -558,6 +581,44 @@ pub trait Angle where
         if rem < Self::zero() { rem + Self::full_turn() } else { rem }
     }
 
+    /// Return the angle, normalized to the signed range
+    /// `(-turn_div_2, turn_div_2]`.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Deg;
+    ///
+    /// assert_eq!(Deg::new(270.0f32).normalize_signed(), Deg::new(-90.0));
+    /// assert_eq!(Deg::new(-180.0f32).normalize_signed(), Deg::new(180.0));
+    /// ```
+    #[inline]
+    fn normalize_signed(self) -> Self {
+        let rem = Self::normalize(self);
+        if rem > Self::turn_div_2() { rem - Self::full_turn() } else { rem }
+    }
+
+    /// Return the signed angle to rotate by to get from this angle to
+    /// `other` by the shortest path, in the range `(-turn_div_2, turn_div_2]`.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Deg;
+    ///
+    /// assert_eq!(Deg::new(350.0f32).shortest_difference(Deg::new(10.0)), Deg::new(20.0));
+    /// assert_eq!(Deg::new(10.0f32).shortest_difference(Deg::new(350.0)), Deg::new(-20.0));
+    /// ```
+    #[inline]
+    fn shortest_difference(self, other: Self) -> Self {
+        Self::normalize_signed(other - self)
+    }
+
+    /// Linearly interpolate from this angle towards `other`, in whichever
+    /// direction around the circle is shortest. The result is not normalized.
+    #[inline]
+    fn lerp_shortest(self, other: Self, amount: Self::Unitless) -> Self {
+        self + Self::shortest_difference(self, other) * amount
+    }
+
     /// Return the angle rotated by half a turn.
     #[inline]
     fn opposite(self) -> Self {
@@ -0,0 +1,357 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::ops::{Add, Mul};
+
+use num_traits::cast;
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
+#[cfg(feature = "rustc-serialize")]
+use rustc_serialize::{Decodable, Decoder};
+
+use structure::*;
+
+use interpolate::Interpolate;
+use num::BaseFloat;
+use quaternion::Quaternion;
+use transform::Decomposed;
+use vector::Vector3;
+
+/// How the values of a `Track` are interpolated between keyframes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub enum Interpolation {
+    /// Hold the value of each keyframe until the next one is reached.
+    Step,
+    /// Interpolate between keyframes using `Interpolate`. Quaternions are
+    /// interpolated spherically.
+    Linear,
+    /// Interpolate between keyframes using cubic Hermite splines. Each
+    /// keyframe has an in-tangent and an out-tangent in addition to its value.
+    CubicSpline,
+}
+
+impl_eders_enum!(Interpolation { Step, Linear, CubicSpline });
+impl_arbitrary_enum!(Interpolation { Step, Linear, CubicSpline });
+
+impl Interpolation {
+    /// The number of values stored for each keyframe.
+    #[inline]
+    fn values_per_key(self) -> usize {
+        match self {
+            Interpolation::Step | Interpolation::Linear => 1,
+            Interpolation::CubicSpline => 3,
+        }
+    }
+}
+
+/// What happens when a `Track` is sampled outside of the times of its
+/// keyframes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub enum Wrap {
+    /// Hold the value of the first or last keyframe.
+    Clamp,
+    /// Repeat the animation, wrapping the time back to the first keyframe.
+    Loop,
+}
+
+impl_eders_enum!(Wrap { Clamp, Loop });
+impl_arbitrary_enum!(Wrap { Clamp, Loop });
+
+/// A sequence of keyframed values, which can be sampled at any time.
+///
+/// The keyframes follow the conventions of glTF animation samplers, so tracks
+/// can be constructed directly from their input and output accessors.
+///
+/// ```rust
+/// use cgmath::{Interpolation, Track, Vector3, Wrap};
+///
+/// let track = Track::new(vec![0.0, 1.0, 3.0],
+///                        vec![Vector3::new(0.0, 0.0, 0.0),
+///                             Vector3::new(1.0, 0.0, 0.0),
+///                             Vector3::new(1.0, 2.0, 0.0)],
+///                        Interpolation::Linear, Wrap::Clamp).unwrap();
+///
+/// assert_eq!(track.sample(0.5), Vector3::new(0.5, 0.0, 0.0));
+/// assert_eq!(track.sample(2.0), Vector3::new(1.0, 1.0, 0.0));
+/// assert_eq!(track.sample(5.0), Vector3::new(1.0, 2.0, 0.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable))]
+pub struct Track<S, T> {
+    times: Vec<S>,
+    values: Vec<T>,
+    interpolation: Interpolation,
+    /// The behaviour of the track outside of its keyframes.
+    pub wrap: Wrap,
+}
+
+impl_eders!(<S: BaseFloat, T> Track { times, values, interpolation, wrap } => {
+    Track::new(times, values, interpolation, wrap)
+});
+
+#[cfg(feature = "rustc-serialize")]
+impl<S: BaseFloat + Decodable, T: Decodable> Decodable for Track<S, T> {
+    /// Decode a track, failing if it would not be accepted by `Track::new`.
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Track<S, T>, D::Error> {
+        decoder.read_struct("Track", 4, |decoder| {
+            let times = try!(decoder.read_struct_field("times", 0, Decodable::decode));
+            let values = try!(decoder.read_struct_field("values", 1, Decodable::decode));
+            let interpolation = try!(decoder.read_struct_field("interpolation", 2, Decodable::decode));
+            let wrap = try!(decoder.read_struct_field("wrap", 3, Decodable::decode));
+            Track::new(times, values, interpolation, wrap).ok_or_else(|| decoder.error("invalid Track"))
+        })
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary, T: Arbitrary> Arbitrary for Track<S, T> {
+    /// Generate a track with up to `g.size()` keyframes, at strictly
+    /// increasing times at least one apart.
+    fn arbitrary<G: Gen>(g: &mut G) -> Track<S, T> {
+        let interpolation = Interpolation::arbitrary(g);
+        let size = g.size();
+        let keys = g.gen_range(1, size.max(1) + 1);
+
+        let mut times = Vec::with_capacity(keys);
+        let mut time = S::arbitrary(g);
+        for _ in 0..keys {
+            times.push(time);
+            time = time + S::one() + S::arbitrary(g).abs();
+        }
+        let values = (0..keys * interpolation.values_per_key()).map(|_| T::arbitrary(g)).collect();
+
+        Track {
+            times: times,
+            values: values,
+            interpolation: interpolation,
+            wrap: Wrap::arbitrary(g),
+        }
+    }
+
+    /// Shrink by removing keyframes, then by shrinking the wrapping and each
+    /// of the values in turn. The times of the remaining keyframes are kept.
+    fn shrink(&self) -> Box<Iterator<Item = Track<S, T>>> {
+        let values_per_key = self.interpolation.values_per_key();
+        let mut removed = Vec::new();
+        if self.times.len() > 1 {
+            for key in 0..self.times.len() {
+                let mut track = self.clone();
+                track.times.remove(key);
+                track.values.drain(key * values_per_key..(key + 1) * values_per_key);
+                removed.push(track);
+            }
+        }
+
+        let track = self.clone();
+        let wraps = self.wrap.shrink().map(move |wrap| Track { wrap: wrap, ..track.clone() });
+
+        let track = self.clone();
+        let values = (0..self.values.len()).flat_map(move |index| {
+            let track = track.clone();
+            let shrinks = track.values[index].shrink();
+            shrinks.map(move |value| {
+                let mut shrunk = track.clone();
+                shrunk.values[index] = value;
+                shrunk
+            })
+        });
+
+        Box::new(removed.into_iter().chain(wraps).chain(values))
+    }
+}
+
+impl<S: BaseFloat, T> Track<S, T> {
+    /// Construct a new track from the times of its keyframes, and their
+    /// values.
+    ///
+    /// For `Interpolation::CubicSpline`, there must be three values for each
+    /// keyframe: its in-tangent, its value, and its out-tangent, in that
+    /// order. The tangents are the derivatives of the value with respect to
+    /// time.
+    ///
+    /// Returns `None` if there are no keyframes, if the times are not
+    /// strictly increasing, or if the number of values does not match the
+    /// number of keyframes.
+    pub fn new(times: Vec<S>, values: Vec<T>, interpolation: Interpolation, wrap: Wrap) -> Option<Track<S, T>> {
+        if times.is_empty() || values.len() != times.len() * interpolation.values_per_key() {
+            return None;
+        }
+        if times.windows(2).any(|w| !(w[0] < w[1])) {
+            return None;
+        }
+
+        Some(Track {
+            times: times,
+            values: values,
+            interpolation: interpolation,
+            wrap: wrap,
+        })
+    }
+}
+
+impl<S: BaseFloat, T> Track<S, T> where
+    T: Copy + Add<T, Output = T> + Mul<S, Output = T> + Interpolate<S>,
+{
+    /// The times of the keyframes.
+    #[inline]
+    pub fn times(&self) -> &[S] {
+        &self.times
+    }
+
+    /// The values of the keyframes, including the tangents for cubic splines.
+    #[inline]
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The method used to interpolate between keyframes.
+    #[inline]
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// The time of the first keyframe.
+    #[inline]
+    pub fn start_time(&self) -> S {
+        self.times[0]
+    }
+
+    /// The time of the last keyframe.
+    #[inline]
+    pub fn end_time(&self) -> S {
+        self.times[self.times.len() - 1]
+    }
+
+    /// The time between the first and last keyframes.
+    #[inline]
+    pub fn duration(&self) -> S {
+        self.end_time() - self.start_time()
+    }
+
+    /// The value of the keyframe at `index`.
+    #[inline]
+    fn value(&self, index: usize) -> T {
+        match self.interpolation {
+            Interpolation::CubicSpline => self.values[index * 3 + 1],
+            _ => self.values[index],
+        }
+    }
+
+    /// Sample the track at the given time.
+    ///
+    /// Note that values produced by cubic spline interpolation of
+    /// quaternions will not generally be normalized.
+    pub fn sample(&self, time: S) -> T {
+        let last = self.times.len() - 1;
+        let time = match self.wrap {
+            Wrap::Clamp => time,
+            Wrap::Loop if last == 0 => time,
+            Wrap::Loop => {
+                let offset = (time - self.start_time()) % self.duration();
+                let offset = if offset < S::zero() { offset + self.duration() } else { offset };
+                self.start_time() + offset
+            },
+        };
+
+        if !(time > self.start_time()) {
+            return self.value(0);
+        } else if time >= self.end_time() {
+            return self.value(last);
+        }
+
+        // Find the last keyframe that is not after the time
+        let (mut lo, mut hi) = (0, last);
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if self.times[mid] <= time { lo = mid; } else { hi = mid; }
+        }
+
+        let delta = self.times[lo + 1] - self.times[lo];
+        let t = (time - self.times[lo]) / delta;
+
+        match self.interpolation {
+            Interpolation::Step => self.value(lo),
+            Interpolation::Linear => self.value(lo).interpolate(self.value(lo + 1), t),
+            Interpolation::CubicSpline => {
+                let p0 = self.values[lo * 3 + 1];
+                let m0 = self.values[lo * 3 + 2] * delta;
+                let p1 = self.values[lo * 3 + 4];
+                let m1 = self.values[lo * 3 + 3] * delta;
+
+                let two: S = cast(2).unwrap();
+                let three: S = cast(3).unwrap();
+                let t2 = t * t;
+                let t3 = t2 * t;
+
+                p0 * (two * t3 - three * t2 + S::one()) +
+                m0 * (t3 - two * t2 + t) +
+                p1 * (three * t2 - two * t3) +
+                m1 * (t3 - t2)
+            },
+        }
+    }
+}
+
+/// The tracks animating each part of a `Decomposed` transform.
+///
+/// Any of the tracks may be omitted, in which case that part of the transform
+/// is left as the identity.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable))]
+pub struct DecomposedTrack<S> {
+    pub scale: Option<Track<S, S>>,
+    pub rot: Option<Track<S, Quaternion<S>>>,
+    pub disp: Option<Track<S, Vector3<S>>>,
+}
+
+impl_eders!(<S: BaseFloat> DecomposedTrack { scale, rot, disp });
+
+#[cfg(feature = "rustc-serialize")]
+impl<S: BaseFloat + Decodable> Decodable for DecomposedTrack<S> {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<DecomposedTrack<S>, D::Error> {
+        decoder.read_struct("DecomposedTrack", 3, |decoder| {
+            Ok(DecomposedTrack {
+                scale: try!(decoder.read_struct_field("scale", 0, Decodable::decode)),
+                rot: try!(decoder.read_struct_field("rot", 1, Decodable::decode)),
+                disp: try!(decoder.read_struct_field("disp", 2, Decodable::decode)),
+            })
+        })
+    }
+}
+impl_arbitrary!(<S: BaseFloat> DecomposedTrack { scale, rot, disp });
+
+impl<S: BaseFloat + Interpolate<S>> DecomposedTrack<S> {
+    /// Sample each of the tracks at the given time, returning the combined
+    /// pose. The rotation is normalized.
+    pub fn sample(&self, time: S) -> Decomposed<Vector3<S>, Quaternion<S>> {
+        Decomposed {
+            scale: self.scale.as_ref().map_or(S::one(), |track| track.sample(time)),
+            rot: self.rot.as_ref().map_or(Quaternion::one(), |track| track.sample(time).normalize()),
+            disp: self.disp.as_ref().map_or(Vector3::zero(), |track| track.sample(time)),
+        }
+    }
+
+    /// The latest time of any of the tracks.
+    pub fn end_time(&self) -> S {
+        let times = [
+            self.scale.as_ref().map(Track::end_time),
+            self.rot.as_ref().map(Track::end_time),
+            self.disp.as_ref().map(Track::end_time),
+        ];
+        times.iter().filter_map(|&t| t).fold(S::zero(), S::max)
+    }
+}
@@ -13,9 +13,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core::fmt;
+use core::str::FromStr;
+
 use structure::*;
 
 use approx::ApproxEq;
+use format::{self, ParseError};
 use matrix::{Matrix2, Matrix3, Matrix4};
 use num::{BaseFloat, BaseNum};
 use point::{Point2, Point3};
@@ -64,6 +68,9 @@ pub struct Decomposed<V: VectorSpace, R> {
     pub disp: V,
 }
 
+impl_eders!(<V: VectorSpace, R> Decomposed { scale, rot, disp } where V::Scalar);
+impl_arbitrary!(<V: VectorSpace, R> Decomposed { scale, rot, disp } where V::Scalar);
+
 impl<P: EuclideanSpace, R: Rotation<P>> Transform<P> for Decomposed<P::Diff, R> where
     // FIXME: Ugly type signatures - blocked by rust-lang/rust#24092
     <P as EuclideanSpace>::Scalar: BaseFloat,
@@ -161,149 +168,35 @@ impl<S: VectorSpace, R, E: BaseFloat> ApproxEq for Decomposed<S, R>
     }
 }
 
-#[cfg(feature = "eders")]
-#[doc(hidden)]
-mod eders_ser {
-    use structure::VectorSpace;
-    use super::Decomposed;
-    use serde::{self, Serialize};
-
-    impl<V: VectorSpace, R> Serialize for Decomposed<V, R>
-        where V: Serialize, V::Scalar: Serialize, R: Serialize
-    {
-        fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
-            where S: serde::Serializer
-        {
-            serializer.serialize_struct("Decomposed", DecomposedVisitor {
-                value: self,
-                state: 0,
-            })
-        }
-    }
-
-    struct DecomposedVisitor<'a, V: 'a + VectorSpace, R: 'a> {
-        value: &'a Decomposed<V, R>,
-        state: u8,
-    }
-
-    impl<'a, V: 'a + VectorSpace, R> serde::ser::MapVisitor for DecomposedVisitor<'a, V, R>
-        where V: Serialize, V::Scalar: Serialize, R: Serialize
-    {
-        fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error>
-            where S: serde::Serializer
-        {
-            match self.state {
-                0 => {
-                    self.state += 1;
-                    Ok(Some(try!(serializer.serialize_struct_elt("scale", &self.value.scale))))
-                },
-                1 => {
-                    self.state += 1;
-                    Ok(Some(try!(serializer.serialize_struct_elt("rot", &self.value.rot))))
-                },
-                2 => {
-                    self.state += 1;
-                    Ok(Some(try!(serializer.serialize_struct_elt("disp", &self.value.disp))))
-                },
-                _ => {
-                    Ok(None)
-                },
-            }
-        }
+/// Formatted as a list of the scale, rotation and displacement, such as
+/// `[2, 1 + 0i + 0j + 0k, [1, 2, 3]]`.
+impl<V: VectorSpace + fmt::Display, R: fmt::Display> fmt::Display for Decomposed<V, R> where
+    V::Scalar: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(f.write_str("["));
+        try!(fmt::Display::fmt(&self.scale, f));
+        try!(f.write_str(", "));
+        try!(fmt::Display::fmt(&self.rot, f));
+        try!(f.write_str(", "));
+        try!(fmt::Display::fmt(&self.disp, f));
+        f.write_str("]")
     }
 }
 
-#[cfg(feature = "eders")]
-#[doc(hidden)]
-mod eders_de {
-    use structure::VectorSpace;
-    use super::Decomposed;
-    use serde::{self, Deserialize};
-    use std::marker::PhantomData;
-
-    enum DecomposedField {
-        Scale,
-        Rot,
-        Disp,
-    }
-
-    impl Deserialize for DecomposedField {
-        fn deserialize<D>(deserializer: &mut D) -> Result<DecomposedField, D::Error>
-            where D: serde::Deserializer
-        {
-            struct DecomposedFieldVisitor;
-
-            impl serde::de::Visitor for DecomposedFieldVisitor {
-                type Value = DecomposedField;
-
-                fn visit_str<E>(&mut self, value: &str) -> Result<DecomposedField, E>
-                    where E: serde::de::Error
-                {
-                    match value {
-                        "scale" => Ok(DecomposedField::Scale),
-                        "rot" => Ok(DecomposedField::Rot),
-                        "disp" => Ok(DecomposedField::Disp),
-                        _ => Err(serde::de::Error::custom("expected scale, rot or disp")),
-                    }
-                }
-            }
-
-            deserializer.deserialize(DecomposedFieldVisitor)
-        }
-    }
-
-    impl<S: VectorSpace, R> Deserialize for Decomposed<S, R>
-        where S: Deserialize, S::Scalar: Deserialize, R: Deserialize
-    {
-        fn deserialize<D>(deserializer: &mut D) -> Result<Decomposed<S, R>, D::Error>
-            where D: serde::de::Deserializer
-        {
-            const FIELDS: &'static [&'static str] = &["scale", "rot", "disp"];
-            deserializer.deserialize_struct("Decomposed", FIELDS, DecomposedVisitor(PhantomData))
-        }
-    }
-
-    struct DecomposedVisitor<S: VectorSpace, R>(PhantomData<(S, R)>);
-
-    impl<S: VectorSpace, R> serde::de::Visitor for DecomposedVisitor<S, R>
-        where S: Deserialize, S::Scalar: Deserialize, R: Deserialize
-    {
-        type Value = Decomposed<S, R>;
-
-        fn visit_map<V>(&mut self, mut visitor: V) -> Result<Decomposed<S, R>, V::Error>
-            where V: serde::de::MapVisitor
-        {
-            let mut scale = None;
-            let mut rot = None;
-            let mut disp = None;
-
-            loop {
-                match try!(visitor.visit_key()) {
-                    Some(DecomposedField::Scale) => { scale = Some(try!(visitor.visit_value())); },
-                    Some(DecomposedField::Rot) => { rot = Some(try!(visitor.visit_value())); },
-                    Some(DecomposedField::Disp) => { disp = Some(try!(visitor.visit_value())); },
-                    _ => { break; },
-                }
-            }
-
-            let scale = match scale {
-                Some(scale) => scale,
-                None => try!(visitor.missing_field("scale")),
-            };
-
-            let rot = match rot {
-                Some(rot) => rot,
-                None => try!(visitor.missing_field("rot")),
-            };
-
-            let disp = match disp {
-                Some(disp) => disp,
-                None => try!(visitor.missing_field("disp")),
-            };
-
-            try!(visitor.end());
-
-            Ok(Decomposed { scale: scale, rot: rot, disp: disp })
-        }
+impl<V, R> FromStr for Decomposed<V, R> where
+    V: VectorSpace + FromStr<Err = ParseError>,
+    V::Scalar: FromStr,
+    R: FromStr<Err = ParseError>,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Decomposed<V, R>, ParseError> {
+        let mut parts = try!(format::parse_list(s, 3));
+        Ok(Decomposed {
+            scale: try!(format::parse_next(&mut parts, format::parse_scalar)),
+            rot: try!(format::parse_next(&mut parts, R::from_str)),
+            disp: try!(format::parse_next(&mut parts, V::from_str)),
+        })
     }
 }
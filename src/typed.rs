@@ -0,0 +1,414 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::*;
+use core::str::FromStr;
+
+use structure::*;
+
+use approx::ApproxEq;
+use format::ParseError;
+use matrix::Matrix4;
+use num::{BaseFloat, BaseNum};
+use point::Point3;
+use transform::{Transform, Transform3};
+use vector::Vector3;
+
+/// A `Point3` tagged with the coordinate space `U` that it is expressed in.
+///
+/// The space is a marker type, usually an empty enum, and has no effect on
+/// the layout: a `TypedPoint3<S, U>` has the same representation as a
+/// `Point3<S>`. Points in different spaces can not be mixed, and can only be
+/// moved between spaces by a `TypedTransform`.
+///
+/// ```rust
+/// use cgmath::{Point3, TypedPoint3, TypedVector3};
+///
+/// enum World {}
+///
+/// let p: TypedPoint3<f32, World> = TypedPoint3::new(1.0, 2.0, 3.0);
+/// let q = p + TypedVector3::new(1.0, 0.0, 0.0);
+///
+/// assert_eq!(q - p, TypedVector3::new(1.0, 0.0, 0.0));
+/// assert_eq!(q.to_untyped(), Point3::new(2.0, 2.0, 3.0));
+/// ```
+#[repr(C)]
+pub struct TypedPoint3<S, U> {
+    point: Point3<S>,
+    space: PhantomData<U>,
+}
+
+/// A `Vector3` tagged with the coordinate space `U` that it is expressed in.
+///
+/// Like `TypedPoint3`, this has the same representation as a `Vector3<S>`.
+#[repr(C)]
+pub struct TypedVector3<S, U> {
+    vector: Vector3<S>,
+    space: PhantomData<U>,
+}
+
+/// A `Matrix4` transforming points and vectors from the coordinate space
+/// `Src` to the coordinate space `Dst`.
+///
+/// Transforms can only be applied to points in `Src`, and can only be composed
+/// when the destination of one is the source of the other. This has the same
+/// representation as a `Matrix4<S>`.
+///
+/// ```rust
+/// use cgmath::{Matrix4, TypedPoint3, TypedTransform, Vector3};
+///
+/// enum Object {}
+/// enum World {}
+/// enum View {}
+///
+/// let model: TypedTransform<f32, Object, World> =
+///     TypedTransform::from_untyped(Matrix4::from_translation(Vector3::new(0.0, 0.0, -5.0)));
+/// let view: TypedTransform<f32, World, View> =
+///     TypedTransform::from_untyped(Matrix4::from_scale(2.0));
+///
+/// // The world space of `model` matches the world space of `view`
+/// let model_view: TypedTransform<f32, Object, View> = view * model;
+///
+/// let p: TypedPoint3<f32, View> = model_view.transform_point(TypedPoint3::new(1.0, 0.0, 0.0));
+/// assert_eq!(p, TypedPoint3::new(2.0, 0.0, -10.0));
+/// ```
+#[repr(C)]
+pub struct TypedTransform<S, Src, Dst> {
+    matrix: Matrix4<S>,
+    space: PhantomData<fn(Src) -> Dst>,
+}
+
+// The marker types need not implement anything, so the standard traits are
+// implemented by hand rather than derived, to avoid requiring them of the
+// markers.
+macro_rules! impl_typed_common {
+    ($Typed:ident < $($U:ident),+ >, $field:ident : $Untyped:ident) => {
+        impl<S, $($U),+> $Typed<S, $($U),+> {
+            /// Tag an untyped value with its coordinate spaces.
+            #[inline]
+            pub fn from_untyped($field: $Untyped<S>) -> $Typed<S, $($U),+> {
+                $Typed { $field: $field, space: PhantomData }
+            }
+
+            /// Discard the coordinate spaces.
+            #[inline]
+            pub fn to_untyped(self) -> $Untyped<S> {
+                self.$field
+            }
+        }
+
+        impl<S, $($U),+> From<$Untyped<S>> for $Typed<S, $($U),+> {
+            #[inline]
+            fn from($field: $Untyped<S>) -> $Typed<S, $($U),+> {
+                $Typed::from_untyped($field)
+            }
+        }
+
+        impl<S, $($U),+> From<$Typed<S, $($U),+>> for $Untyped<S> {
+            #[inline]
+            fn from(typed: $Typed<S, $($U),+>) -> $Untyped<S> {
+                typed.$field
+            }
+        }
+
+        impl<S: Copy, $($U),+> Copy for $Typed<S, $($U),+> {}
+
+        impl<S: Copy, $($U),+> Clone for $Typed<S, $($U),+> {
+            #[inline]
+            fn clone(&self) -> $Typed<S, $($U),+> {
+                *self
+            }
+        }
+
+        impl<S: PartialEq, $($U),+> PartialEq for $Typed<S, $($U),+> {
+            #[inline]
+            fn eq(&self, other: &$Typed<S, $($U),+>) -> bool {
+                self.$field == other.$field
+            }
+        }
+
+        impl<S: fmt::Debug, $($U),+> fmt::Debug for $Typed<S, $($U),+> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                try!(write!(f, "{} ", stringify!($Typed)));
+                fmt::Debug::fmt(&self.$field, f)
+            }
+        }
+
+        impl<S: BaseFloat, $($U),+> ApproxEq for $Typed<S, $($U),+> {
+            type Epsilon = S;
+
+            #[inline]
+            fn approx_eq_eps(&self, other: &$Typed<S, $($U),+>, epsilon: &S) -> bool {
+                self.$field.approx_eq_eps(&other.$field, epsilon)
+            }
+        }
+
+        /// Formatted as the untyped value.
+        impl<S, $($U),+> fmt::Display for $Typed<S, $($U),+> where $Untyped<S>: fmt::Display {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.$field, f)
+            }
+        }
+
+        impl<S, $($U),+> FromStr for $Typed<S, $($U),+> where $Untyped<S>: FromStr<Err = ParseError> {
+            type Err = ParseError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<$Typed<S, $($U),+>, ParseError> {
+                s.parse().map($Typed::from_untyped)
+            }
+        }
+
+        /// Serialized as the untyped value, as the spaces are only known at
+        /// compile time.
+        #[cfg(feature = "eders")]
+        impl<S: ::serde::Serialize, $($U),+> ::serde::Serialize for $Typed<S, $($U),+> {
+            #[inline]
+            fn serialize<Ser>(&self, serializer: &mut Ser) -> Result<(), Ser::Error> where
+                Ser: ::serde::Serializer,
+            {
+                self.$field.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "eders")]
+        impl<S: ::serde::Deserialize, $($U),+> ::serde::Deserialize for $Typed<S, $($U),+> {
+            #[inline]
+            fn deserialize<De>(deserializer: &mut De) -> Result<$Typed<S, $($U),+>, De::Error> where
+                De: ::serde::Deserializer,
+            {
+                $Untyped::deserialize(deserializer).map($Typed::from_untyped)
+            }
+        }
+
+        /// Generated and shrunk as the untyped value.
+        #[cfg(feature = "quickcheck")]
+        impl<S: Copy, $($U: Send + 'static),+> ::quickcheck::Arbitrary for $Typed<S, $($U),+> where
+            $Untyped<S>: ::quickcheck::Arbitrary,
+        {
+            fn arbitrary<G: ::quickcheck::Gen>(g: &mut G) -> $Typed<S, $($U),+> {
+                $Typed::from_untyped(::quickcheck::Arbitrary::arbitrary(g))
+            }
+
+            fn shrink(&self) -> Box<Iterator<Item = $Typed<S, $($U),+>>> {
+                Box::new(::quickcheck::Arbitrary::shrink(&self.$field).map($Typed::from_untyped))
+            }
+        }
+    }
+}
+
+impl_typed_common!(TypedPoint3<U>, point: Point3);
+impl_typed_common!(TypedVector3<U>, vector: Vector3);
+impl_typed_common!(TypedTransform<Src, Dst>, matrix: Matrix4);
+
+impl<S: BaseNum, U> TypedPoint3<S, U> {
+    /// Construct a new point in the space `U`.
+    #[inline]
+    pub fn new(x: S, y: S, z: S) -> TypedPoint3<S, U> {
+        TypedPoint3::from_untyped(Point3::new(x, y, z))
+    }
+
+    /// The origin of the space `U`.
+    #[inline]
+    pub fn origin() -> TypedPoint3<S, U> {
+        TypedPoint3::from_untyped(Point3::origin())
+    }
+
+    /// The displacement of the point from the origin of its space.
+    #[inline]
+    pub fn to_vec(self) -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(self.point.to_vec())
+    }
+
+    /// The point at a displacement from the origin of its space.
+    #[inline]
+    pub fn from_vec(v: TypedVector3<S, U>) -> TypedPoint3<S, U> {
+        TypedPoint3::from_untyped(Point3::from_vec(v.vector))
+    }
+}
+
+impl<S: BaseFloat, U> TypedPoint3<S, U> {
+    /// The distance between two points in the same space.
+    #[inline]
+    pub fn distance(self, other: TypedPoint3<S, U>) -> S {
+        self.point.distance(other.point)
+    }
+}
+
+impl<S: BaseNum, U> TypedVector3<S, U> {
+    /// Construct a new vector in the space `U`.
+    #[inline]
+    pub fn new(x: S, y: S, z: S) -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(Vector3::new(x, y, z))
+    }
+
+    /// The zero vector.
+    #[inline]
+    pub fn zero() -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(Vector3::zero())
+    }
+
+    /// The cross product of two vectors in the same space.
+    #[inline]
+    pub fn cross(self, other: TypedVector3<S, U>) -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(self.vector.cross(other.vector))
+    }
+}
+
+impl<S: BaseFloat, U> TypedVector3<S, U> {
+    /// The dot product of two vectors in the same space.
+    #[inline]
+    pub fn dot(self, other: TypedVector3<S, U>) -> S {
+        self.vector.dot(other.vector)
+    }
+
+    /// The length of the vector.
+    #[inline]
+    pub fn magnitude(self) -> S {
+        self.vector.magnitude()
+    }
+
+    /// The vector with the same direction, and a length of `1`.
+    #[inline]
+    pub fn normalize(self) -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(self.vector.normalize())
+    }
+}
+
+impl<S: BaseNum, U> Add<TypedVector3<S, U>> for TypedPoint3<S, U> {
+    type Output = TypedPoint3<S, U>;
+
+    #[inline]
+    fn add(self, rhs: TypedVector3<S, U>) -> TypedPoint3<S, U> {
+        TypedPoint3::from_untyped(self.point + rhs.vector)
+    }
+}
+
+impl<S: BaseNum, U> Sub<TypedVector3<S, U>> for TypedPoint3<S, U> {
+    type Output = TypedPoint3<S, U>;
+
+    #[inline]
+    fn sub(self, rhs: TypedVector3<S, U>) -> TypedPoint3<S, U> {
+        TypedPoint3::from_untyped(Point3::from_vec(self.point.to_vec() - rhs.vector))
+    }
+}
+
+impl<S: BaseNum, U> Sub<TypedPoint3<S, U>> for TypedPoint3<S, U> {
+    type Output = TypedVector3<S, U>;
+
+    #[inline]
+    fn sub(self, rhs: TypedPoint3<S, U>) -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(self.point - rhs.point)
+    }
+}
+
+impl<S: BaseNum, U> Add<TypedVector3<S, U>> for TypedVector3<S, U> {
+    type Output = TypedVector3<S, U>;
+
+    #[inline]
+    fn add(self, rhs: TypedVector3<S, U>) -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(self.vector + rhs.vector)
+    }
+}
+
+impl<S: BaseNum, U> Sub<TypedVector3<S, U>> for TypedVector3<S, U> {
+    type Output = TypedVector3<S, U>;
+
+    #[inline]
+    fn sub(self, rhs: TypedVector3<S, U>) -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(self.vector - rhs.vector)
+    }
+}
+
+impl<S: BaseNum, U> Mul<S> for TypedVector3<S, U> {
+    type Output = TypedVector3<S, U>;
+
+    #[inline]
+    fn mul(self, scalar: S) -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(self.vector * scalar)
+    }
+}
+
+impl<S: BaseNum, U> Div<S> for TypedVector3<S, U> {
+    type Output = TypedVector3<S, U>;
+
+    #[inline]
+    fn div(self, scalar: S) -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(self.vector / scalar)
+    }
+}
+
+impl<S: BaseFloat, U> Neg for TypedVector3<S, U> {
+    type Output = TypedVector3<S, U>;
+
+    #[inline]
+    fn neg(self) -> TypedVector3<S, U> {
+        TypedVector3::from_untyped(-self.vector)
+    }
+}
+
+impl<S: BaseFloat, Src, Dst> TypedTransform<S, Src, Dst> {
+    /// Tag any three dimensional transform with the spaces it maps between.
+    #[inline]
+    pub fn from_transform<T: Transform3<S>>(transform: T) -> TypedTransform<S, Src, Dst> {
+        TypedTransform::from_untyped(transform.into())
+    }
+
+    /// Transform a point from `Src` into `Dst`.
+    #[inline]
+    pub fn transform_point(&self, point: TypedPoint3<S, Src>) -> TypedPoint3<S, Dst> {
+        TypedPoint3::from_untyped(self.matrix.transform_point(point.point))
+    }
+
+    /// Transform a vector from `Src` into `Dst`.
+    #[inline]
+    pub fn transform_vector(&self, vec: TypedVector3<S, Src>) -> TypedVector3<S, Dst> {
+        TypedVector3::from_untyped(self.matrix.transform_vector(vec.vector))
+    }
+
+    /// Apply this transform, followed by `next`.
+    #[inline]
+    pub fn then<Next>(self, next: TypedTransform<S, Dst, Next>) -> TypedTransform<S, Src, Next> {
+        next * self
+    }
+
+    /// The transform from `Dst` back to `Src`, if the matrix is invertible.
+    #[inline]
+    pub fn inverse(&self) -> Option<TypedTransform<S, Dst, Src>> {
+        self.matrix.inverse_transform().map(TypedTransform::from_untyped)
+    }
+}
+
+impl<S: BaseFloat, U> TypedTransform<S, U, U> {
+    /// The identity transform, mapping a space onto itself.
+    #[inline]
+    pub fn identity() -> TypedTransform<S, U, U> {
+        TypedTransform::from_untyped(Matrix4::identity())
+    }
+}
+
+/// Composes two transforms, applying `rhs` first. The destination of `rhs`
+/// must be the source of `self`.
+impl<S: BaseFloat, A, B, C> Mul<TypedTransform<S, A, B>> for TypedTransform<S, B, C> {
+    type Output = TypedTransform<S, A, C>;
+
+    #[inline]
+    fn mul(self, rhs: TypedTransform<S, A, B>) -> TypedTransform<S, A, C> {
+        TypedTransform::from_untyped(self.matrix * rhs.matrix)
+    }
+}
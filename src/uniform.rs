@@ -0,0 +1,327 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Writing values with the padding required by the `std140` and `std430`
+//! layouts of GLSL uniform and shader storage blocks.
+//!
+//! The cgmath types are packed, so they can't be copied into a uniform block
+//! as they are: a `vec3` is aligned to 16 bytes, and the columns of a `mat3`
+//! are each padded to 16 bytes. A `Block` instead lays out its members one at
+//! a time, following the rules in section 7.6.2.2 of the OpenGL 4.5
+//! specification.
+//!
+//! Matrices are written in column-major order, which is the default in GLSL.
+//!
+//! ```rust
+//! use cgmath::{Matrix4, Vector3};
+//! use cgmath::uniform::Block;
+//!
+//! // layout(std140) uniform Light {
+//! //     mat4 transform;
+//! //     vec3 color;
+//! //     float intensity;
+//! //     vec3 position;
+//! // };
+//! let mut block = Block::std140();
+//! assert_eq!(block.push(&Matrix4::<f32>::from_scale(1.0)), 0);
+//! assert_eq!(block.push(&Vector3::new(1.0f32, 1.0, 1.0)), 64);
+//! assert_eq!(block.push(&0.5f32), 76);
+//! assert_eq!(block.push(&Vector3::new(0.0f32, 10.0, 0.0)), 80);
+//! assert_eq!(block.as_bytes().len(), 92);
+//! ```
+
+use angle::{Deg, Grad, Rad, Turns};
+use matrix::{Matrix2, Matrix3, Matrix4};
+use point::{Point1, Point2, Point3};
+use pod::Pod;
+use vector::{Vector1, Vector2, Vector3, Vector4};
+
+/// The rules used to lay out the members of a block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// The layout of uniform blocks, in which arrays, matrix columns and
+    /// structures are aligned to 16 bytes.
+    Std140,
+    /// The layout of shader storage blocks, which is like `Std140` without
+    /// rounding up the alignment of arrays, matrix columns and structures.
+    Std430,
+}
+
+impl Layout {
+    /// The alignment of an array or structure whose elements or members have
+    /// the given alignment.
+    #[inline]
+    pub fn aggregate_alignment(self, alignment: usize) -> usize {
+        match self {
+            Layout::Std140 => round_up(alignment, 16),
+            Layout::Std430 => alignment,
+        }
+    }
+
+    /// The distance in bytes between the elements of an array of `T`.
+    #[inline]
+    pub fn array_stride<T: Uniform>(self) -> usize {
+        round_up(T::size(self), self.aggregate_alignment(T::alignment(self)))
+    }
+}
+
+/// A type that can be written to a uniform or shader storage block.
+pub trait Uniform {
+    /// The base alignment of the type, in bytes.
+    fn alignment(layout: Layout) -> usize;
+
+    /// The size of the type in bytes, including any padding between the
+    /// columns of a matrix, but not any padding following it.
+    fn size(layout: Layout) -> usize;
+
+    /// Write the value to `out`, which is `size(layout)` bytes long and filled
+    /// with zeros.
+    fn write(&self, layout: Layout, out: &mut [u8]);
+}
+
+mod private {
+    /// Prevents `Scalar` from being implemented outside of this crate.
+    pub trait Sealed {}
+}
+
+/// The scalar types that can be the components of a vector or matrix in a
+/// block.
+pub trait Scalar: Uniform + Pod + private::Sealed {}
+
+macro_rules! impl_scalar {
+    ($($T:ty),+) => {
+        $(
+            impl Uniform for $T {
+                #[inline]
+                fn alignment(_: Layout) -> usize { ::core::mem::size_of::<$T>() }
+
+                #[inline]
+                fn size(_: Layout) -> usize { ::core::mem::size_of::<$T>() }
+
+                #[inline]
+                fn write(&self, _: Layout, out: &mut [u8]) {
+                    out.copy_from_slice(self.as_bytes());
+                }
+            }
+
+            impl private::Sealed for $T {}
+            impl Scalar for $T {}
+        )+
+    }
+}
+
+impl_scalar!(f32, f64, i32, u32);
+
+/// Written as a `uint` of `0` or `1`, as a GLSL `bool` is four bytes.
+impl Uniform for bool {
+    #[inline]
+    fn alignment(_: Layout) -> usize { 4 }
+
+    #[inline]
+    fn size(_: Layout) -> usize { 4 }
+
+    #[inline]
+    fn write(&self, layout: Layout, out: &mut [u8]) {
+        (*self as u32).write(layout, out);
+    }
+}
+
+macro_rules! impl_uniform_vector {
+    ($($VectorN:ident, $alignment:expr, $n:expr);+) => {
+        $(
+            impl<S: Scalar> Uniform for $VectorN<S> {
+                #[inline]
+                fn alignment(layout: Layout) -> usize { $alignment * S::size(layout) }
+
+                #[inline]
+                fn size(layout: Layout) -> usize { $n * S::size(layout) }
+
+                #[inline]
+                fn write(&self, _: Layout, out: &mut [u8]) {
+                    out.copy_from_slice(self.as_bytes());
+                }
+            }
+        )+
+    }
+}
+
+// A three component vector is aligned as if it had four components.
+impl_uniform_vector!(Vector1, 1, 1; Vector2, 2, 2; Vector3, 4, 3; Vector4, 4, 4);
+impl_uniform_vector!(Point1, 1, 1; Point2, 2, 2; Point3, 4, 3);
+
+macro_rules! impl_uniform_matrix {
+    ($MatrixN:ident, $VectorN:ident, $n:expr) => {
+        /// Laid out as an array of its columns.
+        impl<S: Scalar> Uniform for $MatrixN<S> {
+            #[inline]
+            fn alignment(layout: Layout) -> usize {
+                layout.aggregate_alignment($VectorN::<S>::alignment(layout))
+            }
+
+            #[inline]
+            fn size(layout: Layout) -> usize {
+                $n * layout.array_stride::<$VectorN<S>>()
+            }
+
+            fn write(&self, layout: Layout, out: &mut [u8]) {
+                let stride = layout.array_stride::<$VectorN<S>>();
+                let size = $VectorN::<S>::size(layout);
+                for i in 0..$n {
+                    self[i].write(layout, &mut out[i * stride..i * stride + size]);
+                }
+            }
+        }
+    }
+}
+
+impl_uniform_matrix!(Matrix2, Vector2, 2);
+impl_uniform_matrix!(Matrix3, Vector3, 3);
+impl_uniform_matrix!(Matrix4, Vector4, 4);
+
+macro_rules! impl_uniform_angle {
+    ($($Angle:ident),+) => {
+        $(
+            /// Written as its scalar value.
+            impl<S: Scalar> Uniform for $Angle<S> {
+                #[inline]
+                fn alignment(layout: Layout) -> usize { S::alignment(layout) }
+
+                #[inline]
+                fn size(layout: Layout) -> usize { S::size(layout) }
+
+                #[inline]
+                fn write(&self, layout: Layout, out: &mut [u8]) {
+                    let s = self.s;
+                    s.write(layout, out);
+                }
+            }
+        )+
+    }
+}
+
+impl_uniform_angle!(Rad, Deg, Turns, Grad);
+
+/// The contents of a uniform block, shader storage block or structure, built
+/// up by appending its members in the order they are declared.
+///
+/// Each method returns the offset in bytes at which the member was written,
+/// which matches the offset reported by `glGetActiveUniformsiv` for the
+/// corresponding member in GLSL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Block {
+    layout: Layout,
+    bytes: Vec<u8>,
+    alignment: usize,
+}
+
+impl Block {
+    /// Create an empty block with the given layout.
+    #[inline]
+    pub fn new(layout: Layout) -> Block {
+        Block { layout: layout, bytes: Vec::new(), alignment: 1 }
+    }
+
+    /// Create an empty block with the `std140` layout.
+    #[inline]
+    pub fn std140() -> Block {
+        Block::new(Layout::Std140)
+    }
+
+    /// Create an empty block with the `std430` layout.
+    #[inline]
+    pub fn std430() -> Block {
+        Block::new(Layout::Std430)
+    }
+
+    /// The layout of the block.
+    #[inline]
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// The base alignment of the block when it is used as a structure, which
+    /// is the largest alignment of its members.
+    #[inline]
+    pub fn alignment(&self) -> usize {
+        self.layout.aggregate_alignment(self.alignment)
+    }
+
+    /// The bytes written so far, without any padding following the last
+    /// member.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consume the block, returning its bytes.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Pad the block to the given alignment, and reserve `size` more bytes,
+    /// returning the offset of the reserved bytes.
+    fn reserve(&mut self, alignment: usize, size: usize) -> usize {
+        let offset = round_up(self.bytes.len(), alignment);
+        self.bytes.resize(offset + size, 0);
+        if alignment > self.alignment {
+            self.alignment = alignment;
+        }
+        offset
+    }
+
+    /// Append a value.
+    pub fn push<T: Uniform>(&mut self, value: &T) -> usize {
+        let size = T::size(self.layout);
+        let offset = self.reserve(T::alignment(self.layout), size);
+        value.write(self.layout, &mut self.bytes[offset..offset + size]);
+        offset
+    }
+
+    /// Append an array of values, padding each element to the array stride.
+    pub fn push_array<T: Uniform>(&mut self, values: &[T]) -> usize {
+        let layout = self.layout;
+        let stride = layout.array_stride::<T>();
+        let size = T::size(layout);
+        let offset = self.reserve(layout.aggregate_alignment(T::alignment(layout)), stride * values.len());
+        for (i, value) in values.iter().enumerate() {
+            let start = offset + i * stride;
+            value.write(layout, &mut self.bytes[start..start + size]);
+        }
+        offset
+    }
+
+    /// Append a structure, whose members have been appended to `member`.
+    ///
+    /// The structure is padded to a multiple of its alignment, so an array of
+    /// structures can be appended by appending each of its elements in turn.
+    ///
+    /// # Panics
+    ///
+    /// If the layout of `member` is not the same as the layout of this block.
+    pub fn push_struct(&mut self, member: &Block) -> usize {
+        assert_eq!(self.layout, member.layout);
+        let alignment = member.alignment();
+        let offset = self.reserve(alignment, round_up(member.bytes.len(), alignment));
+        self.bytes[offset..offset + member.bytes.len()].copy_from_slice(&member.bytes);
+        offset
+    }
+}
+
+/// Round `n` up to a multiple of `alignment`.
+#[inline]
+fn round_up(n: usize, alignment: usize) -> usize {
+    (n + alignment - 1) / alignment * alignment
+}
@@ -0,0 +1,290 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::ops::*;
+use core::str::FromStr;
+
+#[cfg(feature = "rand")]
+use rand::{Rand, Rng};
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
+
+use num_traits::cast;
+
+use structure::*;
+
+use angle::Rad;
+use approx::ApproxEq;
+use format::{self, ParseError};
+use matrix::{Matrix2, Matrix3};
+use num::BaseFloat;
+use point::Point2;
+use rotation::{Basis2, Rotation, Rotation2};
+#[cfg(feature = "rand")]
+use sample;
+use vector::Vector2;
+
+/// A two-dimensional rotation, stored as a complex number of unit length.
+///
+/// The real part is the cosine of the angle of rotation, and the imaginary
+/// part is its sine. This takes half the memory of a `Basis2`, rotations can
+/// be composed with a single complex multiplication, and accumulated rounding
+/// errors can be removed by normalizing.
+///
+/// ```rust
+/// use cgmath::prelude::*;
+/// use cgmath::{ApproxEq, Rad, UnitComplex, Vector2};
+/// use std::f64;
+///
+/// let rot = UnitComplex::from_angle(Rad::new(f64::consts::FRAC_PI_4));
+/// let rot = rot * rot;
+///
+/// assert!(rot.rotate_vector(Vector2::unit_x()).approx_eq(&Vector2::unit_y()));
+/// assert!(rot.angle().approx_eq(&Rad::new(f64::consts::FRAC_PI_2)));
+/// ```
+///
+/// This type is marked as `#[repr(C, packed)]`.
+#[repr(C, packed)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub struct UnitComplex<S> {
+    /// The real part, or the cosine of the angle.
+    pub re: S,
+    /// The imaginary part, or the sine of the angle.
+    pub im: S,
+}
+
+impl_eders!(<S> UnitComplex { re, im });
+
+impl<S: BaseFloat> UnitComplex<S> {
+    /// Construct a new rotation from its real and imaginary parts. These
+    /// should describe a complex number of unit length.
+    #[inline]
+    pub fn new(re: S, im: S) -> UnitComplex<S> {
+        UnitComplex { re: re, im: im }
+    }
+
+    /// The angle of the rotation, in the range `(-π, π]`.
+    #[inline]
+    pub fn angle(&self) -> Rad<S> {
+        Rad::atan2(self.im, self.re)
+    }
+
+    /// The complex conjugate, which rotates by the same angle in the opposite
+    /// direction.
+    #[inline]
+    pub fn conjugate(&self) -> UnitComplex<S> {
+        UnitComplex::new(self.re, -self.im)
+    }
+
+    /// The length of the complex number, which should be `1` for a valid
+    /// rotation.
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.re.hypot(self.im)
+    }
+
+    /// Rescale the complex number to unit length, correcting any rounding
+    /// errors accumulated by composing many rotations.
+    #[inline]
+    pub fn normalize(&self) -> UnitComplex<S> {
+        let magnitude = self.magnitude();
+        UnitComplex::new(self.re / magnitude, self.im / magnitude)
+    }
+
+    /// Spherical linear interpolation, rotating at a constant angular speed
+    /// along the shortest arc between the two rotations.
+    ///
+    /// Returns `self` when `amount` is `0`, and `other` when `amount` is `1`.
+    #[inline]
+    pub fn slerp(self, other: UnitComplex<S>, amount: S) -> UnitComplex<S> {
+        let delta = self.conjugate() * other;
+        self * UnitComplex::from_angle(delta.angle() * amount)
+    }
+
+    /// Normalized linear interpolation. This is cheaper than `slerp`, but
+    /// does not rotate at a constant speed, and is undefined when the two
+    /// rotations are opposite each other.
+    #[inline]
+    pub fn nlerp(self, other: UnitComplex<S>, amount: S) -> UnitComplex<S> {
+        UnitComplex::new(self.re + (other.re - self.re) * amount,
+                         self.im + (other.im - self.im) * amount).normalize()
+    }
+}
+
+impl<S: BaseFloat> One for UnitComplex<S> {
+    #[inline]
+    fn one() -> UnitComplex<S> {
+        UnitComplex::new(S::one(), S::zero())
+    }
+}
+
+impl_operator!(<S: BaseFloat> Mul<UnitComplex<S> > for UnitComplex<S> {
+    fn mul(lhs, rhs) -> UnitComplex<S> {
+        UnitComplex::new(lhs.re * rhs.re - lhs.im * rhs.im,
+                         lhs.re * rhs.im + lhs.im * rhs.re)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<Vector2<S> > for UnitComplex<S> {
+    fn mul(lhs, rhs) -> Vector2<S> {
+        Vector2::new(lhs.re * rhs.x - lhs.im * rhs.y,
+                     lhs.im * rhs.x + lhs.re * rhs.y)
+    }
+});
+
+impl_assignment_operator!(<S: BaseFloat> MulAssign<UnitComplex<S> > for UnitComplex<S> {
+    fn mul_assign(&mut self, other) { *self = *self * other; }
+});
+
+impl<S: BaseFloat> ApproxEq for UnitComplex<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &UnitComplex<S>, epsilon: &S) -> bool {
+        self.re.approx_eq_eps(&other.re, epsilon) &&
+        self.im.approx_eq_eps(&other.im, epsilon)
+    }
+}
+
+/// Formatted as a complex number, such as `0.6 + 0.8i`.
+impl<S: BaseFloat + fmt::Display> fmt::Display for UnitComplex<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format::fmt_sum(f, &[(self.re, ""), (self.im, "i")])
+    }
+}
+
+/// Parses a complex number in the same format as `Display`. The terms may be
+/// given in either order, and one that is left out is zero. The result is not
+/// normalized.
+impl<S: BaseFloat + FromStr> FromStr for UnitComplex<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<UnitComplex<S>, ParseError> {
+        let mut parts = [S::zero(); 2];
+        try!(format::parse_sum(s, &["", "i"], &mut parts));
+        Ok(UnitComplex::new(parts[0], parts[1]))
+    }
+}
+
+impl<S: BaseFloat> Rotation<Point2<S>> for UnitComplex<S> {
+    #[inline]
+    fn look_at(dir: Vector2<S>, up: Vector2<S>) -> UnitComplex<S> {
+        Matrix2::look_at(dir, up).into()
+    }
+
+    #[inline]
+    fn between_vectors(a: Vector2<S>, b: Vector2<S>) -> UnitComplex<S> {
+        UnitComplex::new(a.dot(b), a.perp_dot(b)).normalize()
+    }
+
+    #[inline]
+    fn rotate_vector(&self, vec: Vector2<S>) -> Vector2<S> {
+        self * vec
+    }
+
+    #[inline]
+    fn invert(&self) -> UnitComplex<S> {
+        self.conjugate()
+    }
+}
+
+impl<S: BaseFloat> Rotation2<S> for UnitComplex<S> {
+    #[inline]
+    fn from_angle(theta: Rad<S>) -> UnitComplex<S> {
+        let (s, c) = Rad::sin_cos(theta);
+        UnitComplex::new(c, s)
+    }
+}
+
+impl<S: BaseFloat> From<Rad<S>> for UnitComplex<S> {
+    #[inline]
+    fn from(theta: Rad<S>) -> UnitComplex<S> {
+        UnitComplex::from_angle(theta)
+    }
+}
+
+impl<S: BaseFloat> From<UnitComplex<S>> for Rad<S> {
+    #[inline]
+    fn from(c: UnitComplex<S>) -> Rad<S> {
+        c.angle()
+    }
+}
+
+/// Finds the closest rotation to the matrix. The matrix should be a rotation
+/// matrix, but small errors are corrected by normalizing the result.
+impl<S: BaseFloat> From<Matrix2<S>> for UnitComplex<S> {
+    fn from(m: Matrix2<S>) -> UnitComplex<S> {
+        let two: S = cast(2).unwrap();
+        UnitComplex::new((m.x.x + m.y.y) / two, (m.x.y - m.y.x) / two).normalize()
+    }
+}
+
+impl<S: BaseFloat> From<UnitComplex<S>> for Matrix2<S> {
+    #[inline]
+    fn from(c: UnitComplex<S>) -> Matrix2<S> {
+        Matrix2::new(c.re, c.im,
+                     -c.im, c.re)
+    }
+}
+
+/// Uses the upper-left 2x2 part of the matrix, so the matrix should be a
+/// rotation in homogeneous coordinates.
+impl<S: BaseFloat> From<Matrix3<S>> for UnitComplex<S> {
+    #[inline]
+    fn from(m: Matrix3<S>) -> UnitComplex<S> {
+        Matrix2::new(m.x.x, m.x.y,
+                     m.y.x, m.y.y).into()
+    }
+}
+
+/// Produces a rotation in homogeneous coordinates.
+impl<S: BaseFloat> From<UnitComplex<S>> for Matrix3<S> {
+    #[inline]
+    fn from(c: UnitComplex<S>) -> Matrix3<S> {
+        Matrix2::from(c).into()
+    }
+}
+
+impl<S: BaseFloat> From<Basis2<S>> for UnitComplex<S> {
+    #[inline]
+    fn from(b: Basis2<S>) -> UnitComplex<S> {
+        Matrix2::from(b).into()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<S: BaseFloat + Rand> Rand for UnitComplex<S> {
+    /// Generate a rotation, uniformly distributed over the circle.
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> UnitComplex<S> {
+        let v: Vector2<S> = sample::on_unit_circle(rng);
+        UnitComplex::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<S: BaseFloat + Arbitrary> Arbitrary for UnitComplex<S> {
+    /// Generate a rotation by an arbitrary angle.
+    fn arbitrary<G: Gen>(g: &mut G) -> UnitComplex<S> {
+        Rad::arbitrary(g).into()
+    }
+
+    /// Shrink the angle of the rotation towards zero.
+    fn shrink(&self) -> Box<Iterator<Item = UnitComplex<S>>> {
+        Box::new(self.angle().shrink().map(UnitComplex::from))
+    }
+}
@@ -13,17 +13,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "rand")]
 use rand::{Rand, Rng};
 use num_traits::NumCast;
-use std::fmt;
-use std::mem;
-use std::ops::*;
+use core::fmt;
+use core::mem;
+use core::ops::*;
+use core::str::FromStr;
 
 use structure::*;
 
 use angle::Rad;
 use approx::ApproxEq;
+use format::{self, ParseError};
 use num::{BaseNum, BaseFloat, PartialOrd};
+use rotor::Bivector3;
 
 /// A 1-dimensional vector.
 ///
@@ -31,7 +35,6 @@ use num::{BaseNum, BaseFloat, PartialOrd};
 #[repr(C, packed)]
 #[derive(PartialEq, Eq, Copy, Clone, Hash)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Vector1<S> {
     /// The x component of the vector.
     pub x: S,
@@ -43,7 +46,6 @@ pub struct Vector1<S> {
 #[repr(C, packed)]
 #[derive(PartialEq, Eq, Copy, Clone, Hash)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Vector2<S> {
     /// The x component of the vector.
     pub x: S,
@@ -57,7 +59,6 @@ pub struct Vector2<S> {
 #[repr(C, packed)]
 #[derive(PartialEq, Eq, Copy, Clone, Hash)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Vector3<S> {
     /// The x component of the vector.
     pub x: S,
@@ -73,7 +74,6 @@ pub struct Vector3<S> {
 #[repr(C, packed)]
 #[derive(PartialEq, Eq, Copy, Clone, Hash)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
-#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
 pub struct Vector4<S> {
     /// The x component of the vector.
     pub x: S,
@@ -127,6 +127,11 @@ macro_rules! impl_vector {
                 $VectorN { $($field: scalar),+ }
             }
 
+            #[inline]
+            fn len() -> usize {
+                $n
+            }
+
             #[inline]
             fn sum(self) -> S where S: Add<Output = S> {
                 fold_array!(add, { $(self.$field),+ })
@@ -180,6 +185,7 @@ macro_rules! impl_vector {
             }
         }
 
+        #[cfg(feature = "rand")]
         impl<S: BaseFloat + Rand> Rand for $VectorN<S> {
             #[inline]
             fn rand<R: Rng>(rng: &mut R) -> $VectorN<S> {
@@ -300,6 +306,16 @@ impl_tuple_conversions!(Vector2<S> { x, y }, (S, S));
 impl_tuple_conversions!(Vector3<S> { x, y, z }, (S, S, S));
 impl_tuple_conversions!(Vector4<S> { x, y, z, w }, (S, S, S, S));
 
+impl_eders!(<S> Vector1 { x });
+impl_eders!(<S> Vector2 { x, y });
+impl_eders!(<S> Vector3 { x, y, z });
+impl_eders!(<S> Vector4 { x, y, z, w });
+
+impl_arbitrary!(<S> Vector1 { x });
+impl_arbitrary!(<S> Vector2 { x, y });
+impl_arbitrary!(<S> Vector3 { x, y, z });
+impl_arbitrary!(<S> Vector4 { x, y, z, w });
+
 impl<S: BaseNum> Vector1<S> {
     /// A unit vector in the `x` direction.
     #[inline]
@@ -363,6 +379,16 @@ impl<S: BaseNum> Vector3<S> {
                      (self.x * other.y) - (self.y * other.x))
     }
 
+    /// Returns the wedge product of the vector and `other`, the bivector
+    /// representing the oriented plane spanned by the two vectors.
+    #[inline]
+    #[must_use]
+    pub fn wedge(self, other: Vector3<S>) -> Bivector3<S> {
+        Bivector3::new((self.x * other.y) - (self.y * other.x),
+                       (self.y * other.z) - (self.z * other.y),
+                       (self.z * other.x) - (self.x * other.z))
+    }
+
     /// Create a `Vector4`, using the `x`, `y` and `z` values from this vector, and the
     /// provided `w`.
     #[inline]
@@ -377,6 +403,93 @@ impl<S: BaseNum> Vector3<S> {
     }
 }
 
+impl<S: BaseFloat> Vector2<S> {
+    /// Returns the signed angle to rotate this vector by to point in the
+    /// direction of `other`, in the range `(-turn_div_2, turn_div_2]`.
+    /// Positive angles are anticlockwise.
+    ///
+    /// ```rust
+    /// use cgmath::{Deg, Vector2};
+    ///
+    /// let a = Vector2::new(1.0f32, 0.0);
+    /// let angle: Deg<f32> = a.signed_angle(Vector2::new(0.0, -2.0));
+    /// assert_eq!(angle, Deg::new(-90.0));
+    /// ```
+    #[inline]
+    pub fn signed_angle<A: Angle<Unitless = S>>(self, other: Vector2<S>) -> A {
+        A::atan2(self.perp_dot(other), self.dot(other))
+    }
+}
+
+impl<S: BaseFloat> Vector3<S> {
+    /// Returns the signed angle to rotate this vector by around `axis` to
+    /// point in the direction of `other`, when both are projected onto the
+    /// plane perpendicular to the axis. The angle is in the range
+    /// `(-turn_div_2, turn_div_2]`, and positive angles are anticlockwise
+    /// when looking down the axis towards the origin.
+    ///
+    /// The axis **must be normalized**.
+    ///
+    /// ```rust
+    /// use cgmath::{Rad, Vector3};
+    /// use std::f32;
+    ///
+    /// let a = Vector3::new(1.0f32, 0.0, 5.0);
+    /// let b = Vector3::new(0.0, 1.0, -3.0);
+    /// let angle: Rad<f32> = a.signed_angle_around(b, Vector3::unit_z());
+    /// assert_eq!(angle, Rad::new(f32::consts::FRAC_PI_2));
+    /// ```
+    #[inline]
+    pub fn signed_angle_around<A: Angle<Unitless = S>>(self, other: Vector3<S>, axis: Vector3<S>) -> A {
+        // Projecting onto the plane doesn't change the triple product
+        let cos = self.dot(other) - self.dot(axis) * other.dot(axis);
+        A::atan2(self.cross(other).dot(axis), cos)
+    }
+
+    /// Returns a vector that is perpendicular to this one.
+    ///
+    /// The result is not normalized, and is only guaranteed to be non-zero if
+    /// this vector is non-zero.
+    #[inline]
+    pub fn any_perpendicular(self) -> Vector3<S> {
+        if self.x.abs() > self.z.abs() {
+            Vector3::new(-self.y, self.x, S::zero())
+        } else {
+            Vector3::new(S::zero(), -self.z, self.y)
+        }
+    }
+
+    /// Returns two unit vectors that, together with this vector, form a
+    /// right-handed orthonormal basis. That is, for a returned pair `(b1, b2)`,
+    /// `b1.cross(b2) == self`.
+    ///
+    /// This vector **must be normalized**.
+    ///
+    /// This uses the branchless construction from [Building an Orthonormal
+    /// Basis, Revisited](http://jcgt.org/published/0006/01/01/) (Duff et al.
+    /// 2017), which is continuous everywhere except where `z` changes sign.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::{ApproxEq, Vector3};
+    ///
+    /// let n = Vector3::new(1.0, 2.0, 3.0).normalize();
+    /// let (b1, b2) = n.orthonormal_basis();
+    ///
+    /// assert!(b1.is_perpendicular(n));
+    /// assert!(b2.is_perpendicular(n));
+    /// assert!(b1.cross(b2).approx_eq(&n));
+    /// ```
+    pub fn orthonormal_basis(self) -> (Vector3<S>, Vector3<S>) {
+        let sign = self.z.signum();
+        let a = -(sign + self.z).recip();
+        let b = self.x * self.y * a;
+
+        (Vector3::new(S::one() + sign * self.x * self.x * a, sign * b, -sign * self.x),
+         Vector3::new(b, sign + self.y * self.y * a, -self.y))
+    }
+}
+
 impl<S: BaseNum> Vector4<S> {
     /// A unit vector in the `x` direction.
     #[inline]
@@ -495,6 +608,11 @@ impl<S: fmt::Debug> fmt::Debug for Vector4<S> {
     }
 }
 
+impl_fmt_list!(Vector1 { x }, 1);
+impl_fmt_list!(Vector2 { x, y }, 2);
+impl_fmt_list!(Vector3 { x, y, z }, 3);
+impl_fmt_list!(Vector4 { x, y, z, w }, 4);
+
 #[cfg(test)]
 mod tests {
     mod vector2 {
@@ -15,7 +15,10 @@
 
 extern crate cgmath;
 
-use cgmath::{Rad, Deg, rad, deg};
+use std::f64;
+
+use cgmath::{Rad, Deg, Turns, Grad, Euler, rad, deg, turns, grad};
+use cgmath::Angle;
 use cgmath::ApproxEq;
 
 #[test]
@@ -36,3 +39,91 @@ fn conv() {
     let angle: Rad<_> = angle.into();
     assert!(angle.approx_eq(&rad(30.0f64)));
 }
+
+#[test]
+fn conv_turns_grad() {
+    let quarter_turns = [Rad::from(rad(f64::consts::FRAC_PI_2)), Rad::from(deg(90.0f64)),
+                         Rad::from(turns(0.25f64)), Rad::from(grad(100.0f64))];
+    for angle in quarter_turns.iter() {
+        assert!(angle.approx_eq(&rad(f64::consts::FRAC_PI_2)));
+    }
+
+    assert!(Turns::from(deg(-90.0f64)).approx_eq(&turns(-0.25f64)));
+    assert!(Grad::from(deg(-90.0f64)).approx_eq(&grad(-100.0f64)));
+    assert!(Turns::from(grad(300.0f64)).approx_eq(&turns(0.75f64)));
+    assert!(Grad::from(turns(0.75f64)).approx_eq(&grad(300.0f64)));
+    assert!(Deg::from(turns(1.5f64)).approx_eq(&deg(540.0f64)));
+    assert!(Deg::from(grad(50.0f64)).approx_eq(&deg(45.0f64)));
+    assert!(Turns::from(rad(f64::consts::PI)).approx_eq(&turns(0.5f64)));
+    assert!(Grad::from(rad(f64::consts::PI)).approx_eq(&grad(200.0f64)));
+}
+
+#[test]
+fn turns_grad_angle() {
+    assert_eq!(Turns::<f64>::full_turn(), turns(1.0));
+    assert_eq!(Grad::<f64>::full_turn(), grad(400.0));
+    assert!(turns(0.125f64).sin().approx_eq(&(0.5f64).sqrt()));
+    assert!(grad(200.0f64).cos().approx_eq(&-1.0));
+    assert!(Turns::atan2(1.0f64, 0.0).approx_eq(&turns(0.25)));
+    assert!(Grad::acos(0.0f64).approx_eq(&grad(100.0)));
+    assert!(turns(1.75f64).normalize().approx_eq(&turns(0.75)));
+    assert!(grad(-100.0f64).normalize().approx_eq(&grad(300.0)));
+    assert_eq!(format!("{:?}", turns(0.5f64)), "0.5 turns");
+    assert_eq!(format!("{:?}", grad(50.0f64)), "50.0 grad");
+}
+
+#[test]
+fn signed() {
+    assert!(deg(190.0f64).normalize_signed().approx_eq(&deg(-170.0)));
+    assert!(deg(-190.0f64).normalize_signed().approx_eq(&deg(170.0)));
+    assert!(deg(180.0f64).normalize_signed().approx_eq(&deg(180.0)));
+    assert!(deg(-180.0f64).normalize_signed().approx_eq(&deg(180.0)));
+    assert!(deg(720.0f64).normalize_signed().approx_eq(&deg(0.0)));
+    assert!(rad(-7.0f64).normalize_signed().approx_eq(&rad(2.0 * f64::consts::PI - 7.0)));
+
+    assert!(deg(170.0f64).shortest_difference(deg(-170.0)).approx_eq(&deg(20.0)));
+    assert!(deg(-170.0f64).shortest_difference(deg(170.0)).approx_eq(&deg(-20.0)));
+    assert!(turns(0.1f64).shortest_difference(turns(2.4)).approx_eq(&turns(0.3)));
+
+    assert!(deg(170.0f64).lerp_shortest(deg(-170.0), 0.5).approx_eq(&deg(180.0)));
+    assert!(deg(10.0f64).lerp_shortest(deg(350.0), 0.25).approx_eq(&deg(5.0)));
+    assert!(grad(0.0f64).lerp_shortest(grad(100.0), 2.0).approx_eq(&grad(200.0)));
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", rad(1.5f64)), "1.5 rad");
+    assert_eq!(format!("{:.1}", deg(90.0f64)), "90.0°");
+    assert_eq!(format!("{}", turns(0.25f64)), "0.25 turns");
+    assert_eq!(format!("{}", grad(100.0f64)), "100 grad");
+    assert_eq!(format!("{:.0}", Euler::new(deg(90.0f64), deg(-45.0), deg(15.0))), "[90°, -45°, 15°]");
+}
+
+#[test]
+fn test_display_width() {
+    assert_eq!(format!("{:8.2}", deg(90.0f64)), "  90.00°");
+    assert_eq!(format!("{:8.2}", rad(-1.0f64)), "-1.00 rad");
+    assert_eq!(format!("{:>10}", rad(1.0f64)), "     1 rad");
+    assert_eq!(format!("{:<8}|", deg(90.0f64)), "90°     |");
+    assert_eq!(format!("{:*^9}", turns(0.5f64)), "0.5 turns");
+    assert_eq!(format!("{:*^11}", turns(0.5f64)), "*0.5 turns*");
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("1.5 rad".parse(), Ok(rad(1.5f64)));
+    assert_eq!("90°".parse(), Ok(deg(90.0f64)));
+    assert_eq!("90 deg".parse(), Ok(deg(90.0f64)));
+    assert_eq!("0.5".parse(), Ok(turns(0.5f64)));
+    assert_eq!("100grad".parse(), Ok(grad(100.0f64)));
+    assert!("180 deg".parse::<Rad<f64>>().unwrap().approx_eq(&rad(f64::consts::PI)));
+    assert!("0.25 turns".parse::<Deg<f64>>().unwrap().approx_eq(&deg(90.0)));
+    assert!("1 furlong".parse::<Rad<f64>>().is_err());
+    assert!("rad".parse::<Rad<f64>>().is_err());
+
+    assert_eq!("[90°, -45 deg, 15]".parse(), Ok(Euler::new(deg(90.0f64), deg(-45.0), deg(15.0))));
+    assert!("[90°, -45 deg]".parse::<Euler<Deg<f64>>>().is_err());
+
+    let angle = rad(-1.0f64 / 3.0);
+    assert_eq!(angle.to_string().parse(), Ok(angle));
+}
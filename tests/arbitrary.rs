@@ -0,0 +1,203 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "quickcheck")]
+
+extern crate cgmath;
+#[macro_use]
+extern crate quickcheck;
+
+use quickcheck::{Arbitrary, TestResult};
+
+use cgmath::*;
+use cgmath::arbitrary::{Invertible, Orthonormal, Unit};
+use cgmath::camera::{Camera, FirstPerson, Orbit};
+
+/// Repeatedly take the first shrink of a value, returning the last one.
+fn shrink_fully<T: Arbitrary>(mut value: T) -> T {
+    for _ in 0..1000 {
+        match value.shrink().next() {
+            Some(shrunk) => value = shrunk,
+            None => return value,
+        }
+    }
+    panic!("shrinking did not terminate");
+}
+
+/// Rotations are equal if they rotate vectors in the same way, as `q` and
+/// `-q` represent the same rotation.
+fn same_rotation(a: Quaternion<f64>, b: Quaternion<f64>) -> bool {
+    a.approx_eq(&b) || a.approx_eq(&-b)
+}
+
+quickcheck! {
+    fn test_invert_matrix2(m: Invertible<Matrix2<f64>>) -> bool {
+        let Invertible(m) = m;
+        let inv = m.invert().unwrap();
+        (m * inv).approx_eq_eps(&Matrix2::identity(), &1e-9) &&
+        (inv * m).approx_eq_eps(&Matrix2::identity(), &1e-9)
+    }
+
+    fn test_invert_matrix3(m: Invertible<Matrix3<f64>>) -> bool {
+        let Invertible(m) = m;
+        let inv = m.invert().unwrap();
+        (m * inv).approx_eq_eps(&Matrix3::identity(), &1e-9) &&
+        (inv * m).approx_eq_eps(&Matrix3::identity(), &1e-9)
+    }
+
+    fn test_invert_matrix4(m: Invertible<Matrix4<f64>>) -> bool {
+        let Invertible(m) = m;
+        let inv = m.invert().unwrap();
+        (m * inv).approx_eq_eps(&Matrix4::identity(), &1e-9) &&
+        (inv * m).approx_eq_eps(&Matrix4::identity(), &1e-9)
+    }
+
+    fn test_invertible_shrinks_are_invertible(m: Invertible<Matrix4<f64>>) -> bool {
+        m.shrink().all(|Invertible(m)| m.invert().is_some())
+    }
+
+    fn test_transpose_of_product(a: Matrix3<f64>, b: Matrix3<f64>) -> bool {
+        (a * b).transpose() == b.transpose() * a.transpose()
+    }
+
+    fn test_unit_vector(v: Unit<Vector3<f64>>) -> bool {
+        v.0.magnitude().approx_eq(&1.0) &&
+        v.shrink().all(|Unit(v)| v.magnitude().approx_eq(&1.0))
+    }
+
+    fn test_unit_quaternion(q: Unit<Quaternion<f64>>) -> bool {
+        q.0.magnitude().approx_eq(&1.0) &&
+        q.shrink().all(|Unit(q)| q.magnitude().approx_eq(&1.0))
+    }
+
+    fn test_quaternion_is_rotation(q: Quaternion<f64>, v: Vector3<f64>) -> bool {
+        q.magnitude().approx_eq(&1.0) &&
+        q.rotate_vector(v).magnitude().approx_eq_eps(&v.magnitude(), &1e-9) &&
+        (q * q.invert()).approx_eq(&Quaternion::one())
+    }
+
+    fn test_quaternion_matrix_round_trip(q: Quaternion<f64>) -> bool {
+        same_rotation(Quaternion::from(Matrix3::from(q)), q)
+    }
+
+    fn test_quaternion_euler_round_trip(q: Quaternion<f64>) -> TestResult {
+        let euler = Euler::from(q);
+        // Close to gimbal lock, the conversion snaps the pitch to a quarter
+        // turn, so the rotation is only approximately preserved
+        if euler.y.s.abs() > 1.5 {
+            return TestResult::discard();
+        }
+        TestResult::from_bool(same_rotation(Quaternion::from(euler), q))
+    }
+
+    fn test_rotor_quaternion_round_trip(r: Rotor3<f64>) -> bool {
+        Rotor3::from(Quaternion::from(r)).approx_eq(&r)
+    }
+
+    fn test_basis3_is_orthonormal(b: Basis3<f64>) -> bool {
+        b.is_orthonormal() && b.shrink().all(|b| b.is_orthonormal())
+    }
+
+    fn test_orthonormal_matrix3(m: Orthonormal<Matrix3<f64>>) -> bool {
+        let Orthonormal(m) = m;
+        m.is_orthonormal() && m.determinant().approx_eq(&1.0) &&
+        m.invert().unwrap().approx_eq(&m.transpose())
+    }
+
+    fn test_orthonormal_matrix2(m: Orthonormal<Matrix2<f64>>) -> bool {
+        let Orthonormal(m) = m;
+        m.is_orthonormal() && m.determinant().approx_eq(&1.0)
+    }
+
+    fn test_unit_complex_matches_basis2(r: UnitComplex<f64>, v: Vector2<f64>) -> bool {
+        r.rotate_vector(v).approx_eq_eps(&Basis2::from(r).rotate_vector(v), &1e-9)
+    }
+
+    fn test_dual_quaternion_matches_matrix(dq: DualQuaternion<f64>, p: Point3<f64>) -> bool {
+        dq.transform_point(p).approx_eq_eps(&Matrix4::from(dq).transform_point(p), &1e-9)
+    }
+
+    fn test_decomposed_inverse(t: Decomposed<Vector3<f64>, Quaternion<f64>>, p: Point3<f64>) -> TestResult {
+        if t.scale.abs() < 0.1 {
+            return TestResult::discard();
+        }
+        let inv = t.inverse_transform().unwrap();
+        TestResult::from_bool(inv.transform_point(t.transform_point(p)).approx_eq_eps(&p, &1e-9))
+    }
+
+    fn test_perspective_fov_near_plane(persp: PerspectiveFov<f64>) -> bool {
+        let m = Matrix4::from(persp);
+        let p = m * Vector4::new(0.0, 0.0, -persp.near, 1.0);
+        (p.z / p.w).approx_eq(&-1.0)
+    }
+
+    fn test_perspective_near_plane(persp: Perspective<f64>) -> bool {
+        let m = Matrix4::from(persp);
+        let p = m * Vector4::new(persp.left, persp.bottom, -persp.near, 1.0);
+        (p.truncate() / p.w).approx_eq_eps(&Vector3::new(-1.0, -1.0, -1.0), &1e-9)
+    }
+
+    fn test_ortho_corners(ortho: Ortho<f64>) -> bool {
+        let m = Matrix4::from(ortho);
+        let near = m.transform_point(Point3::new(ortho.left, ortho.bottom, -ortho.near));
+        let far = m.transform_point(Point3::new(ortho.right, ortho.top, -ortho.far));
+        near.approx_eq_eps(&Point3::new(-1.0, -1.0, -1.0), &1e-9) &&
+        far.approx_eq_eps(&Point3::new(1.0, 1.0, 1.0), &1e-9)
+    }
+
+    fn test_projection_shrinks_are_valid(persp: PerspectiveFov<f64>) -> bool {
+        persp.shrink().all(|persp| persp.near > 0.0 && persp.far > persp.near)
+    }
+
+    fn test_camera_view_is_rigid(camera: FirstPerson<f64>) -> bool {
+        let view = camera.view_matrix();
+        let rotation = Matrix3::from_cols(view.x.truncate(), view.y.truncate(), view.z.truncate());
+        rotation.is_orthonormal() && camera.pitch.s.abs() < camera.max_pitch.s &&
+        camera.forward().y.approx_eq(&camera.pitch.s.sin())
+    }
+
+    fn test_camera_shrinks_pitch_towards_zero(camera: Orbit<f64>) -> bool {
+        camera.shrink().all(|shrunk| shrunk.pitch.s.abs() <= camera.pitch.s.abs())
+    }
+
+    fn test_change_convention_round_trip(v: Vector3<f64>, from: Convention, to: Convention) -> bool {
+        v.change_convention(from, to).change_convention(to, from) == v
+    }
+
+    fn test_track_passes_through_keyframes(track: Track<f64, f64>) -> bool {
+        let mut track = track;
+        track.wrap = Wrap::Clamp;
+        let values_per_key = track.values().len() / track.times().len();
+        track.times().iter().enumerate().all(|(key, &time)| {
+            let value = track.values()[key * values_per_key + values_per_key / 2];
+            track.sample(time).approx_eq_eps(&value, &1e-9)
+        })
+    }
+
+    fn test_typed_point(p: TypedPoint3<f64, ()>, v: TypedVector3<f64, ()>) -> bool {
+        ((p + v) - p).approx_eq_eps(&v, &1e-9)
+    }
+
+    fn test_rotations_shrink_to_identity(q: Quaternion<f64>, b: Basis3<f64>, v: Unit<Vector3<f64>>) -> bool {
+        shrink_fully(q) == Quaternion::one() &&
+        shrink_fully(b) == Basis3::one() &&
+        shrink_fully(v) == Unit(Vector3::unit_x())
+    }
+
+    fn test_track_shrinks_to_one_keyframe(track: Track<f64, Vector3<f64>>) -> bool {
+        let track = shrink_fully(track);
+        track.times().len() == 1 && track.wrap == Wrap::Clamp
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate cgmath;
+
+use cgmath::*;
+use cgmath::camera::*;
+
+use std::f64::consts::FRAC_PI_2;
+
+/// Check that the view matrix is the inverse of the transform, and agrees with
+/// `Matrix4::look_at`.
+fn check_view<C: Camera<f64>>(camera: &C) {
+    let view = camera.view_matrix();
+    let transform = Matrix4::from(camera.transform());
+    assert_approx_eq!(view * transform, Matrix4::identity());
+
+    let eye = camera.position();
+    let up = camera.transform().rot.rotate_vector(Vector3::unit_y());
+    assert_approx_eq!(view, Matrix4::look_at(eye, eye + camera.forward(), up));
+}
+
+#[test]
+fn test_first_person() {
+    let mut camera = FirstPerson::new(Point3::new(1.0, 2.0, 3.0), Rad::new(0.0), Rad::new(0.0));
+    assert_approx_eq!(camera.forward(), -Vector3::unit_z());
+    check_view(&camera);
+
+    // Turning left
+    camera.rotate(Rad::new(FRAC_PI_2), Rad::new(0.0));
+    assert_approx_eq!(camera.forward(), -Vector3::unit_x());
+    assert_approx_eq!(camera.right(), -Vector3::unit_z());
+
+    // Moving is horizontal, regardless of pitch
+    camera.rotate(Rad::new(0.0), Rad::new(0.5));
+    assert!(camera.forward().y > 0.0);
+    camera.move_by(2.0, 1.0, 0.5);
+    assert_approx_eq!(camera.position, Point3::new(-1.0, 2.5, 2.0));
+    check_view(&camera);
+}
+
+#[test]
+fn test_pitch_clamping() {
+    let mut camera = FirstPerson::new(Point3::origin(), Rad::new(0.3), Rad::new(10.0));
+    assert_eq!(camera.pitch, camera.max_pitch);
+    camera.rotate(Rad::new(0.0), Rad::new(-20.0));
+    assert_eq!(camera.pitch, -camera.max_pitch);
+    assert_approx_eq!(Deg::from(camera.max_pitch), Deg::new(89.0));
+    check_view(&camera);
+}
+
+#[test]
+fn test_orbit() {
+    let target = Point3::new(1.0, 0.0, -2.0);
+    let mut camera = Orbit::new(target, 5.0, Rad::new(0.0), Rad::new(0.0));
+    assert_approx_eq!(camera.position(), Point3::new(1.0, 0.0, 3.0));
+    check_view(&camera);
+
+    // Above the target, looking down at it
+    camera.rotate(Rad::new(1.0), Rad::new(0.6));
+    assert!(camera.position().y > 0.0);
+    assert_approx_eq!(camera.position().distance(target), 5.0);
+    assert_approx_eq!(camera.forward(), (target - camera.position()).normalize());
+    check_view(&camera);
+
+    camera.min_distance = 2.0;
+    camera.zoom(0.5);
+    assert_eq!(camera.distance, 2.5);
+    camera.zoom(0.5);
+    assert_eq!(camera.distance, 2.0);
+
+    let forward = camera.forward();
+    camera.pan(1.0, 2.0);
+    assert_approx_eq!((camera.target - target).dot(forward), 0.0);
+    assert_approx_eq!((camera.target - target).magnitude2(), 5.0);
+}
+
+#[test]
+fn test_arcball_projection() {
+    assert_approx_eq!(Arcball::project(Vector2::new(0.0, 0.0)), Vector3::unit_z());
+    assert_approx_eq!(Arcball::project(Vector2::new(0.6, 0.0)), Vector3::new(0.6, 0.0, 0.8));
+    assert_approx_eq!(Arcball::project(Vector2::new(0.0, 3.0)), Vector3::unit_y());
+}
+
+#[test]
+fn test_arcball() {
+    let mut arcball = Arcball::new(Point3::new(0.0, 1.0, 0.0), 4.0);
+    check_view(&arcball);
+    assert_approx_eq!(arcball.view_matrix().transform_point(arcball.target), Point3::new(0.0, 0.0, -4.0));
+
+    // Dragging right brings the front of the object to the right
+    let (from, to) = (Vector2::new(0.0, 0.0), Vector2::new(0.6, 0.0));
+    arcball.drag(from, to);
+    assert_approx_eq!(arcball.rotation.rotate_vector(Vector3::unit_z()), Vector3::new(0.6, 0.0, 0.8));
+    let front = arcball.target + Vector3::unit_z();
+    assert_approx_eq!(arcball.view_matrix().transform_point(front), Point3::new(0.6, 0.0, -3.2));
+    check_view(&arcball);
+
+    // Dragging back undoes the rotation
+    arcball.drag(to, from);
+    assert_approx_eq!(arcball.rotation, Quaternion::one());
+}
+
+#[test]
+fn test_arcball_opposite_rim() {
+    let mut arcball = Arcball::new(Point3::new(0.0, 1.0, 0.0), 4.0);
+    let (from, to) = (Vector2::new(-2.0f64, 0.0), Vector2::new(2.0, 0.0));
+    arcball.drag(from, to);
+    assert!(arcball.rotation.s.is_finite() && arcball.rotation.v.magnitude().is_finite());
+    assert_approx_eq!(arcball.rotation.rotate_vector(Arcball::project(from)), Arcball::project(to));
+    assert_approx_eq!(arcball.rotation.magnitude(), 1.0);
+    check_view(&arcball);
+}
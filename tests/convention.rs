@@ -0,0 +1,94 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate cgmath;
+
+use cgmath::*;
+
+fn conventions() -> Vec<Convention> {
+    vec![Convention::y_up_right_handed(), Convention::y_up_left_handed(),
+         Convention::z_up_right_handed(), Convention::z_up_left_handed()]
+}
+
+fn rotation() -> Quaternion<f64> {
+    Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, -2.0).normalize(), Rad::new(0.9))
+}
+
+#[test]
+fn test_handedness() {
+    for c in conventions() {
+        let basis: Matrix3<f64> = c.basis();
+        let expected = match c.handedness { Handedness::Right => 1.0, Handedness::Left => -1.0 };
+        assert_eq!(basis.determinant(), expected);
+        assert_eq!(basis * Vector3::unit_x(), Vector3::unit_x());
+        let up = match c.up { UpAxis::Y => Vector3::unit_y(), UpAxis::Z => Vector3::unit_z() };
+        assert_eq!(basis * up, Vector3::unit_y());
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    for a in conventions() {
+        assert_eq!(v.change_convention(a, a), v);
+        for b in conventions() {
+            assert_eq!(v.change_convention(a, b).change_convention(b, a), v);
+            assert_approx_eq!(rotation().change_convention(a, b).change_convention(b, a), rotation());
+        }
+    }
+}
+
+#[test]
+fn test_transformations() {
+    let v = Vector3::new(0.5, -1.0, 2.0);
+    let p = Point3::new(3.0, 1.0, -4.0);
+    let q = rotation();
+    let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from(q);
+    let d = Decomposed { scale: 2.0, rot: q, disp: Vector3::new(-1.0, 0.0, 5.0) };
+
+    for a in conventions() {
+        for b in conventions() {
+            // Transforming and then converting is the same as converting and
+            // then transforming
+            let convert = |v: Vector3<f64>| v.change_convention(a, b);
+            assert_approx_eq!(q.change_convention(a, b).rotate_vector(convert(v)), convert(q.rotate_vector(v)));
+            assert_approx_eq!(Matrix3::from(q).change_convention(a, b), Matrix3::from(q.change_convention(a, b)));
+            assert_approx_eq!(m.change_convention(a, b).transform_point(p.change_convention(a, b)),
+                              m.transform_point(p).change_convention(a, b));
+            assert_approx_eq!(d.change_convention(a, b).transform_point(p.change_convention(a, b)),
+                              d.transform_point(p).change_convention(a, b));
+        }
+    }
+}
+
+#[test]
+fn test_look_at_lh() {
+    let eye = Point3::new(1.0, 2.0, 3.0);
+    let center = Point3::new(-2.0, 0.0, 1.0);
+    let up = Vector3::unit_y();
+
+    let lh = Matrix4::look_at_lh(eye, center, up);
+    let view = lh.transform_point(center);
+    assert_approx_eq!(view, Point3::new(0.0, 0.0, (center - eye).magnitude()));
+    assert_approx_eq!(lh.transform_point(eye), Point3::origin());
+    assert!(lh.transform_vector(up).y > 0.0);
+
+    // The view is a rotation within the left-handed world, so the view space
+    // is also left-handed
+    let rotation = Matrix3::from_cols(lh.x.truncate(), lh.y.truncate(), lh.z.truncate());
+    assert_approx_eq!(rotation.determinant(), 1.0);
+    assert_approx_eq!(rotation * rotation.transpose(), Matrix3::identity());
+}
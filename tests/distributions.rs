@@ -0,0 +1,139 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "rand")]
+
+#[macro_use]
+extern crate cgmath;
+extern crate rand;
+
+use rand::{SeedableRng, XorShiftRng};
+use rand::distributions::{IndependentSample, Sample};
+
+use cgmath::*;
+use cgmath::distributions::{StandardNormal, UniformRotation, UnitComponents, UnitSphere};
+
+const N: usize = 10000;
+
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([1, 2, 3, 4])
+}
+
+#[test]
+fn test_unit_components() {
+    let mut rng = rng();
+    let mut mean = Vector3::zero();
+    for _ in 0..N {
+        let v: Vector3<f64> = UnitComponents.ind_sample(&mut rng);
+        assert!(v.x >= 0.0 && v.x < 1.0 && v.y >= 0.0 && v.y < 1.0 && v.z >= 0.0 && v.z < 1.0);
+        mean += v / N as f64;
+    }
+    assert_approx_eq_eps!(mean, Vector3::from_value(0.5), 0.02);
+
+    let p: Point2<f32> = UnitComponents.ind_sample(&mut rng);
+    assert!(p.x >= 0.0 && p.x < 1.0 && p.y >= 0.0 && p.y < 1.0);
+}
+
+#[test]
+fn test_standard_normal() {
+    let mut rng = rng();
+    let mut mean = Vector4::zero();
+    let mut mean_sq = Vector4::zero();
+    for _ in 0..N {
+        let v: Vector4<f64> = StandardNormal.ind_sample(&mut rng);
+        mean += v / N as f64;
+        mean_sq += v.mul_element_wise(v) / N as f64;
+    }
+    assert_approx_eq_eps!(mean, Vector4::zero(), 0.03);
+    assert_approx_eq_eps!(mean_sq, Vector4::from_value(1.0), 0.05);
+}
+
+#[test]
+fn test_standard_normal_quaternion() {
+    // Normalizing a quaternion with normally distributed components gives a
+    // uniformly distributed rotation
+    let mut rng = rng();
+    let mut mean_sq = Vector4::zero();
+    for _ in 0..N {
+        let q: Quaternion<f64> = StandardNormal.ind_sample(&mut rng);
+        let q = q.normalize();
+        mean_sq += Vector4::new(q.s * q.s, q.v.x * q.v.x, q.v.y * q.v.y, q.v.z * q.v.z) / N as f64;
+    }
+    assert_approx_eq_eps!(mean_sq, Vector4::from_value(0.25), 0.01);
+}
+
+#[test]
+fn test_unit_sphere() {
+    let mut rng = rng();
+    let mut mean = Vector3::zero();
+    for _ in 0..N {
+        let v: Vector3<f64> = UnitSphere.ind_sample(&mut rng);
+        assert_approx_eq!(v.magnitude(), 1.0);
+        mean += v / N as f64;
+    }
+    assert_approx_eq_eps!(mean, Vector3::zero(), 0.02);
+
+    let v: Vector2<f32> = UnitSphere.ind_sample(&mut rng);
+    assert_approx_eq!(v.magnitude(), 1.0);
+}
+
+#[test]
+fn test_uniform_rotation_3d() {
+    let mut rng = rng();
+    let mut mean_sq = Vector4::zero();
+    for _ in 0..N {
+        let q: Quaternion<f64> = UniformRotation.ind_sample(&mut rng);
+        assert_approx_eq!(q.magnitude(), 1.0);
+        mean_sq += Vector4::new(q.s * q.s, q.v.x * q.v.x, q.v.y * q.v.y, q.v.z * q.v.z) / N as f64;
+    }
+    assert_approx_eq_eps!(mean_sq, Vector4::from_value(0.25), 0.01);
+
+    for _ in 0..100 {
+        let b: Basis3<f64> = UniformRotation.ind_sample(&mut rng);
+        assert!(b.is_orthonormal());
+        let m: Matrix3<f64> = UniformRotation.ind_sample(&mut rng);
+        assert!(m.is_orthonormal());
+        assert_approx_eq!(m.determinant(), 1.0);
+        let r: Rotor3<f64> = UniformRotation.ind_sample(&mut rng);
+        assert_approx_eq!(Quaternion::from(r).magnitude(), 1.0);
+        let e: Euler<Rad<f64>> = UniformRotation.ind_sample(&mut rng);
+        assert!(e.y >= -Rad::turn_div_4() && e.y <= Rad::turn_div_4());
+    }
+}
+
+#[test]
+fn test_uniform_rotation_2d() {
+    let mut rng = rng();
+    let mut mean = Vector2::zero();
+    for _ in 0..N {
+        let m: Matrix2<f64> = UniformRotation.ind_sample(&mut rng);
+        assert_approx_eq!(m.determinant(), 1.0);
+        mean += m.x / N as f64;
+    }
+    assert_approx_eq_eps!(mean, Vector2::zero(), 0.02);
+
+    let b: Basis2<f32> = UniformRotation.ind_sample(&mut rng);
+    assert_approx_eq!(b.as_ref().determinant(), 1.0);
+    let c: UnitComplex<f32> = UniformRotation.ind_sample(&mut rng);
+    assert_approx_eq!(c.re * c.re + c.im * c.im, 1.0);
+}
+
+#[test]
+fn test_sample_matches_ind_sample() {
+    let mut distribution = UniformRotation;
+    let a: Quaternion<f64> = distribution.sample(&mut rng());
+    let b: Quaternion<f64> = UniformRotation.ind_sample(&mut rng());
+    assert_eq!(a, b);
+}
@@ -0,0 +1,183 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate cgmath;
+
+use cgmath::*;
+
+fn rotation() -> Quaternion<f64> {
+    Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Rad::new(1.2))
+}
+
+fn transform() -> DualQuaternion<f64> {
+    DualQuaternion::from_rotation_translation(rotation(), Vector3::new(4.0, -5.0, 6.0))
+}
+
+#[test]
+fn test_rotation_translation() {
+    let dq = transform();
+    assert_approx_eq!(dq.rotation(), rotation());
+    assert_approx_eq!(dq.translation(), Vector3::new(4.0, -5.0, 6.0));
+
+    let p = Point3::new(1.0, 2.0, 3.0);
+    let expected = Point3::from_vec(rotation().rotate_vector(p.to_vec()) + Vector3::new(4.0, -5.0, 6.0));
+    assert_approx_eq!(dq.transform_point(p), expected);
+    assert_approx_eq!(dq.transform_vector(p.to_vec()), rotation().rotate_vector(p.to_vec()));
+
+    let t = DualQuaternion::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    assert_approx_eq!(t.transform_point(p), Point3::new(2.0, 4.0, 6.0));
+    let r = DualQuaternion::from_rotation(rotation());
+    assert_approx_eq!(r.translation(), Vector3::zero());
+}
+
+#[test]
+fn test_transform() {
+    let a = transform();
+    let b = DualQuaternion::from_rotation_translation(Quaternion::from_angle_y(Rad::new(-0.4)), Vector3::new(0.0, 1.0, 2.0));
+    let p = Point3::new(-1.0, 0.5, 2.0);
+
+    assert_approx_eq!(DualQuaternion::one().transform_point(p), p);
+    assert_approx_eq!(a.concat(&b).transform_point(p), a.transform_point(b.transform_point(p)));
+    assert_approx_eq!(a.inverse_transform().unwrap().transform_point(a.transform_point(p)), p);
+
+    let eye = Point3::new(1.0, 2.0, 3.0);
+    let center = Point3::new(-2.0, 0.0, 1.0);
+    let up = Vector3::unit_y();
+    let dq: DualQuaternion<f64> = Transform::look_at(eye, center, up);
+    let decomposed: Decomposed<Vector3<f64>, Quaternion<f64>> = Transform::look_at(eye, center, up);
+    assert_approx_eq!(dq.transform_point(p), decomposed.transform_point(p));
+}
+
+#[test]
+fn test_decomposed_conversion() {
+    let dq = transform();
+    let decomposed = Decomposed::from(dq);
+    assert_eq!(decomposed.scale, 1.0);
+    assert_approx_eq!(decomposed.rot, rotation());
+    assert_approx_eq!(decomposed.disp, Vector3::new(4.0, -5.0, 6.0));
+    assert_approx_eq!(DualQuaternion::from_decomposed(&decomposed).unwrap(), dq);
+
+    let scaled = Decomposed { scale: 2.0, ..decomposed };
+    assert!(DualQuaternion::from_decomposed(&scaled).is_none());
+}
+
+#[test]
+fn test_matrix_conversion() {
+    let dq = transform();
+    let m = Matrix4::from(dq);
+    let p = Point3::new(1.0, -2.0, 0.5);
+    assert_approx_eq!(m.transform_point(p), dq.transform_point(p));
+
+    let back = DualQuaternion::from_matrix4(&m).unwrap();
+    assert_approx_eq!(back.transform_point(p), dq.transform_point(p));
+
+    assert!(DualQuaternion::from_matrix4(&(m * Matrix4::from_scale(2.0))).is_none());
+    assert!(DualQuaternion::from_matrix4(&(m * Matrix4::from_nonuniform_scale(1.0, 1.0, -1.0))).is_none());
+    assert!(DualQuaternion::from_matrix4(&perspective(Deg::new(60.0), 1.0, 0.1, 100.0)).is_none());
+}
+
+#[test]
+fn test_normalize() {
+    let dq = transform();
+    let unnormalized = DualQuaternion::new(dq.real * 3.0, dq.dual * 3.0 + dq.real * 0.1);
+    let normalized = unnormalized.normalize();
+    assert_approx_eq!(normalized.real.magnitude(), 1.0);
+    assert_approx_eq!(normalized.real.dot(normalized.dual), 0.0);
+    assert_approx_eq!(normalized.real, dq.real);
+}
+
+#[test]
+fn test_screw() {
+    // A rotation around the `z` axis through (1, 0, 0), while moving along it
+    let p = Vector3::new(1.0, 0.0, 0.0);
+    let axis = Vector3::unit_z();
+    let dq = DualQuaternion::from_screw(axis, Rad::new(1.0), 2.0, p.cross(axis));
+
+    let (a, angle, pitch, moment) = dq.to_screw();
+    assert_approx_eq!(a, axis);
+    assert_approx_eq!(angle, Rad::new(1.0));
+    assert_approx_eq!(pitch, 2.0);
+    assert_approx_eq!(moment, p.cross(axis));
+
+    // Points on the axis only move along it
+    assert_approx_eq!(dq.transform_point(Point3::new(1.0, 0.0, 5.0)), Point3::new(1.0, 0.0, 7.0));
+
+    // The parameters of a general transformation round trip
+    let dq = transform();
+    let (axis, angle, pitch, moment) = dq.to_screw();
+    assert_approx_eq!(axis.magnitude(), 1.0);
+    assert_approx_eq!(axis.dot(moment), 0.0);
+    assert_approx_eq!(DualQuaternion::from_screw(axis, angle, pitch, moment), dq);
+
+    // A pure translation
+    let dq = DualQuaternion::from_translation(Vector3::new(0.0, 3.0, 4.0));
+    let (axis, angle, pitch, _) = dq.to_screw();
+    assert_approx_eq!(axis, Vector3::new(0.0, 0.6, 0.8));
+    assert_approx_eq!(angle, Rad::zero());
+    assert_approx_eq!(pitch, 5.0);
+}
+
+#[test]
+fn test_sclerp() {
+    let a = DualQuaternion::from_translation(Vector3::new(1.0, 0.0, 0.0));
+    let b = transform();
+    assert_approx_eq!(a.sclerp(b, 0.0), a);
+    assert_approx_eq!(a.sclerp(b, 1.0), b);
+    assert_approx_eq!(a.sclerp(-b, 1.0), b);
+
+    // Pure translations are interpolated linearly
+    let c = DualQuaternion::from_translation(Vector3::new(3.0, 4.0, 0.0));
+    assert_approx_eq!(a.sclerp(c, 0.5).translation(), Vector3::new(2.0, 2.0, 0.0));
+
+    // Pure rotations are interpolated spherically
+    let (r0, r1) = (Quaternion::from_angle_x(Rad::new(0.2)), rotation());
+    let mid = DualQuaternion::from_rotation(r0).sclerp(DualQuaternion::from_rotation(r1), 0.3);
+    assert_approx_eq!(mid.rotation(), r0.slerp(r1, 0.3));
+    assert_approx_eq!(mid.translation(), Vector3::zero());
+
+    // The motion is at a constant speed
+    let p = Point3::new(0.5, 0.5, 0.5);
+    let step = |t: f64| a.sclerp(b, t + 0.1).transform_point(p).distance(a.sclerp(b, t).transform_point(p));
+    assert_approx_eq!(step(0.0), step(0.45));
+}
+
+#[test]
+fn test_blend() {
+    let a = transform();
+    let b = DualQuaternion::from_rotation_translation(Quaternion::from_angle_z(Rad::new(2.0)), Vector3::new(1.0, 0.0, 0.0));
+
+    assert!(DualQuaternion::<f64>::blend(&[]).is_none());
+    assert_approx_eq!(DualQuaternion::blend(&[(1.0, a)]).unwrap(), a);
+    assert_approx_eq!(DualQuaternion::blend(&[(0.25, a), (0.75, a)]).unwrap(), a);
+    // Antipodal quaternions describe the same transformation
+    assert_approx_eq!(DualQuaternion::blend(&[(0.5, a), (0.5, -a)]).unwrap(), a);
+
+    let blended = DualQuaternion::blend(&[(0.5, a), (0.5, b)]).unwrap();
+    assert_approx_eq!(blended.real.magnitude(), 1.0);
+    assert_approx_eq!(blended.real.dot(blended.dual), 0.0);
+    assert!(DualQuaternion::blend(&[(1.0, a), (-1.0, a)]).is_none());
+}
+
+#[test]
+fn test_display_from_str() {
+    let dq = DualQuaternion::from_translation(Vector3::new(1.0f64, 2.0, 3.0));
+    assert_eq!(format!("{}", dq), "[1 + 0i + 0j + 0k, 0 + 0.5i + 1j + 1.5k]");
+    assert_eq!("[1, 0.5i + 1j + 1.5k]".parse(), Ok(dq));
+    assert!("[1 + 0i + 0j + 0k]".parse::<DualQuaternion<f64>>().is_err());
+
+    let dq = transform();
+    assert_eq!(dq.to_string().parse(), Ok(dq));
+}
@@ -0,0 +1,113 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "mint")]
+
+extern crate cgmath;
+extern crate mint;
+
+use std::fmt::Debug;
+
+use cgmath::*;
+use cgmath::pod;
+use mint::IntoMint;
+
+fn test_round_trip<T, M>(value: T) where
+    T: Copy + Debug + PartialEq + From<M> + Into<M>,
+{
+    let mint: M = value.into();
+    assert_eq!(T::from(mint), value);
+}
+
+#[test]
+fn test_vectors() {
+    assert_eq!(mint::Vector2::from(Vector2::new(1, 2)), mint::Vector2 { x: 1, y: 2 });
+    assert_eq!(mint::Vector3::from(Vector3::new(1, 2, 3)), mint::Vector3 { x: 1, y: 2, z: 3 });
+    assert_eq!(mint::Vector4::from(Vector4::new(1, 2, 3, 4)), mint::Vector4 { x: 1, y: 2, z: 3, w: 4 });
+    assert_eq!(Vector3::from(mint::Vector3 { x: 1, y: 2, z: 3 }), Vector3::new(1, 2, 3));
+
+    test_round_trip::<_, mint::Vector2<f32>>(Vector2::new(1.0f32, 2.0));
+    test_round_trip::<_, mint::Vector3<f32>>(Vector3::new(1.0f32, 2.0, 3.0));
+    test_round_trip::<_, mint::Vector4<f32>>(Vector4::new(1.0f32, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn test_points() {
+    assert_eq!(mint::Point2::from(Point2::new(1, 2)), mint::Point2 { x: 1, y: 2 });
+    assert_eq!(mint::Point3::from(Point3::new(1, 2, 3)), mint::Point3 { x: 1, y: 2, z: 3 });
+    test_round_trip::<_, mint::Point3<f64>>(Point3::new(1.0f64, 2.0, 3.0));
+}
+
+#[test]
+fn test_column_matrices() {
+    let m = Matrix3::new(1.0f32, 2.0, 3.0,
+                         4.0, 5.0, 6.0,
+                         7.0, 8.0, 9.0);
+    let cols = mint::ColumnMatrix3::from(m);
+    // The first column, second row
+    assert_eq!(cols.x.y, 2.0);
+    assert_eq!(cols.z, mint::Vector3 { x: 7.0, y: 8.0, z: 9.0 });
+
+    test_round_trip::<_, mint::ColumnMatrix2<f32>>(Matrix2::new(1.0f32, 2.0, 3.0, 4.0));
+    test_round_trip::<_, mint::ColumnMatrix3<f32>>(m);
+    test_round_trip::<_, mint::ColumnMatrix4<f32>>(Matrix4::from_translation(Vector3::new(1.0f32, 2.0, 3.0)));
+}
+
+#[test]
+fn test_row_matrices() {
+    let m = Matrix4::from_translation(Vector3::new(1.0f32, 2.0, 3.0));
+    let rows = mint::RowMatrix4::from(m);
+    // The translation is in the last column, so the last element of each row
+    assert_eq!(rows.x.w, 1.0);
+    assert_eq!(rows.y.w, 2.0);
+    assert_eq!(rows.z.w, 3.0);
+    assert_eq!(rows.w, mint::Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 });
+
+    test_round_trip::<_, mint::RowMatrix2<f32>>(Matrix2::new(1.0f32, 2.0, 3.0, 4.0));
+    test_round_trip::<_, mint::RowMatrix3<f32>>(Matrix3::new(1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0));
+    test_round_trip::<_, mint::RowMatrix4<f32>>(m);
+}
+
+#[test]
+fn test_quaternion() {
+    let q = Quaternion::new(1.0f32, 2.0, 3.0, 4.0);
+    let mq = mint::Quaternion::from(q);
+    assert_eq!(mq.s, 1.0);
+    assert_eq!(mq.v, mint::Vector3 { x: 2.0, y: 3.0, z: 4.0 });
+    // mint stores the scalar part last
+    let array: [f32; 4] = mq.into();
+    assert_eq!(array, [2.0, 3.0, 4.0, 1.0]);
+    test_round_trip::<_, mint::Quaternion<f32>>(q);
+}
+
+#[test]
+fn test_into_mint() {
+    fn to_mint<T: IntoMint>(value: T) -> T::MintType { value.into() }
+
+    assert_eq!(to_mint(Vector2::new(1, 2)), mint::Vector2 { x: 1, y: 2 });
+    assert_eq!(to_mint(Matrix2::new(1.0f32, 2.0, 3.0, 4.0)).y, mint::Vector2 { x: 3.0, y: 4.0 });
+    assert_eq!(to_mint(Quaternion::new(1.0f32, 0.0, 0.0, 0.0)).s, 1.0);
+}
+
+#[test]
+fn test_cast_slices() {
+    let points = [mint::Point3 { x: 1.0f32, y: 2.0, z: 3.0 }, mint::Point3 { x: 4.0, y: 5.0, z: 6.0 }];
+    let cast: &[Point3<f32>] = pod::cast_slice(&points).unwrap();
+    assert_eq!(cast, &[Point3::new(1.0, 2.0, 3.0), Point3::new(4.0, 5.0, 6.0)]);
+
+    let matrices = [mint::ColumnMatrix2::from([[1.0f32, 2.0], [3.0, 4.0]])];
+    let cast: &[Matrix2<f32>] = pod::cast_slice(&matrices).unwrap();
+    assert_eq!(cast, &[Matrix2::new(1.0, 2.0, 3.0, 4.0)]);
+}
@@ -0,0 +1,184 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate cgmath;
+
+use cgmath::*;
+
+fn degrees(value: f64) -> Rad<f64> {
+    Deg::new(value).into()
+}
+
+#[test]
+fn test_scalar() {
+    assert_eq!(1.0f32.interpolate(3.0, 0.25), 1.5);
+    assert_eq!(1.0f64.interpolate(3.0, 0.0), 1.0);
+    assert_eq!(1.0f64.interpolate(3.0, 1.0), 3.0);
+    assert_eq!(1.0f64.interpolate(3.0, 1.5), 4.0);
+}
+
+#[test]
+fn test_vector_and_point() {
+    assert_eq!(Vector4::new(0.0, 1.0, 2.0, 3.0).interpolate(Vector4::new(4.0, 5.0, 6.0, 7.0), 0.5),
+               Vector4::new(2.0, 3.0, 4.0, 5.0));
+    assert_eq!(Point3::new(0.0, 0.0, 0.0).interpolate(Point3::new(2.0, -4.0, 8.0), 0.25),
+               Point3::new(0.5, -1.0, 2.0));
+}
+
+#[test]
+fn test_angle_shortest_arc() {
+    assert_approx_eq!(Deg::new(10.0f64).interpolate(Deg::new(50.0), 0.5), Deg::new(30.0));
+    assert_approx_eq!(Deg::new(350.0f64).interpolate(Deg::new(10.0), 0.5), Deg::new(360.0));
+    assert_approx_eq!(Deg::new(10.0f64).interpolate(Deg::new(350.0), 0.5), Deg::new(0.0));
+    assert_approx_eq!(Deg::new(-170.0f64).interpolate(Deg::new(170.0), 0.25), Deg::new(-175.0));
+    assert_approx_eq!(Deg::new(0.0f64).interpolate(Deg::new(720.0 + 90.0), 1.0), Deg::new(90.0));
+
+    let (a, b) = (Rad::new(3.0f64), Rad::new(-3.0));
+    let mid = a.interpolate(b, 0.5);
+    assert_approx_eq!(mid.normalize(), Rad::turn_div_2());
+}
+
+#[test]
+fn test_quaternion() {
+    let a = Quaternion::from_angle_z(degrees(10.0));
+    let b = Quaternion::from_angle_z(degrees(70.0));
+    assert_approx_eq!(a.interpolate(b, 0.0), a);
+    assert_approx_eq!(a.interpolate(b, 1.0), b);
+    assert_approx_eq!(a.interpolate(b, 0.5), Quaternion::from_angle_z(degrees(40.0)));
+
+    // `-b` is the same rotation as `b`, so the result should be the same
+    let c = a.interpolate(-b, 0.5);
+    assert_approx_eq!(c.rotate_vector(Vector3::unit_x()),
+                      Quaternion::from_angle_z(degrees(40.0)).rotate_vector(Vector3::unit_x()));
+}
+
+#[test]
+fn test_basis2() {
+    let a = Basis2::from_angle(degrees(170.0));
+    let b = Basis2::from_angle(degrees(-150.0));
+    assert_approx_eq!(a.interpolate(b, 0.0), a);
+    assert_approx_eq!(a.interpolate(b, 1.0), b);
+    assert_approx_eq!(a.interpolate(b, 0.5), Basis2::from_angle(degrees(190.0)));
+}
+
+#[test]
+fn test_basis3() {
+    let a = Basis3::from_angle_x(degrees(0.0));
+    let b = Basis3::from_angle_x(degrees(90.0));
+    assert_approx_eq!(a.interpolate(b, 1.0), b);
+    assert_approx_eq!(a.interpolate(b, 0.5), Basis3::from_angle_x(degrees(45.0)));
+}
+
+#[test]
+fn test_euler() {
+    let a = Euler::new(Deg::new(0.0f64), Deg::new(0.0), Deg::new(0.0));
+    let b = Euler::new(Deg::new(0.0), Deg::new(0.0), Deg::new(80.0));
+    assert_approx_eq!(a.interpolate(b, 0.25), Euler::new(Deg::new(0.0), Deg::new(0.0), Deg::new(20.0)));
+
+    // The rotations are interpolated, not the individual angles
+    let a = Euler::new(Rad::new(0.3f64), Rad::new(0.2), Rad::new(-0.4));
+    let b = Euler::new(Rad::new(-1.0), Rad::new(0.5), Rad::new(2.0));
+    let expected = Quaternion::from(a).interpolate(Quaternion::from(b), 0.3);
+    assert_approx_eq!(Quaternion::from(a.interpolate(b, 0.3)), expected);
+}
+
+#[test]
+fn test_decomposed() {
+    let a = Decomposed {
+        scale: 1.0f64,
+        rot: Quaternion::from_angle_y(degrees(0.0)),
+        disp: Vector3::new(0.0, 0.0, 0.0),
+    };
+    let b = Decomposed {
+        scale: 3.0,
+        rot: Quaternion::from_angle_y(degrees(90.0)),
+        disp: Vector3::new(2.0, 4.0, 6.0),
+    };
+    let c = a.interpolate(b, 0.5);
+    assert_approx_eq!(c.scale, 2.0);
+    assert_approx_eq!(c.rot, Quaternion::from_angle_y(degrees(45.0)));
+    assert_approx_eq!(c.disp, Vector3::new(1.0, 2.0, 3.0));
+}
+
+mod ease {
+    use cgmath::ease::*;
+
+    const CURVES: [(&'static str, fn(f64) -> f64); 19] = [
+        ("linear", linear),
+        ("quad_in", quad_in), ("quad_out", quad_out), ("quad_in_out", quad_in_out),
+        ("cubic_in", cubic_in), ("cubic_out", cubic_out), ("cubic_in_out", cubic_in_out),
+        ("expo_in", expo_in), ("expo_out", expo_out), ("expo_in_out", expo_in_out),
+        ("elastic_in", elastic_in), ("elastic_out", elastic_out), ("elastic_in_out", elastic_in_out),
+        ("back_in", back_in), ("back_out", back_out), ("back_in_out", back_in_out),
+        ("bounce_in", bounce_in), ("bounce_out", bounce_out), ("bounce_in_out", bounce_in_out),
+    ];
+
+    #[test]
+    fn test_end_points() {
+        for &(name, f) in CURVES.iter() {
+            assert!(f(0.0).abs() < 1e-3, "{} starts at {}", name, f(0.0));
+            assert!((f(1.0) - 1.0).abs() < 1e-3, "{} ends at {}", name, f(1.0));
+        }
+    }
+
+    #[test]
+    fn test_continuity() {
+        for &(name, f) in CURVES.iter() {
+            for i in 0..1000 {
+                let (t0, t1) = (i as f64 / 1000.0, (i + 1) as f64 / 1000.0);
+                assert!((f(t1) - f(t0)).abs() < 0.05, "{} is discontinuous at {}", name, t0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_symmetry() {
+        let pairs: [(fn(f64) -> f64, fn(f64) -> f64); 6] = [
+            (quad_in, quad_out), (cubic_in, cubic_out), (expo_in, expo_out),
+            (elastic_in, elastic_out), (back_in, back_out), (bounce_in, bounce_out),
+        ];
+        for &(ease_in, ease_out) in pairs.iter() {
+            for i in 1..10 {
+                let t = i as f64 / 10.0;
+                assert!((ease_in(t) - (1.0 - ease_out(1.0 - t))).abs() < 1e-12);
+            }
+        }
+
+        let in_outs: [fn(f64) -> f64; 6] =
+            [quad_in_out, cubic_in_out, expo_in_out, elastic_in_out, back_in_out, bounce_in_out];
+        for &f in in_outs.iter() {
+            assert!((f(0.5) - 0.5).abs() < 1e-3);
+            for i in 1..10 {
+                let t = i as f64 / 20.0;
+                assert!((f(t) - (1.0 - f(1.0 - t))).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_values() {
+        assert_eq!(quad_in(0.5), 0.25);
+        assert_eq!(quad_out(0.5), 0.75);
+        assert_eq!(cubic_in(0.5f32), 0.125);
+        assert_eq!(cubic_in_out(0.25), 0.0625);
+        assert!((expo_in(0.5) - 2.0f64.powi(-5)).abs() < 1e-12);
+        assert!((bounce_out(1.0f64 / 2.75) - 1.0).abs() < 1e-12);
+        // The overshooting curves leave the unit interval
+        assert!(back_in(0.2) < 0.0);
+        assert!(back_out(0.8) > 1.0);
+        assert!(elastic_out(0.1) > 1.0);
+    }
+}
@@ -311,6 +311,26 @@ pub mod matrix3 {
         assert!(D.is_invertible());
 
         assert!(Matrix3::from_value(6.0f64).is_diagonal());
+
+        assert!(Matrix3::<f64>::identity().is_orthonormal());
+        assert!(Matrix3::from_angle_x(rad(1.0f64)).is_orthonormal());
+        assert!(!D.is_orthonormal());
+        assert!(!Matrix3::from_value(2.0f64).is_orthonormal());
+    }
+
+    #[test]
+    fn test_orthonormalize() {
+        let m = D.orthonormalize();
+        assert!(m.is_orthonormal());
+        assert_approx_eq!(m.x, D.x.normalize());
+        assert!(m.determinant() > 0.0);
+
+        let r = Matrix3::from_axis_angle(Vector3::new(1.0f64, 2.0, 3.0).normalize(), rad(0.5));
+        assert_approx_eq!(r.orthonormalize(), r);
+
+        let drifted = r + Matrix3::from_value(1.0e-3);
+        assert!(!drifted.is_orthonormal());
+        assert!(drifted.orthonormalize().is_orthonormal());
     }
 
     mod from_axis_x {
@@ -718,3 +738,98 @@ pub mod matrix4 {
         }
     }
 }
+
+pub mod format {
+    use cgmath::*;
+
+    #[test]
+    fn test_display() {
+        let m = Matrix2::new(1.0f64, 2.0,
+                             3.0, 4.0);
+        assert_eq!(format!("{}", m), "[[1, 3], [2, 4]]");
+        assert_eq!(format!("{:.1}", m), "[[1.0, 3.0], [2.0, 4.0]]");
+    }
+
+    #[test]
+    fn test_display_alternate() {
+        let m = Matrix3::new(1.0f64, 0.0, 0.0,
+                             0.0, 1.0, 0.0,
+                             -10.5, 2.0, 1.0);
+        assert_eq!(format!("{:#}", m), "[[1, 0, -10.5],\n [0, 1,     2],\n [0, 0,     1]]");
+        assert_eq!(format!("{:#.2}", m), "[[1.00, 0.00, -10.50],\n [0.00, 1.00,   2.00],\n [0.00, 0.00,   1.00]]");
+        assert_eq!(format!("{:#6}", Matrix2::new(1.0f64, 2.0, 3.0, 4.0)), "[[     1,      3],\n [     2,      4]]");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("[[1, 3], [2, 4]]".parse(), Ok(Matrix2::new(1.0f64, 2.0, 3.0, 4.0)));
+        assert!("[[1, 3], [2, 4]".parse::<Matrix2<f64>>().is_err());
+        assert!("[[1, 3], [2, 4], [5, 6]]".parse::<Matrix2<f64>>().is_err());
+        assert!("[[1, 3], [2, 4, 5]]".parse::<Matrix2<f64>>().is_err());
+        assert!("[[1, 3] 2, [4]]".parse::<Matrix2<f64>>().is_err());
+
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, -2.5, 3.0)) * Matrix4::from_scale(0.1);
+        assert_eq!(m.to_string().parse(), Ok(m));
+        assert_eq!(format!("{:#}", m).parse(), Ok(m));
+
+        let m = Matrix3::from([[1i32, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!("[[1, 4, 7], [2, 5, 8], [3, 6, 9]]".parse(), Ok(m));
+        assert_eq!(m.to_string().parse(), Ok(m));
+    }
+}
+
+pub mod arrays {
+    use cgmath::*;
+
+    #[test]
+    fn test_to_arrays() {
+        let m = Matrix2::new(1.0f64, 2.0,
+                             3.0, 4.0);
+        assert_eq!(m.to_cols_array(), [[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(m.to_rows_array(), [[1.0, 3.0], [2.0, 4.0]]);
+        assert_eq!(m.to_cols_array_flat(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(m.to_rows_array_flat(), [1.0, 3.0, 2.0, 4.0]);
+        assert_eq!(m.to_cols_array(), Into::<[[f64; 2]; 2]>::into(m));
+    }
+
+    #[test]
+    fn test_translation_rows() {
+        let m = Matrix4::from_translation(Vector3::new(1.0f32, 2.0, 3.0));
+        let rows = m.to_rows_array_flat();
+        assert_eq!(&rows[0..4], &[1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(&rows[4..8], &[0.0, 1.0, 0.0, 2.0]);
+        assert_eq!(&rows[8..12], &[0.0, 0.0, 1.0, 3.0]);
+        assert_eq!(&rows[12..16], &[0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(&m.to_cols_array_flat()[12..16], &[1.0, 2.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_from_slices() {
+        let m = Matrix3::new(1.0f64, 2.0, 3.0,
+                             4.0, 5.0, 6.0,
+                             7.0, 8.0, 9.0);
+        assert_eq!(Matrix3::from_cols_slice(&m.to_cols_array()), Ok(m));
+        assert_eq!(Matrix3::from_rows_slice(&m.to_rows_array()), Ok(m));
+        assert_eq!(Matrix3::from_cols_slice_flat(&m.to_cols_array_flat()), Ok(m));
+        assert_eq!(Matrix3::from_rows_slice_flat(&m.to_rows_array_flat()), Ok(m));
+        assert_eq!(Matrix3::from_rows_slice_flat(&[1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]), Ok(m));
+
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0));
+        assert_eq!(Matrix4::from_rows_slice_flat(&m.to_rows_array_flat()), Ok(m));
+        assert_eq!(Matrix4::from_rows_slice(&m.to_rows_array()), Ok(m));
+    }
+
+    #[test]
+    fn test_from_slices_wrong_length() {
+        let flat = [0.0f64; 17];
+        let err = Matrix4::from_cols_slice_flat(&flat).unwrap_err();
+        assert_eq!(err.expected(), 16);
+        assert_eq!(err.found(), 17);
+        assert_eq!(err.to_string(), "expected a slice of length 16, found 17");
+
+        assert!(Matrix4::from_rows_slice_flat(&flat[..15]).is_err());
+        assert!(Matrix2::from_cols_slice_flat(&[] as &[f64]).is_err());
+        assert!(Matrix2::from_rows_slice(&[[1.0f64, 2.0]]).is_err());
+        assert!(Matrix3::from_cols_slice(&[[1.0f64, 2.0, 3.0]; 4]).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use std::mem;
+
+use cgmath::*;
+use cgmath::pod::{self, Pod};
+
+#[test]
+fn test_sizes() {
+    assert_eq!(mem::size_of::<Vector3<f32>>(), 12);
+    assert_eq!(mem::size_of::<Point2<f64>>(), 16);
+    assert_eq!(mem::size_of::<Matrix3<f32>>(), 36);
+    assert_eq!(mem::size_of::<Matrix4<f64>>(), 128);
+    assert_eq!(mem::size_of::<Quaternion<f32>>(), 16);
+    assert_eq!(mem::size_of::<Euler<Deg<f32>>>(), 12);
+}
+
+#[test]
+fn test_value_as_bytes() {
+    let v = Vector2::new(1u16, 0x0203);
+    let expected = if cfg!(target_endian = "little") { [1, 0, 3, 2] } else { [0, 1, 2, 3] };
+    assert_eq!(v.as_bytes(), &expected);
+    assert_eq!(Rad::new(1.0f32).as_bytes(), 1.0f32.as_bytes());
+}
+
+#[test]
+fn test_slice_round_trip() {
+    let quats = [Quaternion::new(1.0f32, 2.0, 3.0, 4.0), Quaternion::new(5.0, 6.0, 7.0, 8.0)];
+    let bytes = pod::as_bytes(&quats);
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(&bytes[..4], 1.0f32.as_bytes());
+    assert_eq!(pod::cast_slice::<u8, Quaternion<f32>>(bytes), Some(&quats[..]));
+}
+
+#[test]
+fn test_matrix_columns() {
+    let floats = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    let matrices: &[Matrix2<f32>] = pod::cast_slice(&floats).unwrap();
+    assert_eq!(matrices, &[Matrix2::new(1.0, 2.0, 3.0, 4.0), Matrix2::new(5.0, 6.0, 7.0, 8.0)]);
+}
+
+#[test]
+fn test_cast_wrong_length() {
+    let floats = [1.0f32, 2.0, 3.0, 4.0];
+    assert_eq!(pod::cast_slice::<f32, Vector3<f32>>(&floats), None);
+    assert_eq!(pod::cast_slice::<f32, Vector2<f32>>(&floats).map(|v| v.len()), Some(2));
+}
+
+#[test]
+fn test_cast_misaligned() {
+    let words = [0u32; 4];
+    let bytes = pod::as_bytes(&words);
+    assert!(pod::cast_slice::<u8, u32>(&bytes[1..5]).is_none());
+    assert_eq!(pod::cast_slice::<u8, u32>(&bytes[4..8]), Some(&[0u32][..]));
+}
+
+#[test]
+fn test_cast_empty() {
+    let empty: [Vector4<f64>; 0] = [];
+    assert_eq!(pod::cast_slice::<Vector4<f64>, u8>(&empty), Some(&[][..]));
+}
+
+#[test]
+fn test_mutate_through_cast() {
+    let mut floats = [0.0f32; 6];
+    {
+        let points: &mut [Point3<f32>] = pod::cast_slice_mut(&mut floats).unwrap();
+        points[1] = Point3::new(1.0, 2.0, 3.0);
+    }
+    {
+        let bytes = pod::as_bytes_mut(&mut floats);
+        bytes[..4].copy_from_slice(10.0f32.as_bytes());
+    }
+    assert_eq!(floats, [10.0, 0.0, 0.0, 1.0, 2.0, 3.0]);
+}
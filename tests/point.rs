@@ -16,7 +16,7 @@
 
 extern crate cgmath;
 
-use cgmath::{Point2, Point3};
+use cgmath::{Point1, Point2, Point3};
 use cgmath::ApproxEq;
 
 macro_rules! impl_test_mul {
@@ -75,3 +75,15 @@ fn test_rem() {
     impl_test_rem!(Point2 { x, y }, 2.0f32, Point2::new(2.0f32, 4.0));
 }
 
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", Point1::new(1)), "[1]");
+    assert_eq!(format!("{:.1}", Point3::new(1.0f64, 2.0, 3.0)), "[1.0, 2.0, 3.0]");
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("[1.5, 2]".parse(), Ok(Point2::new(1.5f64, 2.0)));
+    assert!("[1.5, 2]".parse::<Point3<f64>>().is_err());
+}
@@ -15,7 +15,8 @@
 
 extern crate cgmath;
 
-use cgmath::{Vector4, ortho, Matrix4};
+use cgmath::{Vector4, ortho, Matrix4, Ortho, Perspective, PerspectiveFov, Rad};
+use cgmath::{Deg, frustum, frustum_lh, ortho_lh, perspective, perspective_lh};
 
 #[test]
 fn test_ortho_scale() {
@@ -67,3 +68,46 @@ fn test_ortho_translate() {
     let orig = o * vec_orig;
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+#[test]
+fn test_ortho_lh() {
+    // The left-handed projection looks along +z, so near maps to -1 and far
+    // maps to 1
+    let o: Matrix4<f32> = ortho_lh(-1., 1., -1., 1., 1., 3.);
+    assert_eq!(o * Vector4::new(0., 0., 1., 1.), Vector4::new(0f32, 0., -1., 1.));
+    assert_eq!(o * Vector4::new(0., 0., 3., 1.), Vector4::new(0f32, 0., 1., 1.));
+}
+
+#[test]
+fn test_perspective_lh() {
+    let rh: Matrix4<f64> = perspective(Deg::new(60.0), 1.5, 0.1, 100.0);
+    let lh: Matrix4<f64> = perspective_lh(Deg::new(60.0), 1.5, 0.1, 100.0);
+    let v = Vector4::new(1.0, -2.0, 5.0, 1.0);
+    assert_eq!(lh * v, rh * Vector4::new(1.0, -2.0, -5.0, 1.0));
+
+    let near = lh * Vector4::new(0.0, 0.0, 0.1, 1.0);
+    assert!((near.z / near.w + 1.0).abs() < 1e-10);
+    let far = lh * Vector4::new(0.0, 0.0, 100.0, 1.0);
+    assert!((far.z / far.w - 1.0).abs() < 1e-10);
+
+    let rh: Matrix4<f64> = frustum(-1.0, 2.0, -1.0, 1.0, 0.5, 10.0);
+    let lh: Matrix4<f64> = frustum_lh(-1.0, 2.0, -1.0, 1.0, 0.5, 10.0);
+    assert_eq!(lh * v, rh * Vector4::new(1.0, -2.0, -5.0, 1.0));
+}
+
+#[test]
+fn test_display_from_str() {
+    let persp = PerspectiveFov { fovy: Rad::new(1.5f64), aspect: 1.25, near: 0.1, far: 100.0 };
+    assert_eq!(format!("{}", persp), "[1.5 rad, 1.25, 0.1, 100]");
+    assert_eq!(persp.to_string().parse(), Ok(persp));
+    let parsed: PerspectiveFov<f64> = "[90°, 1.25, 0.1, 100]".parse().unwrap();
+    assert_eq!(parsed.fovy, Deg::new(90.0).into());
+
+    let persp = Perspective { left: -1.0f64, right: 1.0, bottom: -0.5, top: 0.5, near: 0.1, far: 100.0 };
+    assert_eq!(format!("{}", persp), "[-1, 1, -0.5, 0.5, 0.1, 100]");
+    assert_eq!(persp.to_string().parse(), Ok(persp));
+
+    let ortho = Ortho { left: 0i32, right: 640, bottom: 0, top: 480, near: -1, far: 1 };
+    assert_eq!(ortho.to_string().parse(), Ok(ortho));
+    assert!("[0, 640, 0, 480, -1]".parse::<Ortho<i32>>().is_err());
+}
@@ -256,3 +256,27 @@ mod rotate_from_axis_angle {
         assert_approx_eq!(vec3(-2.0f32.sqrt() / 2.0, 0.0, 2.0f32.sqrt() / 2.0), rot * vec);
     }
 }
+
+mod format {
+    use cgmath::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Quaternion::new(1.0f64, 2.0, -3.0, 4.5)), "1 + 2i - 3j + 4.5k");
+        assert_eq!(format!("{:.1}", Quaternion::new(-1.0f64, -0.0, 0.0, 1.0)), "-1.0 - 0.0i + 0.0j + 1.0k");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("1 + 2i - 3j + 4.5k".parse(), Ok(Quaternion::new(1.0f64, 2.0, -3.0, 4.5)));
+        assert_eq!("-2j+1e-2".parse(), Ok(Quaternion::new(0.01f64, 0.0, -2.0, 0.0)));
+        assert_eq!("3k - -1i".parse(), Ok(Quaternion::new(0.0f64, 1.0, 0.0, 3.0)));
+        assert!("".parse::<Quaternion<f64>>().is_err());
+        assert!("1 + 2i + 3i".parse::<Quaternion<f64>>().is_err());
+        assert!("1 + 2x".parse::<Quaternion<f64>>().is_err());
+        assert!("1 + ".parse::<Quaternion<f64>>().is_err());
+
+        let q = Quaternion::new(0.1f64, -1.0 / 3.0, 1e-20, -12345.678);
+        assert_eq!(q.to_string().parse(), Ok(q));
+    }
+}
@@ -45,3 +45,32 @@ fn test_invert_basis3() {
     let a: &Matrix3<_> = a.as_ref();
     assert!(a.is_identity());
 }
+
+#[test]
+fn test_orthonormalize_basis3() {
+    let a: Basis3<_> = rotation::a3();
+    assert!(a.is_orthonormal());
+
+    let mut b = a;
+    for _ in 0..1000 {
+        b = b * a * a.invert();
+    }
+    let b = b.orthonormalize();
+    assert!(b.is_orthonormal());
+    assert!(b.approx_eq(&a));
+}
+
+#[test]
+fn test_basis_from_str() {
+    let a: Basis2<f64> = rotation::a2();
+    assert_eq!(a.to_string().parse(), Ok(a));
+    let a: Basis3<f64> = rotation::a3();
+    assert_eq!(a.to_string().parse(), Ok(a));
+    assert_eq!(format!("{:#}", a).parse(), Ok(a));
+
+    let b: Basis2<f64> = "[[0, -1], [1, 0]]".parse().unwrap();
+    assert!(b.approx_eq(&Basis2::from_angle(Rad::turn_div_4())));
+    assert!("[[2, 0], [0, 2]]".parse::<Basis2<f64>>().is_err());
+    assert!("[[1, 1, 0], [0, 1, 0], [0, 0, 1]]".parse::<Basis3<f64>>().is_err());
+    assert!("[[1, 0], [0, 1]]".parse::<Basis3<f64>>().is_err());
+}
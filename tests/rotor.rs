@@ -0,0 +1,124 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate cgmath;
+
+use cgmath::*;
+
+fn axis() -> Vector3<f64> {
+    Vector3::new(1.0, -2.0, 3.0).normalize()
+}
+
+fn vectors() -> Vec<Vector3<f64>> {
+    vec![Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z(), Vector3::new(1.5, -0.5, 2.0)]
+}
+
+#[test]
+fn test_wedge() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(-4.0, 0.5, 2.0);
+
+    assert_eq!(a.wedge(b), -b.wedge(a));
+    assert_eq!(a.wedge(a), Bivector3::zero());
+    assert_eq!(a.wedge(b).axis(), a.cross(b));
+    assert_eq!(Vector3::<f64>::unit_x().wedge(Vector3::unit_y()), Bivector3::unit_xy());
+    assert_eq!(Vector3::<f64>::unit_y().wedge(Vector3::unit_z()), Bivector3::unit_yz());
+    assert_eq!(Vector3::<f64>::unit_z().wedge(Vector3::unit_x()), Bivector3::unit_zx());
+    assert_approx_eq!(a.wedge(b).magnitude(), a.cross(b).magnitude());
+}
+
+#[test]
+fn test_plane_angle() {
+    let r = Rotor3::from_plane_angle(Bivector3::unit_yz(), Rad::new(std::f64::consts::FRAC_PI_2));
+    assert_approx_eq!(r.rotate_vector(Vector3::unit_y()), Vector3::unit_z());
+    assert_approx_eq!(r.rotate_vector(Vector3::unit_x()), Vector3::unit_x());
+
+    let plane = Bivector3::from_axis(axis());
+    let r = Rotor3::from_plane_angle(plane, Rad::new(1.3));
+    let (p, angle) = r.to_plane_angle();
+    assert_approx_eq!(p, plane);
+    assert_approx_eq!(angle, Rad::new(1.3));
+}
+
+#[test]
+fn test_matches_quaternion() {
+    let q = Quaternion::from_axis_angle(axis(), Rad::new(2.1));
+    let r = Rotor3::from_axis_angle(axis(), Rad::new(2.1));
+
+    assert_approx_eq!(Rotor3::from(q), r);
+    assert_approx_eq!(Quaternion::from(r), q);
+    for v in vectors() {
+        assert_approx_eq!(r.rotate_vector(v), q.rotate_vector(v));
+        assert_approx_eq!(r * v, Matrix3::from(r) * v);
+        assert_approx_eq!(Basis3::from(r).rotate_vector(v), q.rotate_vector(v));
+    }
+
+    let euler = Euler { x: Rad::new(0.3), y: Rad::new(-1.1), z: Rad::new(2.0) };
+    assert_approx_eq!(Rotor3::from(euler), Rotor3::from(Quaternion::from(euler)));
+}
+
+#[test]
+fn test_composition() {
+    let a = Rotor3::from_axis_angle(axis(), Rad::new(0.8));
+    let b = Rotor3::from_axis_angle(Vector3::unit_y(), Rad::new(-2.3));
+
+    assert_approx_eq!(a * b, Rotor3::from(Quaternion::from(a) * Quaternion::from(b)));
+    for v in vectors() {
+        assert_approx_eq!((a * b).rotate_vector(v), a.rotate_vector(b.rotate_vector(v)));
+        assert_approx_eq!(a.invert().rotate_vector(a.rotate_vector(v)), v);
+    }
+    assert_approx_eq!(a * a.reverse(), Rotor3::one());
+}
+
+#[test]
+fn test_between_vectors() {
+    let a = Vector3::new(1.0, 2.0, -1.0).normalize();
+    for b in vectors() {
+        let b = b.normalize();
+        let r = Rotor3::between_vectors(a, b);
+        assert_approx_eq!(r.rotate_vector(a), b);
+        assert_approx_eq!(r, Rotor3::from(Quaternion::between_vectors(a, b)));
+    }
+}
+
+#[test]
+fn test_slerp() {
+    let a = Rotor3::from_axis_angle(axis(), Rad::new(0.2));
+    let b = Rotor3::from_axis_angle(axis(), Rad::new(1.4));
+
+    assert_approx_eq!(a.slerp(b, 0.0), a);
+    assert_approx_eq!(a.slerp(b, 1.0), b);
+    assert_approx_eq!(a.interpolate(b, 0.25), Rotor3::from_axis_angle(axis(), Rad::new(0.5)));
+}
+
+#[test]
+fn test_display_from_str() {
+    let b = Bivector3::new(1.0f64, 2.0, -3.5);
+    assert_eq!(format!("{}", b), "1xy + 2yz - 3.5zx");
+    assert_eq!("-3.5zx + 1xy + 2yz".parse(), Ok(b));
+    assert_eq!("2yz".parse(), Ok(Bivector3::new(0.0f64, 2.0, 0.0)));
+    assert!("1 + 2yz".parse::<Bivector3<f64>>().is_err());
+    assert!("1xy + 2xy".parse::<Bivector3<f64>>().is_err());
+
+    let r = Rotor3::new(0.5f64, Bivector3::new(0.5, -0.5, 1e-3));
+    assert_eq!(format!("{:.1}", r), "0.5 + 0.5xy - 0.5yz + 0.0zx");
+    assert_eq!("0.5 + 0.5xy - 0.5yz + 1e-3zx".parse(), Ok(r));
+    assert!("0.5 + 0.5i".parse::<Rotor3<f64>>().is_err());
+
+    let r = Rotor3::from_axis_angle(axis(), Rad::new(1.0));
+    assert_eq!(r.to_string().parse(), Ok(r));
+    assert_eq!(r.b.to_string().parse(), Ok(r.b));
+}
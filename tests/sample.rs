@@ -0,0 +1,279 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "rand")]
+
+#[macro_use]
+extern crate cgmath;
+extern crate rand;
+
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+use cgmath::*;
+use cgmath::sample;
+
+const N: usize = 10000;
+
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([1, 2, 3, 4])
+}
+
+#[test]
+fn test_unit_quaternion() {
+    let mut rng = rng();
+    let mut mean_sq = Vector4::zero();
+    for _ in 0..N {
+        let q: Quaternion<f64> = sample::unit_quaternion(&mut rng);
+        assert_approx_eq!(q.magnitude(), 1.0);
+        mean_sq += Vector4::new(q.s * q.s, q.v.x * q.v.x, q.v.y * q.v.y, q.v.z * q.v.z) / N as f64;
+    }
+    // Each component of a uniformly distributed rotation has E[c²] = 1/4
+    assert_approx_eq_eps!(mean_sq, Vector4::from_value(0.25), 0.01);
+}
+
+#[test]
+fn test_rand_quaternion() {
+    let q: Quaternion<f32> = rng().gen();
+    assert_approx_eq!(q.magnitude(), 1.0);
+}
+
+#[test]
+fn test_basis3() {
+    let mut rng = rng();
+    for _ in 0..100 {
+        let b: Basis3<f64> = sample::basis3(&mut rng);
+        assert!(b.is_orthonormal());
+    }
+}
+
+#[test]
+fn test_rand_euler() {
+    let mut rng = rng();
+    for _ in 0..100 {
+        let e: Euler<Deg<f64>> = rng.gen();
+        assert!(e.y >= deg(-90.0) && e.y <= deg(90.0));
+    }
+}
+
+#[test]
+fn test_on_unit_circle() {
+    let mut rng = rng();
+    let mut mean = Vector2::zero();
+    for _ in 0..N {
+        let v: Vector2<f64> = sample::on_unit_circle(&mut rng);
+        assert_approx_eq!(v.magnitude(), 1.0);
+        mean += v / N as f64;
+    }
+    assert_approx_eq_eps!(mean, Vector2::zero(), 0.02);
+}
+
+#[test]
+fn test_on_unit_sphere() {
+    let mut rng = rng();
+    let mut mean = Vector3::zero();
+    for _ in 0..N {
+        let v: Vector3<f64> = sample::on_unit_sphere(&mut rng);
+        assert_approx_eq!(v.magnitude(), 1.0);
+        mean += v / N as f64;
+    }
+    assert_approx_eq_eps!(mean, Vector3::zero(), 0.02);
+}
+
+#[test]
+fn test_in_unit_disk() {
+    let mut rng = rng();
+    let mut inner = 0;
+    for _ in 0..N {
+        let p: Point2<f64> = sample::in_unit_disk(&mut rng);
+        let r = p.to_vec().magnitude();
+        assert!(r <= 1.0);
+        if r < 0.5 { inner += 1; }
+    }
+    // The inner disk covers a quarter of the area
+    assert_approx_eq_eps!(inner as f64 / N as f64, 0.25, 0.02);
+}
+
+#[test]
+fn test_in_unit_ball() {
+    let mut rng = rng();
+    let mut inner = 0;
+    for _ in 0..N {
+        let p: Point3<f64> = sample::in_unit_ball(&mut rng);
+        let r = p.to_vec().magnitude();
+        assert!(r <= 1.0);
+        if r < 0.5 { inner += 1; }
+    }
+    // The inner ball covers an eighth of the volume
+    assert_approx_eq_eps!(inner as f64 / N as f64, 0.125, 0.02);
+}
+
+#[test]
+fn test_in_box() {
+    let mut rng = rng();
+    let (min, max) = (Point3::new(-1.0f64, 2.0, 3.0), Point3::new(1.0f64, 5.0, 4.0));
+    let mut mean = Vector3::zero();
+    for _ in 0..N {
+        let p = sample::in_box(&mut rng, min, max);
+        assert!(p.x >= min.x && p.y >= min.y && p.z >= min.z);
+        assert!(p.x < max.x && p.y < max.y && p.z < max.z);
+        mean += p.to_vec() / N as f64;
+    }
+    assert_approx_eq_eps!(mean, min.midpoint(max).to_vec(), 0.05);
+
+    let p = sample::in_rect(&mut rng, Point2::new(0.0f64, 0.0), Point2::new(1.0, 2.0));
+    assert!(p.x >= 0.0 && p.x < 1.0 && p.y >= 0.0 && p.y < 2.0);
+}
+
+#[test]
+fn test_in_triangle() {
+    let mut rng = rng();
+    let (a, b, c) = (Point2::new(0.0f64, 0.0), Point2::new(4.0, 0.0), Point2::new(0.0, 2.0));
+    let mut mean = Vector2::zero();
+    for _ in 0..N {
+        let p = sample::in_triangle(&mut rng, a, b, c);
+        assert!(p.x >= 0.0 && p.y >= 0.0);
+        assert!(p.x / 4.0 + p.y / 2.0 <= 1.0);
+        mean += p.to_vec() / N as f64;
+    }
+    assert_approx_eq_eps!(mean, Point2::centroid(&[a, b, c]).to_vec(), 0.05);
+}
+
+#[test]
+fn test_cosine_weighted_hemisphere() {
+    let mut rng = rng();
+    let mut mean_z = 0.0;
+    for _ in 0..N {
+        let v: Vector3<f64> = sample::cosine_weighted_hemisphere(&mut rng);
+        assert_approx_eq!(v.magnitude(), 1.0);
+        assert!(v.z >= 0.0);
+        mean_z += v.z / N as f64;
+    }
+    // E[cos θ] = 2/3 for a cosine-weighted hemisphere
+    assert_approx_eq_eps!(mean_z, 2.0 / 3.0, 0.01);
+}
+
+#[test]
+fn test_radical_inverse() {
+    let expected = [0.0, 0.5, 0.25, 0.75, 0.125, 0.625, 0.375, 0.875];
+    for (i, &x) in expected.iter().enumerate() {
+        assert_eq!(sample::radical_inverse::<f64>(2, i as u32), x);
+    }
+    assert_approx_eq!(sample::radical_inverse::<f64>(3, 1), 1.0 / 3.0);
+    assert_approx_eq!(sample::radical_inverse::<f64>(3, 5), 7.0 / 9.0);
+    assert_approx_eq!(sample::radical_inverse::<f64>(10, 123), 0.321);
+    assert!(sample::radical_inverse::<f32>(2, !0) < 1.0);
+}
+
+#[test]
+#[should_panic]
+fn test_radical_inverse_base_1() {
+    sample::radical_inverse::<f64>(1, 3);
+}
+
+#[test]
+fn test_halton() {
+    assert_approx_eq!(sample::halton2::<f64>(0), Vector2::new(0.0, 0.0));
+    assert_approx_eq!(sample::halton2::<f64>(1), Vector2::new(0.5, 1.0 / 3.0));
+    assert_approx_eq!(sample::halton2::<f64>(2), Vector2::new(0.25, 2.0 / 3.0));
+    assert_approx_eq!(sample::halton3::<f64>(3), Vector3::new(0.75, 1.0 / 9.0, 0.6));
+}
+
+#[test]
+fn test_hammersley() {
+    assert_approx_eq!(sample::hammersley2::<f64>(0, 4), Vector2::new(0.0, 0.0));
+    assert_approx_eq!(sample::hammersley2::<f64>(1, 4), Vector2::new(0.25, 0.5));
+    assert_approx_eq!(sample::hammersley2::<f64>(3, 4), Vector2::new(0.75, 0.75));
+    assert_approx_eq!(sample::hammersley3::<f64>(2, 4), Vector3::new(0.5, 0.25, 2.0 / 3.0));
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn test_hammersley_out_of_range() {
+    sample::hammersley2::<f64>(4, 4);
+}
+
+#[test]
+fn test_sobol() {
+    let expected = [
+        (0.0, 0.0, 0.0),
+        (0.5, 0.5, 0.5),
+        (0.25, 0.75, 0.75),
+        (0.75, 0.25, 0.25),
+        (0.125, 0.625, 0.375),
+        (0.625, 0.125, 0.875),
+        (0.375, 0.375, 0.625),
+        (0.875, 0.875, 0.125),
+    ];
+    for (i, &(x, y, z)) in expected.iter().enumerate() {
+        assert_eq!(sample::sobol2::<f64>(i as u32), Vector2::new(x, y));
+        assert_eq!(sample::sobol3::<f64>(i as u32), Vector3::new(x, y, z));
+    }
+}
+
+#[test]
+fn test_sequences_stratify_unit_square() {
+    // Every power of two prefix of these sequences places exactly one point
+    // in each row and column of a grid of that size.
+    let n = 64;
+    let sequences: [fn(u32) -> Vector2<f64>; 2] = [sample::sobol2, |i| sample::hammersley2(i, 64)];
+    for sequence in sequences.iter() {
+        let (mut rows, mut cols) = (vec![0; n], vec![0; n]);
+        for i in 0..n as u32 {
+            let p = sequence(i);
+            assert!(p.x >= 0.0 && p.x < 1.0 && p.y >= 0.0 && p.y < 1.0);
+            cols[(p.x * n as f64) as usize] += 1;
+            rows[(p.y * n as f64) as usize] += 1;
+        }
+        assert!(rows.iter().chain(cols.iter()).all(|&count| count == 1));
+    }
+}
+
+#[test]
+fn test_r2() {
+    assert_approx_eq!(sample::r2::<f64>(0), Vector2::new(0.5, 0.5));
+    let mut mean = Vector2::zero();
+    for i in 0..N as u32 {
+        let p: Vector2<f64> = sample::r2(i);
+        assert!(p.x >= 0.0 && p.x < 1.0 && p.y >= 0.0 && p.y < 1.0);
+        mean += p / N as f64;
+    }
+    assert_approx_eq_eps!(mean, Vector2::new(0.5, 0.5), 0.001);
+}
+
+#[test]
+fn test_square_to_unit_sphere() {
+    assert_approx_eq!(sample::square_to_unit_sphere(Vector2::new(0.0, 0.0)), Vector3::new(0.0, 0.0, 1.0));
+    assert_approx_eq!(sample::square_to_unit_sphere(Vector2::new(0.5, 0.25)), Vector3::new(0.0, 1.0, 0.0));
+    let mut mean = Vector3::zero();
+    for i in 0..1024 {
+        let v: Vector3<f64> = sample::square_to_unit_sphere(sample::sobol2(i));
+        assert_approx_eq!(v.magnitude(), 1.0);
+        mean += v / 1024.0;
+    }
+    assert_approx_eq_eps!(mean, Vector3::zero(), 0.01);
+}
+
+#[test]
+fn test_square_to_cosine_weighted_hemisphere() {
+    let mut mean_z = 0.0;
+    for i in 0..1024 {
+        let v: Vector3<f64> = sample::square_to_cosine_weighted_hemisphere(sample::halton2(i));
+        assert_approx_eq!(v.magnitude(), 1.0);
+        assert!(v.z >= 0.0);
+        mean_z += v.z / 1024.0;
+    }
+    assert_approx_eq_eps!(mean_z, 2.0 / 3.0, 0.01);
+}
@@ -0,0 +1,195 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "eders")]
+
+extern crate cgmath;
+extern crate serde;
+extern crate serde_json;
+
+use std::fmt::Debug;
+
+use serde::{Deserialize, Serialize};
+
+use cgmath::*;
+use cgmath::camera::{Arcball, FirstPerson, Orbit};
+use cgmath::compact::Compact;
+
+// Values are chosen to be exactly representable in decimal, as `serde_json`
+// does not always parse floats to the nearest value.
+fn round_trip<T: Serialize + Deserialize + PartialEq + Debug>(value: T) {
+    let json = serde_json::to_string(&value).unwrap();
+    let result: T = serde_json::from_str(&json).unwrap();
+    assert_eq!(result, value);
+}
+
+#[test]
+fn test_vectors() {
+    round_trip(Vector1::new(1.0f64));
+    round_trip(Vector2::new(1.0f64, 2.0));
+    round_trip(Vector3::new(1.0f64, 2.0, 3.0));
+    round_trip(Vector4::new(1.0f64, 2.0, 3.0, 4.0));
+    round_trip(Vector3::new(1i32, -2, 3));
+}
+
+#[test]
+fn test_points() {
+    round_trip(Point1::new(1.0f32));
+    round_trip(Point2::new(1.0f32, 2.0));
+    round_trip(Point3::new(1.0f32, 2.0, 3.0));
+}
+
+#[test]
+fn test_matrices() {
+    round_trip(Matrix2::new(1.0f64, 2.0, 3.0, 4.0));
+    round_trip(Matrix3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0));
+    round_trip(Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0)));
+}
+
+#[test]
+fn test_angles() {
+    round_trip(Rad::new(0.5f64));
+    round_trip(Deg::new(90.0f64));
+    round_trip(Turns::new(0.25f64));
+    round_trip(Grad::new(100.0f64));
+    round_trip(Euler::new(Deg::new(10.0f64), Deg::new(20.0), Deg::new(30.0)));
+}
+
+#[test]
+fn test_rotations() {
+    round_trip(Quaternion::new(0.5f64, 0.5, 0.5, 0.5));
+    round_trip(Basis2::look_at(Vector2::new(0.0f64, 1.0), Vector2::new(1.0, 0.0)));
+    round_trip(Basis3::look_at(Vector3::new(0.0f64, 0.0, 1.0), Vector3::new(1.0, 0.0, 0.0)));
+    round_trip(UnitComplex::new(0.0f64, 1.0));
+    round_trip(Rotor3::new(0.5f64, Bivector3::new(0.5, -0.5, 0.5)));
+    round_trip(DualQuaternion::from_translation(Vector3::new(1.0f64, 2.0, 3.0)));
+}
+
+#[test]
+fn test_projections() {
+    round_trip(PerspectiveFov { fovy: Rad::new(1.0f64), aspect: 1.5, near: 0.1, far: 100.0 });
+    round_trip(Perspective { left: -1.0f64, right: 1.0, bottom: -1.0, top: 1.0, near: 0.1, far: 100.0 });
+    round_trip(Ortho { left: -1.0f64, right: 1.0, bottom: -1.0, top: 1.0, near: 0.1, far: 100.0 });
+}
+
+#[test]
+fn test_conventions_and_cameras() {
+    round_trip(Convention::z_up_left_handed());
+
+    let mut first_person = FirstPerson::new(Point3::new(1.0f64, 2.0, 3.0), Rad::new(0.5), Rad::new(0.25));
+    first_person.max_pitch = Rad::new(1.5);
+    round_trip(first_person);
+
+    // Infinite distances can't be represented in JSON
+    let mut orbit = Orbit::new(Point3::new(1.0f64, 2.0, 3.0), 5.0, Rad::new(0.5), Rad::new(0.25));
+    orbit.max_pitch = Rad::new(1.5);
+    orbit.max_distance = 100.0;
+    round_trip(orbit);
+    round_trip(Arcball::new(Point3::new(1.0f64, 2.0, 3.0), 5.0));
+}
+
+#[test]
+fn test_splines_and_tracks() {
+    let p = |x| Point2::new(x, x * 2.0f64);
+    round_trip(CubicBezier { p0: p(0.0), p1: p(1.0), p2: p(2.0), p3: p(3.0) });
+    round_trip(CubicHermite { p0: p(0.0), m0: Vector2::unit_x(), p1: p(1.0), m1: Vector2::unit_y() });
+    round_trip(CatmullRom::centripetal(p(0.0), p(1.0), p(2.0), p(3.0)));
+
+    let track = Track::new(vec![0.0f64, 1.0], vec![Vector3::zero(), Vector3::unit_x()],
+                           Interpolation::Linear, Wrap::Loop).unwrap();
+    round_trip(track.clone());
+    round_trip(DecomposedTrack { scale: None, rot: None, disp: Some(track) });
+}
+
+#[test]
+fn test_invalid_tracks() {
+    let track = Track::new(vec![0.0f64, 1.0], vec![0.0f64, 2.0], Interpolation::Linear, Wrap::Clamp).unwrap();
+    let json = serde_json::to_string(&track).unwrap();
+    assert_eq!(serde_json::from_str::<Track<f64, f64>>(&json).unwrap(), track);
+
+    let parse = |times: &str, values: &str| {
+        let json = format!(r#"{{"times":{},"values":{},"interpolation":{{"Linear":[]}},"wrap":{{"Clamp":[]}}}}"#,
+                           times, values);
+        serde_json::from_str::<Track<f64, f64>>(&json)
+    };
+    assert!(parse("[0.0,1.0]", "[0.0,2.0]").is_ok());
+    assert!(parse("[]", "[]").is_err());
+    assert!(parse("[0.0,1.0]", "[0.0]").is_err());
+    assert!(parse("[1.0,0.0]", "[0.0,2.0]").is_err());
+}
+
+#[test]
+fn test_invalid_arc_length_tables() {
+    let table = ArcLengthTable::new(&CubicBezier::new(Point2::new(0.0f64, 0.0), Point2::new(0.0, 1.0),
+                                                      Point2::new(1.0, 1.0), Point2::new(1.0, 0.0)), 4);
+    let json = serde_json::to_string(&table).unwrap();
+    assert_eq!(serde_json::from_str::<ArcLengthTable<f64>>(&json).unwrap(), table);
+
+    assert!(serde_json::from_str::<ArcLengthTable<f64>>(r#"{"lengths":[0.0,0.5,1.5]}"#).is_ok());
+    assert!(serde_json::from_str::<ArcLengthTable<f64>>(r#"{"lengths":[]}"#).is_err());
+    assert!(serde_json::from_str::<ArcLengthTable<f64>>(r#"{"lengths":[0.0]}"#).is_err());
+    assert!(serde_json::from_str::<ArcLengthTable<f64>>(r#"{"lengths":[0.0,1.5,0.5]}"#).is_err());
+}
+
+#[test]
+fn test_format() {
+    assert_eq!(serde_json::to_string(&Vector3::new(1, 2, 3)).unwrap(), r#"{"x":1,"y":2,"z":3}"#);
+    assert_eq!(serde_json::to_string(&Rad::new(0.5f64)).unwrap(), r#"{"s":0.5}"#);
+    assert_eq!(serde_json::from_str::<Point2<i32>>(r#"{"y":2,"x":1}"#).unwrap(), Point2::new(1, 2));
+    assert!(serde_json::from_str::<Point2<i32>>(r#"{"x":1}"#).is_err());
+    assert!(serde_json::from_str::<Point2<i32>>(r#"{"x":1,"y":2,"z":3}"#).is_err());
+    assert_eq!(serde_json::to_string(&Wrap::Loop).unwrap(), r#"{"Loop":[]}"#);
+    assert_eq!(serde_json::from_str::<Handedness>(r#"{"Left":[]}"#).unwrap(), Handedness::Left);
+}
+
+fn compact_round_trip<T: Copy>(value: T, expected: &str) where
+    Compact<T>: Serialize + Deserialize + PartialEq + Debug,
+{
+    let json = serde_json::to_string(&Compact(value)).unwrap();
+    assert_eq!(json, expected);
+    let result: Compact<T> = serde_json::from_str(&json).unwrap();
+    assert_eq!(result, Compact(value));
+}
+
+#[test]
+fn test_compact() {
+    compact_round_trip(Vector2::new(1, 2), "[1,2]");
+    compact_round_trip(Vector4::new(1, 2, 3, 4), "[1,2,3,4]");
+    compact_round_trip(Point3::new(1, 2, 3), "[1,2,3]");
+    compact_round_trip(Matrix3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0),
+                       "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]");
+    compact_round_trip(Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0)),
+                       "[1.0,0.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,0.0,1.0,0.0,1.0,2.0,3.0,1.0]");
+    compact_round_trip(Quaternion::new(4.0f64, 1.0, 2.0, 3.0), "[1.0,2.0,3.0,4.0]");
+    compact_round_trip(Rad::new(0.5f64), "0.5");
+    compact_round_trip(Deg::new(90.0f64), "90.0");
+}
+
+#[test]
+fn test_compact_length() {
+    assert!(serde_json::from_str::<Compact<Vector3<i32>>>("[1,2]").is_err());
+    assert!(serde_json::from_str::<Compact<Vector3<i32>>>("[1,2,3,4]").is_err());
+    assert!(serde_json::from_str::<Compact<Matrix2<i32>>>("[[1,2],[3,4]]").is_err());
+}
+
+#[test]
+fn test_compact_functions() {
+    let mut json = Vec::new();
+    cgmath::compact::serialize(&Vector3::new(1, 2, 3), &mut serde_json::Serializer::new(&mut json)).unwrap();
+    assert_eq!(json, b"[1,2,3]");
+
+    let v: Vector3<i32> = cgmath::compact::deserialize(&mut serde_json::Deserializer::new(b"[1,2,3]".iter().map(|&b| Ok(b)))).unwrap();
+    assert_eq!(v, Vector3::new(1, 2, 3));
+}
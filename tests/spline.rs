@@ -0,0 +1,356 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate cgmath;
+
+use std::fmt;
+
+use cgmath::*;
+
+/// Check the derivatives of a curve against finite differences.
+fn check_derivatives<P, C>(curve: &C) where
+    P: ControlPoint<Scalar = f64> + ApproxEq<Epsilon = f64> + fmt::Debug,
+    P::Diff: ApproxEq<Epsilon = f64> + fmt::Debug,
+    C: Spline<P>,
+{
+    let h = 1e-6;
+    for i in 1..10 {
+        let t = i as f64 / 10.0;
+        let d = (curve.eval(t + h).to_diff() - curve.eval(t - h).to_diff()) / (2.0 * h);
+        assert_approx_eq_eps!(curve.derivative(t), d, 1e-5);
+        let dd = (curve.derivative(t + h) - curve.derivative(t - h)) / (2.0 * h);
+        assert_approx_eq_eps!(curve.second_derivative(t), dd, 1e-5);
+    }
+}
+
+/// Check that the halves of a split curve trace the original.
+fn check_split<P, A, B>(curve: &A, left: &B, right: &B, t: f64) where
+    P: ControlPoint<Scalar = f64> + ApproxEq<Epsilon = f64> + fmt::Debug,
+    A: Spline<P>,
+    B: Spline<P>,
+{
+    for i in 0..11 {
+        let u = i as f64 / 10.0;
+        assert_approx_eq!(left.eval(u), curve.eval(u * t));
+        assert_approx_eq!(right.eval(u), curve.eval(t + u * (1.0 - t)));
+    }
+}
+
+/// Check that the bounding box contains the curve, and is touched by it.
+fn check_bounding_box<P, C>(curve: &C) where
+    P: ControlPoint<Scalar = f64>,
+    C: Spline<P>,
+{
+    let (min, max) = curve.bounding_box();
+    let (min, max) = (min.to_diff(), max.to_diff());
+    let (mut lo, mut hi) = (max, min);
+    for i in 0..1001 {
+        let p = curve.eval(i as f64 / 1000.0).to_diff();
+        for j in 0..P::Diff::len() {
+            assert!(p[j] >= min[j] - 1e-12 && p[j] <= max[j] + 1e-12);
+            lo[j] = lo[j].min(p[j]);
+            hi[j] = hi[j].max(p[j]);
+        }
+    }
+    for j in 0..P::Diff::len() {
+        assert_approx_eq_eps!(lo[j], min[j], 1e-5);
+        assert_approx_eq_eps!(hi[j], max[j], 1e-5);
+    }
+}
+
+#[test]
+fn test_quadratic_bezier() {
+    let curve = QuadraticBezier::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(2.0, 0.0));
+    assert_approx_eq!(curve.eval(0.0), curve.p0);
+    assert_approx_eq!(curve.eval(0.5), Vector2::new(1.0, 1.0));
+    assert_approx_eq!(curve.eval(1.0), curve.p2);
+    assert_approx_eq!(curve.derivative(0.0), Vector2::new(2.0, 4.0));
+    assert_approx_eq!(curve.derivative(1.0), Vector2::new(2.0, -4.0));
+    check_derivatives(&curve);
+
+    let (left, right) = curve.split(0.3);
+    check_split(&curve, &left, &right, 0.3);
+
+    let (min, max) = curve.bounding_box();
+    assert_approx_eq!(min, Vector2::new(0.0, 0.0));
+    assert_approx_eq!(max, Vector2::new(2.0, 1.0));
+    check_bounding_box(&curve);
+}
+
+#[test]
+fn test_quadratic_bezier_to_cubic() {
+    let curve = QuadraticBezier::new(Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 2.0, 3.0), Point3::new(2.0, 0.0, -1.0));
+    let cubic = curve.to_cubic();
+    for i in 0..11 {
+        let t = i as f64 / 10.0;
+        assert_approx_eq!(cubic.eval(t), curve.eval(t));
+    }
+}
+
+#[test]
+fn test_cubic_bezier() {
+    let curve = CubicBezier::new(Point2::new(0.0, 0.0), Point2::new(0.0, 1.0),
+                                 Point2::new(1.0, 1.0), Point2::new(1.0, 0.0));
+    assert_approx_eq!(curve.eval(0.0), curve.p0);
+    assert_approx_eq!(curve.eval(0.5), Point2::new(0.5, 0.75));
+    assert_approx_eq!(curve.eval(1.0), curve.p3);
+    assert_approx_eq!(curve.derivative(0.0), Vector2::new(0.0, 3.0));
+    assert_approx_eq!(curve.derivative(1.0), Vector2::new(0.0, -3.0));
+    check_derivatives(&curve);
+
+    let (left, right) = curve.split(0.6);
+    check_split(&curve, &left, &right, 0.6);
+
+    let (min, max) = curve.bounding_box();
+    assert_approx_eq!(min, Point2::new(0.0, 0.0));
+    assert_approx_eq!(max, Point2::new(1.0, 0.75));
+    check_bounding_box(&curve);
+}
+
+#[test]
+fn test_cubic_bezier_bounding_box() {
+    let curve = CubicBezier::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(-1.0, 3.0, 1.0),
+                                 Vector3::new(2.0, -3.0, 1.0), Vector3::new(1.0, 0.0, 0.0));
+    check_bounding_box(&curve);
+
+    // A curve whose control points all lie on a line
+    let line = CubicBezier::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0),
+                                Vector2::new(2.0, 2.0), Vector2::new(3.0, 3.0));
+    let (min, max) = line.bounding_box();
+    assert_approx_eq!(min, Vector2::new(0.0, 0.0));
+    assert_approx_eq!(max, Vector2::new(3.0, 3.0));
+}
+
+#[test]
+fn test_cubic_hermite() {
+    let curve = CubicHermite::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 0.0),
+                                  Point3::new(1.0, 0.0, 1.0), Vector3::new(0.0, -1.0, 3.0));
+    assert_approx_eq!(curve.eval(0.0), curve.p0);
+    assert_approx_eq!(curve.eval(1.0), curve.p1);
+    assert_approx_eq!(curve.derivative(0.0), curve.m0);
+    assert_approx_eq!(curve.derivative(1.0), curve.m1);
+    check_derivatives(&curve);
+
+    let (left, right) = curve.split(0.25);
+    check_split(&curve, &left, &right, 0.25);
+    check_bounding_box(&curve);
+
+    let bezier = curve.to_bezier();
+    for i in 0..11 {
+        let t = i as f64 / 10.0;
+        assert_approx_eq!(bezier.eval(t), curve.eval(t));
+    }
+}
+
+#[test]
+fn test_catmull_rom_uniform() {
+    let (p0, p1, p2, p3) = (Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0),
+                            Vector2::new(3.0, 1.0), Vector2::new(4.0, 0.0));
+    let curve = CatmullRom::uniform(p0, p1, p2, p3);
+    assert_approx_eq!(curve.eval(0.0), p1);
+    assert_approx_eq!(curve.eval(1.0), p2);
+    assert_approx_eq!(curve.derivative(0.0), (p2 - p0) / 2.0);
+    assert_approx_eq!(curve.derivative(1.0), (p3 - p1) / 2.0);
+    check_derivatives(&curve);
+    check_bounding_box(&curve);
+
+    let (left, right) = curve.split(0.5);
+    check_split(&curve, &left, &right, 0.5);
+}
+
+#[test]
+fn test_catmull_rom_centripetal() {
+    let (p0, p1, p2, p3) = (Point2::new(0.0, 0.0), Point2::new(0.0, 1.0),
+                            Point2::new(10.0, 1.0), Point2::new(10.0, 0.0));
+    let curve = CatmullRom::centripetal(p0, p1, p2, p3);
+    assert_approx_eq!(curve.eval(0.0), p1);
+    assert_approx_eq!(curve.eval(1.0), p2);
+    check_derivatives(&curve);
+    check_bounding_box(&curve);
+
+    let (left, right) = curve.split(0.7);
+    check_split(&curve, &left, &right, 0.7);
+
+    // Segments that share points join with matching tangents
+    let next = CatmullRom::centripetal(p1, p2, p3, Point2::new(5.0, -2.0));
+    assert_approx_eq!(curve.derivative(1.0) / curve.to_hermite().m1.magnitude(),
+                      next.derivative(0.0) / next.to_hermite().m0.magnitude());
+
+    // Coincident points do not produce a degenerate curve
+    let curve = CatmullRom::centripetal(p1, p1, p2, p2);
+    assert_approx_eq!(curve.eval(0.0), p1);
+    assert_approx_eq!(curve.eval(1.0), p2);
+}
+
+#[test]
+fn test_uniform_b_spline() {
+    let (p0, p1, p2, p3) = (Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0),
+                            Vector2::new(3.0, 2.0), Vector2::new(4.0, 0.0));
+    let curve = UniformBSpline::new(p0, p1, p2, p3);
+    assert_approx_eq!(curve.eval(0.0), (p0 + p1 * 4.0 + p2) / 6.0);
+    assert_approx_eq!(curve.eval(1.0), (p1 + p2 * 4.0 + p3) / 6.0);
+    check_derivatives(&curve);
+    check_bounding_box(&curve);
+
+    let (left, right) = curve.split(0.4);
+    check_split(&curve, &left, &right, 0.4);
+
+    // Consecutive segments join with continuous second derivatives
+    let next = UniformBSpline::new(p1, p2, p3, Vector2::new(6.0, 1.0));
+    assert_approx_eq!(curve.eval(1.0), next.eval(0.0));
+    assert_approx_eq!(curve.derivative(1.0), next.derivative(0.0));
+    assert_approx_eq!(curve.second_derivative(1.0), next.second_derivative(0.0));
+
+    let round_trip = UniformBSpline::from_bezier(&curve.to_bezier());
+    assert_approx_eq!(round_trip.p0, p0);
+    assert_approx_eq!(round_trip.p1, p1);
+    assert_approx_eq!(round_trip.p2, p2);
+    assert_approx_eq!(round_trip.p3, p3);
+}
+
+#[test]
+fn test_arc_length() {
+    // A straight line traversed at a varying speed
+    let line = CubicBezier::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 0.0),
+                                Point3::new(1.0, 2.0, 2.0), Point3::new(1.0, 2.0, 2.0));
+    assert_approx_eq!(line.arc_length(), 3.0);
+    assert_approx_eq!(line.arc_length_between(0.0, 0.5), 1.5);
+
+    // A cubic approximation of a quarter circle
+    let k = 0.5522847498;
+    let arc = CubicBezier::new(Vector2::new(1.0, 0.0), Vector2::new(1.0, k),
+                               Vector2::new(k, 1.0), Vector2::new(0.0, 1.0));
+    assert_approx_eq_eps!(arc.arc_length(), std::f64::consts::FRAC_PI_2, 1e-3);
+
+    // The lengths of the halves of a split curve add up
+    let curve = CatmullRom::centripetal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0),
+                                        Vector3::new(3.0, 1.0, 2.0), Vector3::new(4.0, 0.0, 0.0));
+    let (left, right) = curve.split(0.3);
+    assert_approx_eq!(left.arc_length() + right.arc_length(), curve.arc_length());
+}
+
+#[test]
+fn test_arc_length_table() {
+    let curve = CubicBezier::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0),
+                                 Point3::new(1.0, 1.0, 0.0), Point3::new(1.0, 0.0, 1.0));
+    let table = ArcLengthTable::new(&curve, 256);
+    assert_approx_eq!(table.length(), curve.arc_length());
+
+    assert_eq!(table.parameter(-1.0), 0.0);
+    assert_eq!(table.parameter(0.0), 0.0);
+    assert_eq!(table.parameter(table.length()), 1.0);
+    assert_eq!(table.parameter(table.length() + 1.0), 1.0);
+    assert_eq!(table.distance(0.0), 0.0);
+    assert_eq!(table.distance(1.0), table.length());
+
+    for i in 1..10 {
+        let distance = table.length() * i as f64 / 10.0;
+        let t = table.parameter(distance);
+        assert_approx_eq_eps!(curve.arc_length_between(0.0, t), distance, 1e-4);
+        assert_approx_eq_eps!(table.distance(t), distance, 1e-12);
+    }
+}
+
+#[test]
+fn test_frenet_frame() {
+    // A helix, approximated around its start
+    let curve = CubicHermite::new(Point3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 1.0),
+                                  Point3::new(0.0, 1.0, 1.0), Vector3::new(-1.0, 0.0, 1.0));
+    for i in 0..11 {
+        let t = i as f64 / 10.0;
+        let frame = curve.frenet_frame(t).unwrap();
+        assert!(frame.is_orthonormal());
+
+        let tangent = frame.rotate_vector(Vector3::unit_x());
+        let normal = frame.rotate_vector(Vector3::unit_y());
+        assert_approx_eq!(tangent, curve.derivative(t).normalize());
+        // The normal points towards the side that the curve is turning
+        assert!(normal.dot(curve.second_derivative(t)) > 0.0);
+        assert_approx_eq!(normal.dot(tangent), 0.0);
+    }
+
+    let line = CubicBezier::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0),
+                                Vector3::new(2.0, 0.0, 0.0), Vector3::new(3.0, 0.0, 0.0));
+    assert!(line.frenet_frame(0.5).is_none());
+}
+
+#[test]
+fn test_frenet_frame_small_scale() {
+    // The same helix, a thousand times smaller
+    let scale = 1.0e-3;
+    let curve = CubicHermite::new(Point3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 1.0),
+                                  Point3::new(0.0, 1.0, 1.0), Vector3::new(-1.0, 0.0, 1.0));
+    let small = CubicHermite::new(Point3::from_vec(curve.p0.to_vec() * scale), curve.m0 * scale,
+                                  Point3::from_vec(curve.p1.to_vec() * scale), curve.m1 * scale);
+    for i in 0..11 {
+        let t = i as f64 / 10.0;
+        assert_approx_eq!(small.frenet_frame(t).unwrap(), curve.frenet_frame(t).unwrap());
+    }
+
+    let line = CubicBezier::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0e-3, 0.0, 0.0),
+                                Vector3::new(3.0e-3, 0.0, 0.0), Vector3::new(6.0e-3, 0.0, 0.0));
+    assert!(line.frenet_frame(0.5).is_none());
+}
+
+#[test]
+fn test_rotation_minimizing_frames() {
+    let curve = CubicBezier::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0),
+                                 Point3::new(2.0, 2.0, 1.0), Point3::new(2.0, 0.0, 3.0));
+    let table = ArcLengthTable::new(&curve, 64);
+    let ts: Vec<f64> = (0..201).map(|i| table.parameter(table.length() * i as f64 / 200.0)).collect();
+    let frames = curve.rotation_minimizing_frames(Vector3::new(1.0, 0.0, 1.0), &ts);
+    assert_eq!(frames.len(), ts.len());
+
+    let first_normal = frames[0].rotate_vector(Vector3::unit_y());
+    assert_approx_eq!(first_normal, Vector3::new(1.0, 0.0, 1.0).normalize());
+
+    for (frame, &t) in frames.iter().zip(ts.iter()) {
+        assert!(frame.is_orthonormal());
+        let tangent = frame.rotate_vector(Vector3::unit_x());
+        assert_approx_eq!(tangent, curve.derivative(t).normalize());
+    }
+
+    // The frames do not twist around the tangent
+    for w in frames.windows(2) {
+        let (n0, n1) = (w[0].rotate_vector(Vector3::unit_y()), w[1].rotate_vector(Vector3::unit_y()));
+        let t1 = w[1].rotate_vector(Vector3::unit_x());
+        let twist = n0.cross(n1).dot(t1);
+        assert!(twist.abs() < 1e-4);
+    }
+
+    // Along a planar curve the normal stays perpendicular to the plane
+    let planar = QuadraticBezier::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 0.0), Vector3::new(2.0, 0.0, 0.0));
+    let ts: Vec<f64> = (0..11).map(|i| i as f64 / 10.0).collect();
+    for frame in planar.rotation_minimizing_frames(Vector3::unit_z(), &ts) {
+        assert_approx_eq!(frame.rotate_vector(Vector3::unit_y()), Vector3::unit_z());
+    }
+
+    assert!(curve.rotation_minimizing_frames(Vector3::unit_z(), &[]).is_empty());
+}
+
+#[test]
+fn test_rotation_minimizing_frames_cusp() {
+    // The curve stops moving at its midpoint
+    let curve = CubicBezier::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                                 Point3::new(1.0, 0.0, 1.0), Point3::new(0.0, 0.0, -1.0));
+    assert_eq!(curve.derivative(0.5), Vector3::zero());
+
+    let ts: Vec<f64> = (0..11).map(|i| i as f64 / 10.0).collect();
+    for frame in curve.rotation_minimizing_frames(Vector3::unit_y(), &ts) {
+        assert!(frame.is_orthonormal());
+        assert_approx_eq!(frame.rotate_vector(Vector3::unit_y()), Vector3::unit_y());
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate cgmath;
+
+use cgmath::*;
+
+fn linear_track(wrap: Wrap) -> Track<f64, Vector3<f64>> {
+    Track::new(vec![1.0, 2.0, 4.0],
+               vec![Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 0.0, 0.0), Vector3::new(2.0, 4.0, 0.0)],
+               Interpolation::Linear, wrap).unwrap()
+}
+
+#[test]
+fn test_new() {
+    let v = Vector2::new(0.0f64, 0.0);
+    let empty: Option<Track<f64, Vector2<f64>>> = Track::new(vec![], vec![], Interpolation::Linear, Wrap::Clamp);
+    assert!(empty.is_none());
+    assert!(Track::new(vec![0.0], vec![v, v], Interpolation::Linear, Wrap::Clamp).is_none());
+    assert!(Track::new(vec![0.0, 1.0], vec![v, v], Interpolation::CubicSpline, Wrap::Clamp).is_none());
+    assert!(Track::new(vec![1.0, 0.0], vec![v, v], Interpolation::Step, Wrap::Clamp).is_none());
+    assert!(Track::new(vec![0.0, 0.0], vec![v, v], Interpolation::Step, Wrap::Clamp).is_none());
+    assert!(Track::new(vec![0.0, 1.0], vec![v; 6], Interpolation::CubicSpline, Wrap::Clamp).is_some());
+
+    let track = linear_track(Wrap::Clamp);
+    assert_eq!(track.start_time(), 1.0);
+    assert_eq!(track.end_time(), 4.0);
+    assert_eq!(track.duration(), 3.0);
+    assert_eq!(track.times(), &[1.0, 2.0, 4.0]);
+    assert_eq!(track.values().len(), 3);
+    assert_eq!(track.interpolation(), Interpolation::Linear);
+}
+
+#[test]
+fn test_step() {
+    let track = Track::new(vec![0.0, 1.0, 2.0], vec![1.0, 5.0, -2.0], Interpolation::Step, Wrap::Clamp).unwrap();
+    assert_eq!(track.sample(-1.0), 1.0);
+    assert_eq!(track.sample(0.0), 1.0);
+    assert_eq!(track.sample(0.99), 1.0);
+    assert_eq!(track.sample(1.0), 5.0);
+    assert_eq!(track.sample(1.5), 5.0);
+    assert_eq!(track.sample(2.0), -2.0);
+    assert_eq!(track.sample(10.0), -2.0);
+}
+
+#[test]
+fn test_linear() {
+    let track = linear_track(Wrap::Clamp);
+    assert_eq!(track.sample(0.0), Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(track.sample(1.5), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(track.sample(2.0), Vector3::new(2.0, 0.0, 0.0));
+    assert_eq!(track.sample(3.0), Vector3::new(2.0, 2.0, 0.0));
+    assert_eq!(track.sample(5.0), Vector3::new(2.0, 4.0, 0.0));
+}
+
+#[test]
+fn test_linear_quaternion() {
+    let a = Quaternion::from_angle_y(Rad::new(0.0f64));
+    let b = Quaternion::from_angle_y(Rad::new(1.0));
+    let track = Track::new(vec![0.0, 2.0], vec![a, -b], Interpolation::Linear, Wrap::Clamp).unwrap();
+    let q = track.sample(1.0);
+    assert_approx_eq!(q.rotate_vector(Vector3::unit_z()),
+                      Quaternion::from_angle_y(Rad::new(0.5)).rotate_vector(Vector3::unit_z()));
+}
+
+#[test]
+fn test_loop() {
+    let track = linear_track(Wrap::Loop);
+    for &t in [1.0, 1.5, 2.0, 3.0, 3.9].iter() {
+        assert_approx_eq!(track.sample(t + 3.0), track.sample(t));
+        assert_approx_eq!(track.sample(t - 3.0), track.sample(t));
+        assert_approx_eq!(track.sample(t + 30.0), track.sample(t));
+    }
+    // The end of the loop wraps around to the start
+    assert_eq!(track.sample(4.0), track.sample(1.0));
+
+    let single = Track::new(vec![1.0], vec![3.0], Interpolation::Linear, Wrap::Loop).unwrap();
+    assert_eq!(single.sample(0.0), 3.0);
+    assert_eq!(single.sample(5.0), 3.0);
+}
+
+#[test]
+fn test_cubic_spline() {
+    // Keyframes of `f(t) = t^3` at t = 0, 1 and 3, with exact tangents
+    let f = |t: f64| t * t * t;
+    let df = |t: f64| 3.0 * t * t;
+    let times = vec![0.0, 1.0, 3.0];
+    let values = times.iter().flat_map(|&t| vec![df(t), f(t), df(t)]).collect();
+    let track = Track::new(times, values, Interpolation::CubicSpline, Wrap::Clamp).unwrap();
+
+    for i in 0..31 {
+        let t = i as f64 / 10.0;
+        assert_approx_eq!(track.sample(t), f(t));
+    }
+    assert_eq!(track.sample(-1.0), 0.0);
+    assert_eq!(track.sample(4.0), 27.0);
+}
+
+#[test]
+fn test_cubic_spline_tangents() {
+    // The in-tangent of the next keyframe controls the arrival, and the
+    // out-tangent of the previous keyframe the departure
+    let zero = Vector2::new(0.0, 0.0);
+    let track = Track::new(vec![0.0, 2.0],
+                           vec![Vector2::new(100.0, 100.0), zero, Vector2::new(1.0, 0.0),
+                                Vector2::new(0.0, 1.0), Vector2::new(2.0, 2.0), Vector2::new(100.0, 100.0)],
+                           Interpolation::CubicSpline, Wrap::Clamp).unwrap();
+    let h = 1e-6;
+    assert_approx_eq!((track.sample(h) - track.sample(0.0)) / h, Vector2::new(1.0, 0.0));
+    assert_approx_eq!((track.sample(2.0) - track.sample(2.0 - h)) / h, Vector2::new(0.0, 1.0));
+}
+
+#[test]
+fn test_decomposed_track() {
+    let rot = Track::new(vec![0.0, 1.0],
+                         vec![Quaternion::one(), Quaternion::from_angle_z(Rad::new(1.0))],
+                         Interpolation::Linear, Wrap::Clamp).unwrap();
+    let scale = Track::new(vec![0.0, 2.0], vec![1.0, 3.0], Interpolation::Linear, Wrap::Clamp).unwrap();
+    let pose = DecomposedTrack { scale: Some(scale), rot: Some(rot), disp: None };
+
+    let transform = pose.sample(0.5);
+    assert_approx_eq!(transform.scale, 1.5);
+    assert_approx_eq!(transform.rot, Quaternion::from_angle_z(Rad::new(0.5)));
+    assert_eq!(transform.disp, Vector3::zero());
+    assert_eq!(pose.end_time(), 2.0);
+
+    let empty: DecomposedTrack<f32> = DecomposedTrack { scale: None, rot: None, disp: None };
+    let transform = empty.sample(1.0);
+    assert_eq!(transform.scale, 1.0);
+    assert_eq!(transform.rot, Quaternion::one());
+    assert_eq!(transform.disp, Vector3::zero());
+}
+
+#[test]
+fn test_decomposed_track_normalizes_rotation() {
+    let (a, b) = (Quaternion::one(), Quaternion::from_angle_x(Rad::new(2.0f64)));
+    let zero = Quaternion::zero();
+    let rot = Track::new(vec![0.0, 1.0], vec![zero, a, zero, zero, b, zero],
+                         Interpolation::CubicSpline, Wrap::Clamp).unwrap();
+    let pose = DecomposedTrack { scale: None, rot: Some(rot), disp: None };
+    assert_approx_eq!(pose.sample(0.5).rot.magnitude(), 1.0);
+}
@@ -59,3 +59,24 @@ fn test_serialize() {
 
     assert!(t.approx_eq(&deserialized));
 }
+
+#[test]
+fn test_display_from_str() {
+    let t = Decomposed {
+        scale: 2.0f64,
+        rot: Quaternion::new(0.5f64, 0.5, -0.5, 0.5),
+        disp: Vector3::new(1.0f64, 2.0, 3.0),
+    };
+    assert_eq!(format!("{}", t), "[2, 0.5 + 0.5i - 0.5j + 0.5k, [1, 2, 3]]");
+    let parsed: Decomposed<Vector3<f64>, Quaternion<f64>> = t.to_string().parse().unwrap();
+    assert!(parsed.approx_eq(&t));
+    assert!("[2, 1, [1, 2]]".parse::<Decomposed<Vector3<f64>, Quaternion<f64>>>().is_err());
+
+    let t = Decomposed {
+        scale: 1.0f64 / 3.0,
+        rot: Basis2::from_angle(Rad::new(0.5f64)),
+        disp: Vector2::new(-1.0f64, 0.25),
+    };
+    let parsed: Decomposed<Vector2<f64>, Basis2<f64>> = t.to_string().parse().unwrap();
+    assert!(parsed.approx_eq(&t));
+}
@@ -0,0 +1,110 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate cgmath;
+
+use std::mem;
+
+use cgmath::*;
+
+enum Object {}
+enum World {}
+enum View {}
+
+fn model() -> TypedTransform<f64, Object, World> {
+    TypedTransform::from_transform(Decomposed {
+        scale: 2.0,
+        rot: Quaternion::from_angle_y(Rad::new(0.5)),
+        disp: Vector3::new(1.0, 2.0, 3.0),
+    })
+}
+
+fn view() -> TypedTransform<f64, World, View> {
+    TypedTransform::from_untyped(Matrix4::look_at(Point3::new(0.0, 5.0, 10.0),
+                                                  Point3::origin(),
+                                                  Vector3::unit_y()))
+}
+
+#[test]
+fn test_zero_cost() {
+    assert_eq!(mem::size_of::<TypedPoint3<f32, World>>(), mem::size_of::<Point3<f32>>());
+    assert_eq!(mem::size_of::<TypedVector3<f32, World>>(), mem::size_of::<Vector3<f32>>());
+    assert_eq!(mem::size_of::<TypedTransform<f32, Object, World>>(), mem::size_of::<Matrix4<f32>>());
+}
+
+#[test]
+fn test_conversions() {
+    let p: TypedPoint3<f64, World> = Point3::new(1.0, 2.0, 3.0).into();
+    assert_eq!(Point3::from(p), Point3::new(1.0, 2.0, 3.0));
+    assert_eq!(p.to_untyped(), Point3::new(1.0, 2.0, 3.0));
+
+    let v: TypedVector3<f64, World> = TypedVector3::from_untyped(Vector3::new(4.0, 5.0, 6.0));
+    assert_eq!(Vector3::from(v), Vector3::new(4.0, 5.0, 6.0));
+    assert_eq!(TypedPoint3::from_vec(p.to_vec()), p);
+}
+
+#[test]
+fn test_arithmetic() {
+    let p: TypedPoint3<f64, World> = TypedPoint3::new(1.0, 2.0, 3.0);
+    let q = TypedPoint3::new(4.0, 6.0, 3.0);
+
+    assert_eq!(q - p, TypedVector3::new(3.0, 4.0, 0.0));
+    assert_eq!(p + (q - p), q);
+    assert_eq!(q - (q - p), p);
+    assert_eq!(p.distance(q), 5.0);
+    assert_eq!((q - p).magnitude(), 5.0);
+    assert_eq!((q - p) * 2.0, TypedVector3::new(6.0, 8.0, 0.0));
+    assert_eq!(-(q - p), p - q);
+    assert_eq!((q - p).dot(TypedVector3::new(1.0, 1.0, 1.0)), 7.0);
+}
+
+#[test]
+fn test_transform() {
+    let p: TypedPoint3<f64, Object> = TypedPoint3::new(1.0, -1.0, 0.5);
+    let v: TypedVector3<f64, Object> = TypedVector3::new(0.0, 1.0, 2.0);
+
+    let world = model().transform_point(p);
+    let view_point = view().transform_point(world);
+    let untyped = Matrix4::from(view()) * Matrix4::from(model());
+
+    assert_approx_eq!(view_point.to_untyped(), untyped.transform_point(p.to_untyped()));
+    assert_approx_eq!((view() * model()).transform_point(p), view_point);
+    assert_approx_eq!(model().then(view()).transform_point(p), view_point);
+    assert_approx_eq!((view() * model()).transform_vector(v).to_untyped(),
+                      untyped.transform_vector(v.to_untyped()));
+}
+
+#[test]
+fn test_inverse() {
+    let p: TypedPoint3<f64, Object> = TypedPoint3::new(1.0, -1.0, 0.5);
+    let inverse: TypedTransform<f64, World, Object> = model().inverse().unwrap();
+
+    assert_approx_eq!(inverse.transform_point(model().transform_point(p)), p);
+    assert_approx_eq!(inverse * model(), TypedTransform::identity());
+}
+
+#[test]
+fn test_display_from_str() {
+    let p: TypedPoint3<f64, World> = TypedPoint3::new(1.0, -2.0, 3.5);
+    assert_eq!(format!("{}", p), "[1, -2, 3.5]");
+    assert_eq!(p.to_string().parse(), Ok(p));
+
+    let v: TypedVector3<f64, View> = TypedVector3::new(0.5, 0.0, -1.0);
+    assert_eq!(v.to_string().parse(), Ok(v));
+
+    let m = model();
+    assert_eq!(m.to_string().parse(), Ok(m));
+}
@@ -0,0 +1,158 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+use cgmath::pod::Pod;
+use cgmath::uniform::{Block, Layout, Uniform};
+
+fn bytes_at<T: Pod>(block: &Block, offset: usize) -> &[u8] {
+    &block.as_bytes()[offset..offset + std::mem::size_of::<T>()]
+}
+
+/// The example std140 uniform block from the `ARB_uniform_buffer_object`
+/// extension, with the offsets given in the specification.
+#[test]
+fn test_std140_specification_example() {
+    // struct { int d; bvec2 e; } f;
+    let mut f = Block::std140();
+    assert_eq!(f.push(&1i32), 0);
+    assert_eq!(f.push(&Vector2::new(1u32, 0)), 8);
+    assert_eq!(f.alignment(), 16);
+
+    // struct { uvec3 j; vec2 k; float l[2]; vec2 m; mat3 n[2]; } o;
+    let mut o = Block::std140();
+    assert_eq!(o.push(&Vector3::new(1u32, 2, 3)), 0);
+    assert_eq!(o.push(&Vector2::new(1.0f32, 2.0)), 16);
+    assert_eq!(o.push_array(&[1.0f32, 2.0]), 32);
+    assert_eq!(o.push(&Vector2::new(1.0f32, 2.0)), 64);
+    assert_eq!(o.push_array(&[Matrix3::<f32>::identity(); 2]), 80);
+    assert_eq!(o.as_bytes().len(), 176);
+
+    let mut block = Block::std140();
+    assert_eq!(block.push(&1.0f32), 0);                             // float a;
+    assert_eq!(block.push(&Vector2::new(1.0f32, 2.0)), 8);          // vec2 b;
+    assert_eq!(block.push(&Vector3::new(1.0f32, 2.0, 3.0)), 16);    // vec3 c;
+    assert_eq!(block.push_struct(&f), 32);                          // f
+    assert_eq!(block.push(&1.0f32), 48);                            // float g;
+    assert_eq!(block.push_array(&[1.0f32, 2.0]), 64);               // float h[2];
+    assert_eq!(block.push_array(&[Vector3::new(1.0f32, 2.0, 3.0); 2]), 96); // mat2x3 i;
+    assert_eq!(block.push_struct(&o), 128);                         // o[0]
+    assert_eq!(block.push_struct(&o), 304);                         // o[1]
+}
+
+#[test]
+fn test_std140_array_elements() {
+    let mut block = Block::std140();
+    block.push(&0u32);
+    assert_eq!(block.push_array(&[1.0f32, 2.0, 3.0]), 16);
+    assert_eq!(bytes_at::<f32>(&block, 16), 1.0f32.as_bytes());
+    assert_eq!(bytes_at::<f32>(&block, 32), 2.0f32.as_bytes());
+    assert_eq!(bytes_at::<f32>(&block, 48), 3.0f32.as_bytes());
+    assert_eq!(block.as_bytes().len(), 64);
+}
+
+#[test]
+fn test_std430_arrays_are_not_padded() {
+    let mut block = Block::std430();
+    assert_eq!(block.push(&1.0f32), 0);
+    assert_eq!(block.push_array(&[1.0f32, 2.0]), 4);
+    assert_eq!(block.push_array(&[Vector2::new(1.0f32, 2.0); 2]), 16);
+    assert_eq!(block.push_array(&[Vector3::new(1.0f32, 2.0, 3.0); 2]), 32);
+    assert_eq!(block.push(&1.0f32), 64);
+}
+
+#[test]
+fn test_std430_struct_alignment() {
+    let mut s = Block::std430();
+    s.push(&1.0f32);
+    s.push(&2.0f32);
+    s.push(&3.0f32);
+    assert_eq!(s.alignment(), 4);
+
+    let mut block = Block::std430();
+    assert_eq!(block.push_struct(&s), 0);
+    assert_eq!(block.push_struct(&s), 12);
+    assert_eq!(block.push(&Vector2::new(1.0f32, 2.0)), 24);
+}
+
+#[test]
+fn test_vec3_followed_by_scalar() {
+    for &layout in &[Layout::Std140, Layout::Std430] {
+        let mut block = Block::new(layout);
+        assert_eq!(block.push(&Vector3::new(1.0f32, 2.0, 3.0)), 0);
+        assert_eq!(block.push(&4.0f32), 12);
+        assert_eq!(block.push(&Vector3::new(5.0f32, 6.0, 7.0)), 16);
+        assert_eq!(bytes_at::<f32>(&block, 12), 4.0f32.as_bytes());
+    }
+}
+
+#[test]
+fn test_matrix_sizes() {
+    assert_eq!(Matrix2::<f32>::size(Layout::Std140), 32);
+    assert_eq!(Matrix2::<f32>::size(Layout::Std430), 16);
+    assert_eq!(Matrix3::<f32>::size(Layout::Std140), 48);
+    assert_eq!(Matrix3::<f32>::size(Layout::Std430), 48);
+    assert_eq!(Matrix4::<f32>::size(Layout::Std140), 64);
+    assert_eq!(Matrix4::<f32>::size(Layout::Std430), 64);
+
+    assert_eq!(Matrix2::<f64>::size(Layout::Std140), 32);
+    assert_eq!(Matrix3::<f64>::size(Layout::Std140), 96);
+    assert_eq!(Matrix3::<f64>::alignment(Layout::Std430), 32);
+}
+
+#[test]
+fn test_matrix_columns() {
+    let m = Matrix3::new(1.0f32, 2.0, 3.0,
+                         4.0, 5.0, 6.0,
+                         7.0, 8.0, 9.0);
+    let mut block = Block::std140();
+    block.push(&m);
+    assert_eq!(bytes_at::<Vector3<f32>>(&block, 0), m.x.as_bytes());
+    assert_eq!(bytes_at::<f32>(&block, 12), 0.0f32.as_bytes());
+    assert_eq!(bytes_at::<Vector3<f32>>(&block, 16), m.y.as_bytes());
+    assert_eq!(bytes_at::<Vector3<f32>>(&block, 32), m.z.as_bytes());
+
+    let m = Matrix2::new(1.0f32, 2.0, 3.0, 4.0);
+    let mut block = Block::std430();
+    block.push(&m);
+    assert_eq!(block.as_bytes(), m.as_bytes());
+}
+
+#[test]
+fn test_double_vectors() {
+    let mut block = Block::std430();
+    assert_eq!(block.push(&1.0f32), 0);
+    assert_eq!(block.push(&Vector2::new(1.0f64, 2.0)), 16);
+    assert_eq!(block.push(&Vector3::new(1.0f64, 2.0, 3.0)), 32);
+    assert_eq!(block.push(&Vector4::new(1.0f64, 2.0, 3.0, 4.0)), 64);
+}
+
+#[test]
+fn test_scalar_like_types() {
+    let mut block = Block::std140();
+    assert_eq!(block.push(&true), 0);
+    assert_eq!(block.push(&Rad::new(0.5f32)), 4);
+    assert_eq!(block.push(&Point2::new(1i32, 2)), 8);
+    assert_eq!(bytes_at::<u32>(&block, 0), 1u32.as_bytes());
+    assert_eq!(bytes_at::<f32>(&block, 4), 0.5f32.as_bytes());
+}
+
+#[test]
+#[should_panic]
+fn test_struct_layout_mismatch() {
+    Block::std140().push_struct(&Block::std430());
+}
@@ -0,0 +1,126 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_matches_basis2() {
+    for &angle in &[-3.0, -1.2, 0.0, 0.4, 2.5] {
+        let c = UnitComplex::from_angle(Rad::new(angle));
+        let b: Basis2<f64> = Rotation2::from_angle(Rad::new(angle));
+        let v = Vector2::new(1.5, -2.0);
+
+        assert_approx_eq!(c.rotate_vector(v), b.rotate_vector(v));
+        assert_approx_eq!(c.angle(), Rad::new(angle));
+        assert_approx_eq!(Matrix2::from(c), Matrix2::from(b));
+        assert_approx_eq!(UnitComplex::from(b), c);
+        assert_approx_eq!(Basis2::from(c), b);
+    }
+}
+
+#[test]
+fn test_composition() {
+    let a = UnitComplex::from_angle(Rad::new(0.7f64));
+    let b = UnitComplex::from_angle(Rad::new(-1.9f64));
+    let v = Vector2::new(3.0, 4.0);
+
+    assert_approx_eq!((a * b).rotate_vector(v), a.rotate_vector(b.rotate_vector(v)));
+    assert_approx_eq!((a * b).angle(), Rad::new(0.7 - 1.9));
+    assert_approx_eq!(a * a.invert(), UnitComplex::one());
+    assert_approx_eq!(Matrix2::from(a * b), Matrix2::from(a) * Matrix2::from(b));
+}
+
+#[test]
+fn test_conversions() {
+    let c = UnitComplex::from_angle(Rad::new(2.0f64));
+
+    assert_approx_eq!(Rad::from(c), Rad::new(2.0));
+    assert_approx_eq!(UnitComplex::from(Rad::new(2.0)), c);
+    assert_approx_eq!(UnitComplex::from(Matrix2::from(c)), c);
+
+    let m = Matrix3::from(c);
+    assert_approx_eq!(m, Matrix3::from(Matrix2::from(c)));
+    assert_approx_eq!(UnitComplex::from(m), c);
+}
+
+#[test]
+fn test_normalize() {
+    let mut c = UnitComplex::from_angle(Rad::new(0.001f64));
+    for _ in 0..10000 {
+        c = c * UnitComplex::new(1.0000001, 0.001).normalize();
+    }
+    assert_approx_eq!(c.normalize().magnitude(), 1.0);
+    assert_approx_eq!(UnitComplex::new(3.0, 4.0).normalize(), UnitComplex::new(0.6, 0.8));
+}
+
+#[test]
+fn test_between_vectors() {
+    let a = Vector2::new(1.0f64, 0.0);
+    for &angle in &[-3.0, -1.0, 0.5, 3.0] {
+        let b = Vector2::new(Rad::cos(Rad::new(angle)), Rad::sin(Rad::new(angle)));
+        let c = UnitComplex::between_vectors(a, b);
+        assert_approx_eq!(c.rotate_vector(a), b);
+        assert_approx_eq!(c.angle(), Rad::new(angle));
+    }
+}
+
+#[test]
+fn test_slerp() {
+    let a = UnitComplex::from_angle(Rad::new(3.0f64));
+    let b = UnitComplex::from_angle(Rad::new(-3.0f64));
+
+    assert_approx_eq!(a.slerp(b, 0.0), a);
+    assert_approx_eq!(a.slerp(b, 1.0), b);
+    // The shortest arc passes through π
+    assert_approx_eq!(a.slerp(b, 0.5), UnitComplex::new(-1.0, 0.0));
+    assert_approx_eq!(a.interpolate(b, 0.25), UnitComplex::from_angle(Rad::new(3.0 + 0.25 * 0.2831853071795862)));
+    assert_approx_eq!(a.nlerp(b, 0.5), UnitComplex::new(-1.0, 0.0));
+}
+
+#[test]
+fn test_decomposed() {
+    let transform = Decomposed {
+        scale: 2.0f64,
+        rot: UnitComplex::from_angle(Rad::new(0.6)),
+        disp: Vector2::new(1.0, -3.0),
+    };
+    let basis = Decomposed {
+        scale: 2.0f64,
+        rot: Basis2::from_angle(Rad::new(0.6)),
+        disp: Vector2::new(1.0, -3.0),
+    };
+    let p = Point2::new(4.0, 5.0);
+
+    assert_approx_eq!(transform.transform_point(p), basis.transform_point(p));
+    assert_approx_eq!(Matrix3::from(transform), Matrix3::from(basis));
+
+    let inverse = transform.inverse_transform().unwrap();
+    assert_approx_eq!(inverse.transform_point(transform.transform_point(p)), p);
+}
+
+#[test]
+fn test_display_from_str() {
+    assert_eq!(format!("{}", UnitComplex::new(0.6f64, -0.8)), "0.6 - 0.8i");
+    assert_eq!("0.8i + 0.6".parse(), Ok(UnitComplex::new(0.6f64, 0.8)));
+    assert_eq!("-1".parse(), Ok(UnitComplex::new(-1.0f64, 0.0)));
+    assert!("0.6 + 0.8j".parse::<UnitComplex<f64>>().is_err());
+    assert!("".parse::<UnitComplex<f64>>().is_err());
+
+    let c = UnitComplex::from_angle(Rad::new(1.0f64 / 3.0));
+    assert_eq!(c.to_string().parse(), Ok(c));
+}
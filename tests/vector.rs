@@ -186,6 +186,34 @@ fn test_is_perpendicular() {
     assert!(Vector4::new(1.0f64, 0.0f64, 0.0f64, 0.0f64).is_perpendicular(Vector4::new(0.0f64, 0.0f64, 0.0f64, 1.0f64)));
 }
 
+#[test]
+fn test_any_perpendicular() {
+    assert!(Vector3::new(1.0f64, 2.0f64, 3.0f64).any_perpendicular().is_perpendicular(Vector3::new(1.0f64, 2.0f64, 3.0f64)));
+    assert!(Vector3::new(3.0f64, 2.0f64, 1.0f64).any_perpendicular().is_perpendicular(Vector3::new(3.0f64, 2.0f64, 1.0f64)));
+    assert!(Vector3::new(0.0f64, 1.0f64, 0.0f64).any_perpendicular().is_perpendicular(Vector3::new(0.0f64, 1.0f64, 0.0f64)));
+    assert!(!Vector3::new(0.0f64, 1.0f64, 0.0f64).any_perpendicular().is_zero());
+}
+
+#[test]
+fn test_orthonormal_basis() {
+    fn check(n: Vector3<f64>) {
+        let (b1, b2) = n.orthonormal_basis();
+        assert_approx_eq!(b1.magnitude(), 1.0);
+        assert_approx_eq!(b2.magnitude(), 1.0);
+        assert!(b1.is_perpendicular(n));
+        assert!(b2.is_perpendicular(n));
+        assert!(b1.is_perpendicular(b2));
+        assert_approx_eq!(b1.cross(b2), n);
+    }
+
+    check(Vector3::unit_x());
+    check(Vector3::unit_y());
+    check(Vector3::unit_z());
+    check(-Vector3::unit_z());
+    check(Vector3::new(1.0, 2.0, 3.0).normalize());
+    check(Vector3::new(-4.0, 0.5, -2.0).normalize());
+}
+
 #[cfg(test)]
 mod test_magnitude {
     use cgmath::*;
@@ -242,6 +270,27 @@ fn test_angle() {
     assert!(Vector4::new(-1.0f64, 0.0f64, -1.0f64, 0.0f64).angle(Vector4::new(0.0f64, 1.0f64, 0.0f64, 1.0f64)).approx_eq( &rad(f64::consts::FRAC_PI_2) ));
 }
 
+#[test]
+fn test_signed_angle() {
+    let a = Vector2::new(2.0f64, 2.0);
+    let angle: Deg<f64> = a.signed_angle(Vector2::new(-1.0, 1.0));
+    assert_approx_eq!(angle, deg(90.0));
+    let angle: Deg<f64> = a.signed_angle(Vector2::new(1.0, -1.0));
+    assert_approx_eq!(angle, deg(-90.0));
+    let angle: Rad<f64> = a.signed_angle(Vector2::new(-1.0, -1.0));
+    assert_approx_eq!(angle, rad(f64::consts::PI));
+
+    let axis = Vector3::new(1.0f64, 1.0, 1.0).normalize();
+    let a = Vector3::new(1.0, 0.0, 0.0);
+    for &degrees in &[-150.0, -30.0, 0.0, 45.0, 170.0] {
+        let b = Quaternion::from_axis_angle(axis, Rad::from(deg(degrees))).rotate_vector(a) + axis * 3.0;
+        let angle: Deg<f64> = a.signed_angle_around(b, axis);
+        assert_approx_eq!(angle, deg(degrees));
+        let angle: Deg<f64> = b.signed_angle_around(a, axis);
+        assert_approx_eq!(angle, deg(-degrees));
+    }
+}
+
 #[test]
 fn test_normalize() {
     // TODO: test normalize_to, normalize_sel.0, and normalize_self_to
@@ -256,3 +305,24 @@ fn test_cast() {
     assert_approx_eq!(Vector3::new(1.0f64, 2.4, -3.13).cast(), Vector3::new(1.0f32, 2.4, -3.13));
     assert_approx_eq!(Vector4::new(13.5f64, -4.6, -8.3, 2.41).cast(), Vector4::new(13.5f32, -4.6, -8.3, 2.41));
 }
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", Vector3::new(1, -2, 3)), "[1, -2, 3]");
+    assert_eq!(format!("{:.2}", Vector2::new(1.0f64, 0.125)), "[1.00, 0.12]");
+    assert_eq!(format!("{:5.1}", Vector2::new(1.0f64, -2.5)), "[  1.0,  -2.5]");
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("[1, -2, 3]".parse(), Ok(Vector3::new(1, -2, 3)));
+    assert_eq!(" [ 1.5,2e1 ] ".parse(), Ok(Vector2::new(1.5f64, 20.0)));
+    assert!("[1, 2]".parse::<Vector3<i32>>().is_err());
+    assert!("[1, 2, 3, 4]".parse::<Vector3<i32>>().is_err());
+    assert!("[1, , 3]".parse::<Vector3<i32>>().is_err());
+    assert!("1, 2, 3".parse::<Vector3<i32>>().is_err());
+    assert!("[1, x, 3]".parse::<Vector3<i32>>().is_err());
+
+    let v = Vector4::new(0.1f64, -1.0 / 3.0, 1e-20, 12345.678);
+    assert_eq!(v.to_string().parse(), Ok(v));
+}